@@ -563,8 +563,10 @@ impl pallet_democracy::Config for Runtime {
 
 parameter_types! {
 	pub const CouncilMotionDuration: BlockNumber = 5 * DAYS;
+	pub const CouncilEmergencyMotionDuration: BlockNumber = 1 * HOURS;
 	pub const CouncilMaxProposals: u32 = 100;
 	pub const CouncilMaxMembers: u32 = 100;
+	pub const CouncilMaxVoteCleanupsPerBlock: u32 = 25;
 }
 
 type CouncilCollective = pallet_collective::Instance1;
@@ -573,9 +575,19 @@ impl pallet_collective::Config<CouncilCollective> for Runtime {
 	type Proposal = Call;
 	type Event = Event;
 	type MotionDuration = CouncilMotionDuration;
+	type EmergencyMotionDuration = CouncilEmergencyMotionDuration;
 	type MaxProposals = CouncilMaxProposals;
 	type MaxMembers = CouncilMaxMembers;
 	type DefaultVote = pallet_collective::PrimeDefaultVote;
+	type VoteHandler = ();
+	type BanOrigin = EnsureOneOf<
+		AccountId,
+		EnsureRoot<AccountId>,
+		pallet_collective::EnsureProportionAtLeast<_2, _3, AccountId, CouncilCollective>
+	>;
+	type RootCapableOrigin = frame_system::EnsureNever<()>;
+	type StrictMemberOrdering = ();
+	type MaxVoteCleanupsPerBlock = CouncilMaxVoteCleanupsPerBlock;
 	type WeightInfo = pallet_collective::weights::SubstrateWeight<Runtime>;
 }
 
@@ -616,8 +628,10 @@ impl pallet_elections_phragmen::Config for Runtime {
 
 parameter_types! {
 	pub const TechnicalMotionDuration: BlockNumber = 5 * DAYS;
+	pub const TechnicalEmergencyMotionDuration: BlockNumber = 1 * HOURS;
 	pub const TechnicalMaxProposals: u32 = 100;
 	pub const TechnicalMaxMembers: u32 = 100;
+	pub const TechnicalMaxVoteCleanupsPerBlock: u32 = 25;
 }
 
 type TechnicalCollective = pallet_collective::Instance2;
@@ -626,9 +640,19 @@ impl pallet_collective::Config<TechnicalCollective> for Runtime {
 	type Proposal = Call;
 	type Event = Event;
 	type MotionDuration = TechnicalMotionDuration;
+	type EmergencyMotionDuration = TechnicalEmergencyMotionDuration;
 	type MaxProposals = TechnicalMaxProposals;
 	type MaxMembers = TechnicalMaxMembers;
 	type DefaultVote = pallet_collective::PrimeDefaultVote;
+	type VoteHandler = ();
+	type BanOrigin = EnsureOneOf<
+		AccountId,
+		EnsureRoot<AccountId>,
+		pallet_collective::EnsureProportionAtLeast<_2, _3, AccountId, TechnicalCollective>
+	>;
+	type RootCapableOrigin = frame_system::EnsureNever<()>;
+	type StrictMemberOrdering = ();
+	type MaxVoteCleanupsPerBlock = TechnicalMaxVoteCleanupsPerBlock;
 	type WeightInfo = pallet_collective::weights::SubstrateWeight<Runtime>;
 }
 
@@ -656,15 +680,44 @@ parameter_types! {
 	pub const TipCountdown: BlockNumber = 1 * DAYS;
 	pub const TipFindersFee: Percent = Percent::from_percent(20);
 	pub const TipReportDepositBase: Balance = 1 * DOLLARS;
+	pub const MaxTipShares: u32 = 10;
+	pub const MaxTipDeclarations: u32 = 13;
+	pub const MinTippersForClose: u32 = 2;
+	pub const BeneficiaryTipCooldown: BlockNumber = 7 * DAYS;
+	pub const AllowSelfReport: bool = true;
 	pub const DataDepositPerByte: Balance = 1 * CENTS;
 	pub const BountyDepositBase: Balance = 1 * DOLLARS;
 	pub const BountyDepositPayoutDelay: BlockNumber = 1 * DAYS;
 	pub const TreasuryModuleId: ModuleId = ModuleId(*b"py/trsry");
 	pub const BountyUpdatePeriod: BlockNumber = 14 * DAYS;
+	pub const BountyClaimTimeout: BlockNumber = 90 * DAYS;
 	pub const MaximumReasonLength: u32 = 16384;
 	pub const BountyCuratorDeposit: Permill = Permill::from_percent(50);
+	pub const BountyRejectionSlash: Permill = Permill::from_percent(100);
+	pub const CuratorSlashAppealPeriod: BlockNumber = 7 * DAYS;
+	pub const CuratorDepositMin: Option<Balance> = Some(1 * DOLLARS);
+	pub const CuratorDepositMax: Option<Balance> = Some(100 * DOLLARS);
 	pub const BountyValueMinimum: Balance = 5 * DOLLARS;
-}
+	pub const MaxBountyTags: u32 = 5;
+	pub const MaxBountyTagLen: u32 = 32;
+	pub const MaxBountyTipReasons: u32 = 10;
+	pub const MaxApprovals: u32 = 100;
+	pub const MaxApprovalsPerBlock: u32 = 50;
+	pub const MaxBountiesPerAccount: u32 = 100;
+	pub const MaxBountyProposalsPerAccount: u32 = 10;
+	pub const MaxTotalActiveBountyValue: Balance = 1_000_000 * DOLLARS;
+	pub const MaxBountyWorkSubmissions: u32 = 100;
+	pub const BountyWorkSubmissionDeposit: Balance = 1 * DOLLARS;
+	pub const MaxCuratorApplicants: u32 = 20;
+	pub const CuratorApplicationDeposit: Balance = 1 * DOLLARS;
+	pub const BountyDisputeDeposit: Balance = 5 * DOLLARS;
+	pub const BountyAccountTag: &'static str = "bt";
+	pub const BountyDescriptionPurgeReward: Balance = 10 * CENTS;
+	pub const BountyMaintenanceTip: Balance = 10 * CENTS;
+	pub const MaxCuratorSponsors: u32 = 10;
+}
+
+const_assert!(DesiredMembers::get() <= MaxTipDeclarations::get());
 
 impl pallet_treasury::Config for Runtime {
 	type ModuleId = TreasuryModuleId;
@@ -690,15 +743,67 @@ impl pallet_treasury::Config for Runtime {
 	type WeightInfo = pallet_treasury::weights::SubstrateWeight<Runtime>;
 }
 
+/// Identifies accounts in bounty events by the hash of their on-chain display name.
+pub struct IdentityDisplay;
+impl pallet_bounties::IdentityProvider<AccountId, Hash> for IdentityDisplay {
+	fn identity_of(who: &AccountId) -> Option<Hash> {
+		Identity::identity(who)
+			.map(|registration| <BlakeTwo256 as traits::Hash>::hash_of(&registration.info.display))
+	}
+}
+
 impl pallet_bounties::Config for Runtime {
 	type Event = Event;
 	type BountyDepositBase = BountyDepositBase;
 	type BountyDepositPayoutDelay = BountyDepositPayoutDelay;
 	type BountyUpdatePeriod = BountyUpdatePeriod;
+	type ClaimTimeout = BountyClaimTimeout;
 	type BountyCuratorDeposit = BountyCuratorDeposit;
+	type BountyRejectionSlash = BountyRejectionSlash;
+	type AppealPeriod = CuratorSlashAppealPeriod;
+	type AppealOrigin = EnsureOneOf<
+		AccountId,
+		EnsureRoot<AccountId>,
+		pallet_collective::EnsureProportionAtLeast<_2, _3, AccountId, TechnicalCollective>
+	>;
+	type RequireSelfAwardApproval = ();
+	type CuratorDepositMin = CuratorDepositMin;
+	type CuratorDepositMax = CuratorDepositMax;
 	type BountyValueMinimum = BountyValueMinimum;
 	type DataDepositPerByte = DataDepositPerByte;
 	type MaximumReasonLength = MaximumReasonLength;
+	type MaxTags = MaxBountyTags;
+	type MaxTagLen = MaxBountyTagLen;
+	type MaxTipReasons = MaxBountyTipReasons;
+	type MaxApprovals = MaxApprovals;
+	type MaxApprovalsPerBlock = MaxApprovalsPerBlock;
+	type MaxBountiesPerAccount = MaxBountiesPerAccount;
+	type MaxProposalsPerAccount = MaxBountyProposalsPerAccount;
+	type MaxTotalActiveBountyValue = MaxTotalActiveBountyValue;
+	type MaxWorkSubmissions = MaxBountyWorkSubmissions;
+	type WorkSubmissionDeposit = BountyWorkSubmissionDeposit;
+	type MaxCuratorApplicants = MaxCuratorApplicants;
+	type CuratorApplicationDeposit = CuratorApplicationDeposit;
+	type FastTrackOrigin = pallet_collective::EnsureProportionAtLeast<_2, _3, AccountId, TechnicalCollective>;
+	type DisputeDeposit = BountyDisputeDeposit;
+	type BountyAccountTag = BountyAccountTag;
+	type DisputeResolutionOrigin = EnsureRootOrHalfCouncil;
+	type DescriptionPurgeReward = BountyDescriptionPurgeReward;
+	type MaintenanceTip = BountyMaintenanceTip;
+	type IdentityProvider = IdentityDisplay;
+	type ProposerNotifier = ();
+	type CuratorFilter = ();
+	type FundingSource = ();
+	type BountyCall = Call;
+	type Scheduler = Scheduler;
+	type PalletsOrigin = OriginCaller;
+	type Paymaster = ();
+	type VetoOrigin = EnsureOneOf<
+		AccountId,
+		EnsureRoot<AccountId>,
+		pallet_collective::EnsureProportionAtLeast<_2, _3, AccountId, TechnicalCollective>
+	>;
+	type MaxCuratorSponsors = MaxCuratorSponsors;
 	type WeightInfo = pallet_bounties::weights::SubstrateWeight<Runtime>;
 }
 
@@ -708,8 +813,17 @@ impl pallet_tips::Config for Runtime {
 	type MaximumReasonLength = MaximumReasonLength;
 	type Tippers = Elections;
 	type TipCountdown = TipCountdown;
+	type MinTippersForClose = MinTippersForClose;
+	type MinTippersOrigin = EnsureRoot<AccountId>;
+	type BeneficiaryTipCooldown = BeneficiaryTipCooldown;
+	type TipCooldownOverrideOrigin = EnsureRootOrHalfCouncil;
 	type TipFindersFee = TipFindersFee;
+	type AllowSelfReport = AllowSelfReport;
 	type TipReportDepositBase = TipReportDepositBase;
+	type MaxTipShares = MaxTipShares;
+	type MaxTipDeclarations = MaxTipDeclarations;
+	type TipFundingSource = Bounties;
+	type TrustProvider = ();
 	type WeightInfo = pallet_tips::weights::SubstrateWeight<Runtime>;
 }
 
@@ -800,7 +914,9 @@ impl<LocalCall> frame_system::offchain::CreateSignedTransaction<LocalCall> for R
 			frame_system::CheckEra::<Runtime>::from(era),
 			frame_system::CheckNonce::<Runtime>::from(nonce),
 			frame_system::CheckWeight::<Runtime>::new(),
-			pallet_transaction_payment::ChargeTransactionPayment::<Runtime>::from(tip),
+			pallet_bounties::CuratorFeeExemption::<Runtime, _>::from(
+				pallet_transaction_payment::ChargeTransactionPayment::<Runtime>::from(tip),
+			),
 		);
 		let raw_payload = SignedPayload::new(call, extra)
 			.map_err(|e| {
@@ -1071,7 +1187,7 @@ pub type SignedExtra = (
 	frame_system::CheckEra<Runtime>,
 	frame_system::CheckNonce<Runtime>,
 	frame_system::CheckWeight<Runtime>,
-	pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
+	pallet_bounties::CuratorFeeExemption<Runtime, pallet_transaction_payment::ChargeTransactionPayment<Runtime>>,
 );
 /// Unchecked extrinsic type as expected by this runtime.
 pub type UncheckedExtrinsic = generic::UncheckedExtrinsic<Address, Call, Signature, SignedExtra>;
@@ -1251,6 +1367,36 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_bounties_rpc_runtime_api::BountiesApi<Block, AccountId, Balance, BlockNumber>
+		for Runtime
+	{
+		fn bounties() -> Vec<(
+			pallet_bounties::BountyIndex,
+			pallet_bounties::Bounty<AccountId, Balance, BlockNumber>,
+		)> {
+			Bounties::all_bounties()
+		}
+
+		fn bounty(
+			index: pallet_bounties::BountyIndex,
+		) -> Option<pallet_bounties::Bounty<AccountId, Balance, BlockNumber>> {
+			Bounties::bounties(index)
+		}
+
+		fn bounties_by_status(
+			status: pallet_bounties::BountyStatusKind,
+		) -> Vec<(
+			pallet_bounties::BountyIndex,
+			pallet_bounties::Bounty<AccountId, Balance, BlockNumber>,
+		)> {
+			Bounties::bounties_by_status(status)
+		}
+
+		fn bounty_account(index: pallet_bounties::BountyIndex) -> AccountId {
+			Bounties::bounty_account_id(index)
+		}
+	}
+
 	impl pallet_contracts_rpc_runtime_api::ContractsApi<Block, AccountId, Balance, BlockNumber>
 		for Runtime
 	{
@@ -0,0 +1,53 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition to query the bounties pallet.
+//!
+//! This API should be imported and implemented by the runtime,
+//! of a node that wants to expose structured bounty data to
+//! RPC layers and light clients.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use sp_std::prelude::*;
+use codec::Codec;
+pub use pallet_bounties::{Bounty, BountyIndex, BountyStatusKind};
+
+sp_api::decl_runtime_apis! {
+	/// The API to query the bounties of the bounties pallet.
+	#[api_version(2)]
+	pub trait BountiesApi<AccountId, Balance, BlockNumber> where
+		AccountId: Codec,
+		Balance: Codec,
+		BlockNumber: Codec,
+	{
+		/// Get all bounties, ordered by index.
+		fn bounties() -> Vec<(BountyIndex, Bounty<AccountId, Balance, BlockNumber>)>;
+
+		/// Get the bounty with the given `index`, if it exists.
+		fn bounty(index: BountyIndex) -> Option<Bounty<AccountId, Balance, BlockNumber>>;
+
+		/// Get all bounties whose status is of the given kind, ordered by index.
+		fn bounties_by_status(
+			status: BountyStatusKind,
+		) -> Vec<(BountyIndex, Bounty<AccountId, Balance, BlockNumber>)>;
+
+		/// Get the account that the funds of the bounty with the given `index` are held in,
+		/// whether or not the bounty exists.
+		fn bounty_account(index: BountyIndex) -> AccountId;
+	}
+}
@@ -23,28 +23,36 @@ use super::*;
 
 use sp_runtime::traits::Bounded;
 use frame_system::{EventRecord, RawOrigin};
-use frame_benchmarking::{benchmarks, account, whitelisted_caller};
-use frame_support::traits::OnInitialize;
+use frame_benchmarking::{benchmarks_instance, account, whitelisted_caller};
+use frame_support::traits::{OnInitialize, UnfilteredDispatchable};
 
 use crate::Module as Bounties;
 use pallet_treasury::Module as Treasury;
 
 const SEED: u32 = 0;
 
-// Create bounties that are approved for use in `on_initialize`.
-fn create_approved_bounties<T: Config>(n: u32) -> Result<(), &'static str> {
+// Create bounty proposals, returning their indices.
+fn create_proposed_bounties<T: Config<I>, I: Instance>(n: u32) -> Result<Vec<BountyIndex>, &'static str> {
+	let mut bounty_ids = Vec::new();
 	for i in 0 .. n {
-		let (caller, _curator, _fee, value, reason) = setup_bounty::<T>(i, MAX_BYTES);
-		Bounties::<T>::propose_bounty(RawOrigin::Signed(caller).into(), value, reason)?;
-		let bounty_id = BountyCount::get() - 1;
-		Bounties::<T>::approve_bounty(RawOrigin::Root.into(), bounty_id)?;
+		let (caller, _curator, _fee, value, reason) = setup_bounty::<T, I>(i, MAX_BYTES);
+		Bounties::<T, I>::propose_bounty(RawOrigin::Signed(caller).into(), value, reason)?;
+		bounty_ids.push(BountyCount::<I>::get() - 1);
+	}
+	Ok(bounty_ids)
+}
+
+// Create bounties that are approved for use in `on_initialize`.
+fn create_approved_bounties<T: Config<I>, I: Instance>(n: u32) -> Result<(), &'static str> {
+	for bounty_id in create_proposed_bounties::<T, I>(n)? {
+		Bounties::<T, I>::approve_bounty(RawOrigin::Root.into(), bounty_id, Default::default())?;
 	}
-	ensure!(BountyApprovals::get().len() == n as usize, "Not all bounty approved");
+	ensure!(BountyApprovals::<I>::get().len() == n as usize, "Not all bounty approved");
 	Ok(())
 }
 
 // Create the pre-requisite information needed to create a treasury `propose_bounty`.
-fn setup_bounty<T: Config>(u: u32, d: u32) -> (
+fn setup_bounty<T: Config<I>, I: Instance>(u: u32, d: u32) -> (
 	T::AccountId,
 	T::AccountId,
 	BalanceOf<T>,
@@ -62,28 +70,44 @@ fn setup_bounty<T: Config>(u: u32, d: u32) -> (
 	(caller, curator, fee, value, reason)
 }
 
-fn create_bounty<T: Config>() -> Result<(
+fn create_bounty<T: Config<I>, I: Instance>() -> Result<(
 	<T::Lookup as StaticLookup>::Source,
 	BountyIndex,
 ), &'static str> {
-	let (caller, curator, fee, value, reason) = setup_bounty::<T>(0, MAX_BYTES);
+	let (caller, curator, fee, value, reason) = setup_bounty::<T, I>(0, MAX_BYTES);
 	let curator_lookup = T::Lookup::unlookup(curator.clone());
-	Bounties::<T>::propose_bounty(RawOrigin::Signed(caller).into(), value, reason)?;
-	let bounty_id = BountyCount::get() - 1;
-	Bounties::<T>::approve_bounty(RawOrigin::Root.into(), bounty_id)?;
+	Bounties::<T, I>::propose_bounty(RawOrigin::Signed(caller).into(), value, reason)?;
+	let bounty_id = BountyCount::<I>::get() - 1;
+	Bounties::<T, I>::approve_bounty(RawOrigin::Root.into(), bounty_id, Default::default())?;
 	Treasury::<T>::on_initialize(T::BlockNumber::zero());
-	Bounties::<T>::propose_curator(RawOrigin::Root.into(), bounty_id, curator_lookup.clone(), fee)?;
-	Bounties::<T>::accept_curator(RawOrigin::Signed(curator).into(), bounty_id)?;
+	Bounties::<T, I>::propose_curator(RawOrigin::Root.into(), bounty_id, curator_lookup.clone(), fee)?;
+	Bounties::<T, I>::accept_curator(RawOrigin::Signed(curator).into(), bounty_id, None)?;
 	Ok((curator_lookup, bounty_id))
 }
 
-fn setup_pot_account<T: Config>() {
-	let pot_account = Bounties::<T>::account_id();
+// Create a funded bounty with a proposed curator who has not accepted yet.
+fn create_bounty_with_proposed_curator<T: Config<I>, I: Instance>() -> Result<(
+	<T::Lookup as StaticLookup>::Source,
+	BountyIndex,
+	BalanceOf<T>,
+), &'static str> {
+	let (caller, curator, fee, value, reason) = setup_bounty::<T, I>(0, MAX_BYTES);
+	let curator_lookup = T::Lookup::unlookup(curator);
+	Bounties::<T, I>::propose_bounty(RawOrigin::Signed(caller).into(), value, reason)?;
+	let bounty_id = BountyCount::<I>::get() - 1;
+	Bounties::<T, I>::approve_bounty(RawOrigin::Root.into(), bounty_id, Default::default())?;
+	Bounties::<T, I>::on_initialize(T::BlockNumber::zero());
+	Bounties::<T, I>::propose_curator(RawOrigin::Root.into(), bounty_id, curator_lookup.clone(), fee)?;
+	Ok((curator_lookup, bounty_id, fee))
+}
+
+fn setup_pot_account<T: Config<I>, I: Instance>() {
+	let pot_account = Bounties::<T, I>::account_id();
 	let value = T::Currency::minimum_balance().saturating_mul(1_000_000_000u32.into());
 	let _ = T::Currency::make_free_balance_be(&pot_account, value);
 }
 
-fn assert_last_event<T: Config>(generic_event: <T as Config>::Event) {
+fn assert_last_event<T: Config<I>, I: Instance>(generic_event: <T as Config<I>>::Event) {
 	let events = frame_system::Module::<T>::events();
 	let system_event: <T as frame_system::Config>::Event = generic_event.into();
 	// compare to the last event record
@@ -93,71 +117,208 @@ fn assert_last_event<T: Config>(generic_event: <T as Config>::Event) {
 
 const MAX_BYTES: u32 = 16384;
 
-benchmarks! {
+benchmarks_instance! {
 	propose_bounty {
-		let d in 0 .. MAX_BYTES;
+		let d in 1 .. MAX_BYTES;
 
-		let (caller, curator, fee, value, description) = setup_bounty::<T>(0, d);
+		let (caller, curator, fee, value, description) = setup_bounty::<T, I>(0, d);
 	}: _(RawOrigin::Signed(caller), value, description)
 
+	propose_bounty_with_hash {
+		let (caller, curator, fee, value, description) = setup_bounty::<T, I>(0, 0);
+		let description_hash = T::Hashing::hash(&description[..]);
+	}: _(RawOrigin::Signed(caller), value, description_hash)
+
+	note_bounty_description {
+		let d in 0 .. MAX_BYTES;
+
+		let (caller, curator, fee, value, description) = setup_bounty::<T, I>(0, d);
+		let description_hash = T::Hashing::hash(&description[..]);
+		Bounties::<T, I>::propose_bounty_with_hash(RawOrigin::Signed(caller).into(), value, description_hash)?;
+		let bounty_id = BountyCount::<I>::get() - 1;
+		let noter: T::AccountId = whitelisted_caller();
+		let deposit = T::DataDepositPerByte::get() * d.into() + T::Currency::minimum_balance();
+		let _ = T::Currency::make_free_balance_be(&noter, deposit);
+	}: _(RawOrigin::Signed(noter.clone()), bounty_id, description)
+	verify {
+		assert_last_event::<T, I>(RawEvent::BountyDescriptionNoted(bounty_id, noter).into())
+	}
+
+	set_bounty_tags {
+		let t in 0 .. T::MaxTags::get();
+
+		let (caller, curator, fee, value, reason) = setup_bounty::<T, I>(0, 1);
+		let deposit = T::DataDepositPerByte::get() * (T::MaxTags::get() * T::MaxTagLen::get()).into();
+		let _ = T::Currency::deposit_creating(&caller, deposit);
+		Bounties::<T, I>::propose_bounty(RawOrigin::Signed(caller.clone()).into(), value, reason)?;
+		let bounty_id = BountyCount::<I>::get() - 1;
+		let tags = vec![vec![0; T::MaxTagLen::get() as usize]; t as usize];
+	}: _(RawOrigin::Signed(caller), bounty_id, tags)
+	verify {
+		assert_last_event::<T, I>(RawEvent::BountyTagsSet(bounty_id).into())
+	}
+
 	approve_bounty {
-		let (caller, curator, fee, value, reason) = setup_bounty::<T>(0, MAX_BYTES);
-		Bounties::<T>::propose_bounty(RawOrigin::Signed(caller).into(), value, reason)?;
-		let bounty_id = BountyCount::get() - 1;
-	}: _(RawOrigin::Root, bounty_id)
+		let (caller, curator, fee, value, reason) = setup_bounty::<T, I>(0, MAX_BYTES);
+		Bounties::<T, I>::propose_bounty(RawOrigin::Signed(caller).into(), value, reason)?;
+		let bounty_id = BountyCount::<I>::get() - 1;
+	}: _(RawOrigin::Root, bounty_id, Default::default())
+
+	approve_bounties {
+		let b in 1 .. T::MaxApprovals::get();
+		let bounty_ids = create_proposed_bounties::<T, I>(b)?;
+	}: _(RawOrigin::Root, bounty_ids, Default::default())
+	verify {
+		ensure!(BountyApprovals::<I>::get().len() == b as usize, "Not all bounty approved");
+	}
+
+	set_bounty_priority {
+		create_approved_bounties::<T, I>(1)?;
+		let bounty_id = BountyCount::<I>::get() - 1;
+	}: _(RawOrigin::Root, bounty_id, BountyPriority::max_value())
+	verify {
+		assert_last_event::<T, I>(RawEvent::BountyPrioritySet(bounty_id, BountyPriority::max_value()).into())
+	}
+
+	set_bounty_payout_delay {
+		create_approved_bounties::<T, I>(1)?;
+		let bounty_id = BountyCount::<I>::get() - 1;
+		let delay = Some(T::BountyDepositPayoutDelay::get());
+	}: _(RawOrigin::Root, bounty_id, delay)
+	verify {
+		assert_last_event::<T, I>(RawEvent::BountyPayoutDelaySet(bounty_id, delay).into())
+	}
 
 	propose_curator {
-		setup_pot_account::<T>();
-		let (caller, curator, fee, value, reason) = setup_bounty::<T>(0, MAX_BYTES);
+		setup_pot_account::<T, I>();
+		let (caller, curator, fee, value, reason) = setup_bounty::<T, I>(0, MAX_BYTES);
 		let curator_lookup = T::Lookup::unlookup(curator.clone());
-		Bounties::<T>::propose_bounty(RawOrigin::Signed(caller).into(), value, reason)?;
-		let bounty_id = BountyCount::get() - 1;
-		Bounties::<T>::approve_bounty(RawOrigin::Root.into(), bounty_id)?;
-		Bounties::<T>::on_initialize(T::BlockNumber::zero());
+		Bounties::<T, I>::propose_bounty(RawOrigin::Signed(caller).into(), value, reason)?;
+		let bounty_id = BountyCount::<I>::get() - 1;
+		Bounties::<T, I>::approve_bounty(RawOrigin::Root.into(), bounty_id, Default::default())?;
+		Bounties::<T, I>::on_initialize(T::BlockNumber::zero());
 	}: _(RawOrigin::Root, bounty_id, curator_lookup, fee)
 
-	// Worst case when curator is inactive and any sender unassigns the curator.
+	propose_curator_with_rate {
+		setup_pot_account::<T, I>();
+		let (caller, curator, _, value, reason) = setup_bounty::<T, I>(0, MAX_BYTES);
+		let curator_lookup = T::Lookup::unlookup(curator.clone());
+		Bounties::<T, I>::propose_bounty(RawOrigin::Signed(caller).into(), value, reason)?;
+		let bounty_id = BountyCount::<I>::get() - 1;
+		Bounties::<T, I>::approve_bounty(RawOrigin::Root.into(), bounty_id, Default::default())?;
+		Bounties::<T, I>::on_initialize(T::BlockNumber::zero());
+		let rate = Permill::from_percent(50);
+	}: _(RawOrigin::Root, bounty_id, curator_lookup, rate)
+	verify {
+		assert_last_event::<T, I>(RawEvent::CuratorProposedWithRate(bounty_id, curator, rate).into())
+	}
+
+	// Worst case when `RejectOrigin` unassigns the curator and their deposit is held for appeal.
+	unassign_curator_proposed {
+		setup_pot_account::<T, I>();
+		let (caller, curator, fee, value, reason) = setup_bounty::<T, I>(0, MAX_BYTES);
+		let curator_lookup = T::Lookup::unlookup(curator.clone());
+		Bounties::<T, I>::propose_bounty(RawOrigin::Signed(caller).into(), value, reason)?;
+		let bounty_id = BountyCount::<I>::get() - 1;
+		Bounties::<T, I>::approve_bounty(RawOrigin::Root.into(), bounty_id, Default::default())?;
+		Treasury::<T>::on_initialize(T::BlockNumber::zero());
+		Bounties::<T, I>::propose_curator(RawOrigin::Root.into(), bounty_id, curator_lookup, fee)?;
+	}: unassign_curator(RawOrigin::Root, bounty_id)
+	verify {
+		assert_last_event::<T, I>(RawEvent::CuratorUnassigned(bounty_id, curator, false).into())
+	}
+
 	unassign_curator {
-		setup_pot_account::<T>();
-		let (curator_lookup, bounty_id) = create_bounty::<T>()?;
-		Bounties::<T>::on_initialize(T::BlockNumber::zero());
-		let bounty_id = BountyCount::get() - 1;
-		frame_system::Module::<T>::set_block_number(T::BountyUpdatePeriod::get() + 1u32.into());
-		let caller = whitelisted_caller();
-	}: _(RawOrigin::Signed(caller), bounty_id)
+		setup_pot_account::<T, I>();
+		let (curator_lookup, bounty_id) = create_bounty::<T, I>()?;
+		Bounties::<T, I>::on_initialize(T::BlockNumber::zero());
+		let bounty_id = BountyCount::<I>::get() - 1;
+	}: _(RawOrigin::Root, bounty_id)
+	verify {
+		let curator = T::Lookup::lookup(curator_lookup)?;
+		assert_last_event::<T, I>(RawEvent::CuratorUnassigned(bounty_id, curator, true).into())
+	}
 
 	accept_curator {
-		setup_pot_account::<T>();
-		let (caller, curator, fee, value, reason) = setup_bounty::<T>(0, MAX_BYTES);
+		setup_pot_account::<T, I>();
+		let (caller, curator, fee, value, reason) = setup_bounty::<T, I>(0, MAX_BYTES);
 		let curator_lookup = T::Lookup::unlookup(curator.clone());
-		Bounties::<T>::propose_bounty(RawOrigin::Signed(caller).into(), value, reason)?;
-		let bounty_id = BountyCount::get() - 1;
-		Bounties::<T>::approve_bounty(RawOrigin::Root.into(), bounty_id)?;
-		Bounties::<T>::on_initialize(T::BlockNumber::zero());
-		Bounties::<T>::propose_curator(RawOrigin::Root.into(), bounty_id, curator_lookup, fee)?;
-	}: _(RawOrigin::Signed(curator), bounty_id)
+		Bounties::<T, I>::propose_bounty(RawOrigin::Signed(caller).into(), value, reason)?;
+		let bounty_id = BountyCount::<I>::get() - 1;
+		Bounties::<T, I>::approve_bounty(RawOrigin::Root.into(), bounty_id, Default::default())?;
+		Bounties::<T, I>::on_initialize(T::BlockNumber::zero());
+		Bounties::<T, I>::propose_curator(RawOrigin::Root.into(), bounty_id, curator_lookup, fee)?;
+		let max_deposit = Bounties::<T, I>::calculate_curator_deposit(&fee);
+	}: _(RawOrigin::Signed(curator), bounty_id, Some(max_deposit))
 
 	award_bounty {
-		setup_pot_account::<T>();
-		let (curator_lookup, bounty_id) = create_bounty::<T>()?;
-		Bounties::<T>::on_initialize(T::BlockNumber::zero());
+		setup_pot_account::<T, I>();
+		let (curator_lookup, bounty_id) = create_bounty::<T, I>()?;
+		Bounties::<T, I>::on_initialize(T::BlockNumber::zero());
 
-		let bounty_id = BountyCount::get() - 1;
+		let bounty_id = BountyCount::<I>::get() - 1;
 		let curator = T::Lookup::lookup(curator_lookup)?;
 		let beneficiary = T::Lookup::unlookup(account("beneficiary", 0, SEED));
-	}: _(RawOrigin::Signed(curator), bounty_id, beneficiary)
+		let remark = vec![0; T::MaximumReasonLength::get() as usize];
+	}: _(RawOrigin::Signed(curator), bounty_id, beneficiary, Some(remark))
+
+	approve_self_award {
+		setup_pot_account::<T, I>();
+		let (curator_lookup, bounty_id) = create_bounty::<T, I>()?;
+		Bounties::<T, I>::on_initialize(T::BlockNumber::zero());
+
+		let bounty_id = BountyCount::<I>::get() - 1;
+		let curator = T::Lookup::lookup(curator_lookup)?;
+	}: _(RawOrigin::Root, bounty_id)
+	verify {
+		assert_last_event::<T, I>(RawEvent::SelfAwardApproved(bounty_id, curator).into())
+	}
+
+	reject_award {
+		setup_pot_account::<T, I>();
+		let (curator_lookup, bounty_id) = create_bounty::<T, I>()?;
+		Bounties::<T, I>::on_initialize(T::BlockNumber::zero());
+
+		let bounty_id = BountyCount::<I>::get() - 1;
+		let curator = T::Lookup::lookup(curator_lookup)?;
+		let beneficiary_account: T::AccountId = account("beneficiary", 0, SEED);
+		let beneficiary = T::Lookup::unlookup(beneficiary_account.clone());
+		Bounties::<T, I>::award_bounty(RawOrigin::Signed(curator).into(), bounty_id, beneficiary, None)?;
+	}: _(RawOrigin::Signed(beneficiary_account.clone()), bounty_id)
+	verify {
+		assert_last_event::<T, I>(RawEvent::BountyAwardRejected(bounty_id, beneficiary_account).into())
+	}
+
+	update_award_beneficiary {
+		setup_pot_account::<T, I>();
+		let (curator_lookup, bounty_id) = create_bounty::<T, I>()?;
+		Bounties::<T, I>::on_initialize(T::BlockNumber::zero());
+
+		let bounty_id = BountyCount::<I>::get() - 1;
+		let curator = T::Lookup::lookup(curator_lookup)?;
+		let beneficiary_account: T::AccountId = account("beneficiary", 0, SEED);
+		let beneficiary = T::Lookup::unlookup(beneficiary_account.clone());
+		Bounties::<T, I>::award_bounty(RawOrigin::Signed(curator.clone()).into(), bounty_id, beneficiary, None)?;
+		let new_beneficiary_account: T::AccountId = account("beneficiary", 1, SEED);
+		let new_beneficiary = T::Lookup::unlookup(new_beneficiary_account.clone());
+	}: _(RawOrigin::Signed(curator), bounty_id, new_beneficiary)
+	verify {
+		assert_last_event::<T, I>(
+			RawEvent::BountyBeneficiaryUpdated(bounty_id, beneficiary_account, new_beneficiary_account).into()
+		)
+	}
 
 	claim_bounty {
-		setup_pot_account::<T>();
-		let (curator_lookup, bounty_id) = create_bounty::<T>()?;
-		Bounties::<T>::on_initialize(T::BlockNumber::zero());
+		setup_pot_account::<T, I>();
+		let (curator_lookup, bounty_id) = create_bounty::<T, I>()?;
+		Bounties::<T, I>::on_initialize(T::BlockNumber::zero());
 
-		let bounty_id = BountyCount::get() - 1;
+		let bounty_id = BountyCount::<I>::get() - 1;
 		let curator = T::Lookup::lookup(curator_lookup)?;
 
 		let beneficiary_account: T::AccountId = account("beneficiary", 0, SEED);
 		let beneficiary = T::Lookup::unlookup(beneficiary_account.clone());
-		Bounties::<T>::award_bounty(RawOrigin::Signed(curator.clone()).into(), bounty_id, beneficiary)?;
+		Bounties::<T, I>::award_bounty(RawOrigin::Signed(curator.clone()).into(), bounty_id, beneficiary, None)?;
 
 		frame_system::Module::<T>::set_block_number(T::BountyDepositPayoutDelay::get());
 		ensure!(T::Currency::free_balance(&beneficiary_account).is_zero(), "Beneficiary already has balance");
@@ -167,46 +328,462 @@ benchmarks! {
 		ensure!(!T::Currency::free_balance(&beneficiary_account).is_zero(), "Beneficiary didn't get paid");
 	}
 
+	sweep_unclaimed_bounty {
+		setup_pot_account::<T, I>();
+		let (curator_lookup, bounty_id) = create_bounty::<T, I>()?;
+		Bounties::<T, I>::on_initialize(T::BlockNumber::zero());
+
+		let bounty_id = BountyCount::<I>::get() - 1;
+		let curator = T::Lookup::lookup(curator_lookup)?;
+
+		let beneficiary_account: T::AccountId = account("beneficiary", 0, SEED);
+		let beneficiary = T::Lookup::unlookup(beneficiary_account.clone());
+		Bounties::<T, I>::award_bounty(RawOrigin::Signed(curator.clone()).into(), bounty_id, beneficiary, None)?;
+
+		frame_system::Module::<T>::set_block_number(
+			T::BountyDepositPayoutDelay::get() + T::ClaimTimeout::get() + 1u32.into()
+		);
+		let caller = whitelisted_caller();
+	}: _(RawOrigin::Signed(caller), bounty_id)
+	verify {
+		ensure!(T::Currency::free_balance(&beneficiary_account).is_zero(), "Beneficiary got paid");
+		ensure!(Bounties::<T, I>::bounties(bounty_id).is_none(), "Bounty not swept");
+	}
+
 	close_bounty_proposed {
-		setup_pot_account::<T>();
-		let (caller, curator, fee, value, reason) = setup_bounty::<T>(0, 0);
-		Bounties::<T>::propose_bounty(RawOrigin::Signed(caller).into(), value, reason)?;
-		let bounty_id = BountyCount::get() - 1;
-	}: close_bounty(RawOrigin::Root, bounty_id)
+		setup_pot_account::<T, I>();
+		let (caller, curator, fee, value, reason) = setup_bounty::<T, I>(0, 1);
+		Bounties::<T, I>::propose_bounty(RawOrigin::Signed(caller).into(), value, reason)?;
+		let bounty_id = BountyCount::<I>::get() - 1;
+		let remark = vec![0; T::MaximumReasonLength::get() as usize];
+	}: close_bounty(RawOrigin::Root, bounty_id, Some(remark))
 
 	close_bounty_active {
-		setup_pot_account::<T>();
-		let (curator_lookup, bounty_id) = create_bounty::<T>()?;
-		Bounties::<T>::on_initialize(T::BlockNumber::zero());
-		let bounty_id = BountyCount::get() - 1;
-	}: close_bounty(RawOrigin::Root, bounty_id)
+		setup_pot_account::<T, I>();
+		let (curator_lookup, bounty_id) = create_bounty::<T, I>()?;
+		Bounties::<T, I>::on_initialize(T::BlockNumber::zero());
+		let bounty_id = BountyCount::<I>::get() - 1;
+		let remark = vec![0; T::MaximumReasonLength::get() as usize];
+		let remark_hash = T::Hashing::hash(&remark[..]);
+	}: close_bounty(RawOrigin::Root, bounty_id, Some(remark))
 	verify {
-		assert_last_event::<T>(RawEvent::BountyCanceled(bounty_id).into())
+		assert_last_event::<T, I>(RawEvent::BountyCanceled(bounty_id, Some(remark_hash)).into())
 	}
 
 	extend_bounty_expiry {
-		setup_pot_account::<T>();
-		let (curator_lookup, bounty_id) = create_bounty::<T>()?;
-		Bounties::<T>::on_initialize(T::BlockNumber::zero());
+		setup_pot_account::<T, I>();
+		let (curator_lookup, bounty_id) = create_bounty::<T, I>()?;
+		Bounties::<T, I>::on_initialize(T::BlockNumber::zero());
 
-		let bounty_id = BountyCount::get() - 1;
+		let bounty_id = BountyCount::<I>::get() - 1;
 		let curator = T::Lookup::lookup(curator_lookup)?;
 	}: _(RawOrigin::Signed(curator), bounty_id, Vec::new())
 	verify {
-		assert_last_event::<T>(RawEvent::BountyExtended(bounty_id).into())
+		assert_last_event::<T, I>(RawEvent::BountyExtended(bounty_id).into())
+	}
+
+	set_tip_co_funding {
+		let r in 0 .. T::MaxTipReasons::get();
+
+		setup_pot_account::<T, I>();
+		let (curator_lookup, bounty_id) = create_bounty::<T, I>()?;
+		Bounties::<T, I>::on_initialize(T::BlockNumber::zero());
+
+		let bounty_id = BountyCount::<I>::get() - 1;
+		let curator = T::Lookup::lookup(curator_lookup)?;
+		let max_per_tip = T::Currency::minimum_balance();
+		// replace as many reasons as possible.
+		let old_reasons = (0 .. T::MaxTipReasons::get())
+			.map(|i| T::Hashing::hash_of(&(0u8, i)))
+			.collect::<Vec<_>>();
+		Bounties::<T, I>::set_tip_co_funding(
+			RawOrigin::Signed(curator.clone()).into(),
+			bounty_id,
+			old_reasons,
+			max_per_tip,
+		)?;
+		let reasons = (0 .. r).map(|i| T::Hashing::hash_of(&(1u8, i))).collect::<Vec<_>>();
+	}: _(RawOrigin::Signed(curator), bounty_id, reasons, max_per_tip)
+	verify {
+		assert_last_event::<T, I>(RawEvent::BountyTipCoFundingSet(bounty_id).into())
+	}
+
+	contribute_to_bounty {
+		setup_pot_account::<T, I>();
+		let (_, bounty_id) = create_bounty::<T, I>()?;
+		let contributor: T::AccountId = account("contributor", 0, SEED);
+		let amount = T::Currency::minimum_balance().saturating_mul(10u32.into());
+		let _ = T::Currency::make_free_balance_be(&contributor, amount.saturating_mul(2u32.into()));
+	}: _(RawOrigin::Signed(contributor.clone()), bounty_id, amount)
+	verify {
+		assert_last_event::<T, I>(RawEvent::BountyContribution(bounty_id, contributor, amount).into())
+	}
+
+	refund_contribution {
+		setup_pot_account::<T, I>();
+		let (_, bounty_id) = create_bounty::<T, I>()?;
+		let contributor: T::AccountId = account("contributor", 0, SEED);
+		let amount = T::Currency::minimum_balance().saturating_mul(10u32.into());
+		let _ = T::Currency::make_free_balance_be(&contributor, amount.saturating_mul(2u32.into()));
+		Bounties::<T, I>::contribute_to_bounty(
+			RawOrigin::Signed(contributor.clone()).into(),
+			bounty_id,
+			amount,
+		)?;
+		Bounties::<T, I>::close_bounty(RawOrigin::Root.into(), bounty_id, None)?;
+	}: _(RawOrigin::Signed(contributor.clone()), bounty_id)
+	verify {
+		assert_last_event::<T, I>(RawEvent::ContributionRefunded(bounty_id, contributor, amount).into())
+	}
+
+	restore_curator_slash {
+		setup_pot_account::<T, I>();
+		let (curator_lookup, bounty_id) = create_bounty::<T, I>()?;
+		Bounties::<T, I>::unassign_curator(RawOrigin::Root.into(), bounty_id)?;
+		let curator = T::Lookup::lookup(curator_lookup.clone())?;
+		let (amount, _) = Bounties::<T, I>::curator_slash_appeal(bounty_id, &curator)
+			.ok_or("Slash not held")?;
+	}: _(RawOrigin::Root, bounty_id, curator_lookup)
+	verify {
+		assert_last_event::<T, I>(RawEvent::CuratorSlashRestored(bounty_id, curator, amount).into())
+	}
+
+	settle_curator_slash {
+		setup_pot_account::<T, I>();
+		let (curator_lookup, bounty_id) = create_bounty::<T, I>()?;
+		Bounties::<T, I>::unassign_curator(RawOrigin::Root.into(), bounty_id)?;
+		let curator = T::Lookup::lookup(curator_lookup.clone())?;
+		let (amount, appeal_end) = Bounties::<T, I>::curator_slash_appeal(bounty_id, &curator)
+			.ok_or("Slash not held")?;
+		frame_system::Module::<T>::set_block_number(appeal_end + 1u32.into());
+		let caller = whitelisted_caller();
+	}: _(RawOrigin::Signed(caller), bounty_id, curator_lookup)
+	verify {
+		assert_last_event::<T, I>(RawEvent::CuratorSlashEnacted(bounty_id, curator, amount).into())
+	}
+
+	pause_new_bounties {
+	}: _(RawOrigin::Root)
+	verify {
+		assert!(Bounties::<T, I>::new_bounties_paused());
+	}
+
+	resume_new_bounties {
+		Bounties::<T, I>::pause_new_bounties(RawOrigin::Root.into())?;
+	}: _(RawOrigin::Root)
+	verify {
+		assert!(!Bounties::<T, I>::new_bounties_paused());
+	}
+
+	pause_bounty {
+		setup_pot_account::<T, I>();
+		let (_, bounty_id) = create_bounty::<T, I>()?;
+	}: _(RawOrigin::Root, bounty_id)
+	verify {
+		assert!(Bounties::<T, I>::bounty_paused(bounty_id));
+	}
+
+	unpause_bounty {
+		setup_pot_account::<T, I>();
+		let (_, bounty_id) = create_bounty::<T, I>()?;
+		Bounties::<T, I>::pause_bounty(RawOrigin::Root.into(), bounty_id)?;
+	}: _(RawOrigin::Root, bounty_id)
+	verify {
+		assert!(!Bounties::<T, I>::bounty_paused(bounty_id));
+	}
+
+	submit_work {
+		setup_pot_account::<T, I>();
+		let (_, bounty_id) = create_bounty::<T, I>()?;
+		let caller: T::AccountId = whitelisted_caller();
+		let deposit = T::WorkSubmissionDeposit::get().saturating_add(T::Currency::minimum_balance());
+		let _ = T::Currency::make_free_balance_be(&caller, deposit);
+		let submission_hash = T::Hash::default();
+	}: _(RawOrigin::Signed(caller.clone()), bounty_id, submission_hash)
+	verify {
+		assert_last_event::<T, I>(RawEvent::WorkSubmitted(bounty_id, caller, submission_hash).into())
+	}
+
+	apply_for_curator {
+		let a in 0 .. T::MaxCuratorApplicants::get() - 1;
+		setup_pot_account::<T, I>();
+		let (caller, _, fee, value, reason) = setup_bounty::<T, I>(0, MAX_BYTES);
+		Bounties::<T, I>::propose_bounty(RawOrigin::Signed(caller).into(), value, reason)?;
+		let bounty_id = BountyCount::<I>::get() - 1;
+		Bounties::<T, I>::approve_bounty(RawOrigin::Root.into(), bounty_id, Default::default())?;
+		Treasury::<T>::on_initialize(T::BlockNumber::zero());
+		let deposit = T::CuratorApplicationDeposit::get().saturating_add(T::Currency::minimum_balance());
+		for i in 0 .. a {
+			let other = account("applicant", i, SEED);
+			let _ = T::Currency::make_free_balance_be(&other, deposit);
+			Bounties::<T, I>::apply_for_curator(RawOrigin::Signed(other).into(), bounty_id, fee)?;
+		}
+		let applicant: T::AccountId = whitelisted_caller();
+		let _ = T::Currency::make_free_balance_be(&applicant, deposit);
+	}: _(RawOrigin::Signed(applicant.clone()), bounty_id, fee)
+	verify {
+		assert_last_event::<T, I>(RawEvent::CuratorApplied(bounty_id, applicant, fee).into())
+	}
+
+	select_curator {
+		let a in 1 .. T::MaxCuratorApplicants::get();
+		setup_pot_account::<T, I>();
+		let (caller, _, fee, value, reason) = setup_bounty::<T, I>(0, MAX_BYTES);
+		Bounties::<T, I>::propose_bounty(RawOrigin::Signed(caller).into(), value, reason)?;
+		let bounty_id = BountyCount::<I>::get() - 1;
+		Bounties::<T, I>::approve_bounty(RawOrigin::Root.into(), bounty_id, Default::default())?;
+		Treasury::<T>::on_initialize(T::BlockNumber::zero());
+		let deposit = T::CuratorApplicationDeposit::get().saturating_add(T::Currency::minimum_balance());
+		for i in 0 .. a {
+			let other = account("applicant", i, SEED);
+			let _ = T::Currency::make_free_balance_be(&other, deposit);
+			Bounties::<T, I>::apply_for_curator(RawOrigin::Signed(other).into(), bounty_id, fee)?;
+		}
+		// the last applicant is the slowest to find.
+		let applicant: T::AccountId = account("applicant", a - 1, SEED);
+		let applicant_lookup = T::Lookup::unlookup(applicant.clone());
+	}: _(RawOrigin::Root, bounty_id, applicant_lookup)
+	verify {
+		assert_last_event::<T, I>(RawEvent::CuratorProposed(bounty_id, applicant, fee).into())
+	}
+
+	fast_track_bounty {
+		setup_pot_account::<T, I>();
+		let (_, bounty_id) = create_bounty::<T, I>()?;
+		let origin = T::FastTrackOrigin::successful_origin();
+		let call = Call::<T, I>::fast_track_bounty(bounty_id);
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert!(Bounties::<T, I>::bounty_fast_tracked(bounty_id));
+	}
+
+	ban_curator {
+		let curator: T::AccountId = account("curator", 0, SEED);
+		let curator_lookup = T::Lookup::unlookup(curator.clone());
+	}: _(RawOrigin::Root, curator_lookup)
+	verify {
+		assert_last_event::<T, I>(RawEvent::CuratorBanned(curator).into())
+	}
+
+	unban_curator {
+		let curator: T::AccountId = account("curator", 0, SEED);
+		let curator_lookup = T::Lookup::unlookup(curator.clone());
+		Bounties::<T, I>::ban_curator(RawOrigin::Root.into(), curator_lookup.clone())?;
+	}: _(RawOrigin::Root, curator_lookup)
+	verify {
+		assert_last_event::<T, I>(RawEvent::CuratorUnbanned(curator).into())
+	}
+
+	dispute_bounty {
+		setup_pot_account::<T, I>();
+		let (curator_lookup, bounty_id) = create_bounty::<T, I>()?;
+		let curator = T::Lookup::lookup(curator_lookup)?;
+		let beneficiary = T::Lookup::unlookup(account("beneficiary", 0, SEED));
+		Bounties::<T, I>::award_bounty(RawOrigin::Signed(curator).into(), bounty_id, beneficiary, None)?;
+
+		let disputer: T::AccountId = whitelisted_caller();
+		let deposit = T::DisputeDeposit::get().saturating_add(T::Currency::minimum_balance());
+		let _ = T::Currency::make_free_balance_be(&disputer, deposit);
+		let reason_hash = T::Hashing::hash(b"not done");
+	}: _(RawOrigin::Signed(disputer.clone()), bounty_id, reason_hash)
+	verify {
+		assert_last_event::<T, I>(RawEvent::BountyDisputed(bounty_id, disputer, reason_hash).into())
+	}
+
+	// Slashing the curator is the most expensive verdict.
+	resolve_dispute {
+		setup_pot_account::<T, I>();
+		let (curator_lookup, bounty_id) = create_bounty::<T, I>()?;
+		let curator = T::Lookup::lookup(curator_lookup)?;
+		let beneficiary = T::Lookup::unlookup(account("beneficiary", 0, SEED));
+		Bounties::<T, I>::award_bounty(RawOrigin::Signed(curator).into(), bounty_id, beneficiary, None)?;
+
+		let disputer: T::AccountId = whitelisted_caller();
+		let deposit = T::DisputeDeposit::get().saturating_add(T::Currency::minimum_balance());
+		let _ = T::Currency::make_free_balance_be(&disputer, deposit);
+		let reason_hash = T::Hashing::hash(b"not done");
+		Bounties::<T, I>::dispute_bounty(RawOrigin::Signed(disputer).into(), bounty_id, reason_hash)?;
+
+		let origin = T::DisputeResolutionOrigin::successful_origin();
+		let call = Call::<T, I>::resolve_dispute(bounty_id, DisputeVerdict::SlashCurator);
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert_last_event::<T, I>(RawEvent::DisputeResolved(bounty_id, DisputeVerdict::SlashCurator).into())
+	}
+
+	purge_orphaned_description {
+		setup_pot_account::<T, I>();
+		let bounty_id = 0;
+		BountyDescriptions::<I>::insert(bounty_id, vec![0; MAX_BYTES as usize]);
+		BountyTags::<I>::insert(bounty_id, vec![vec![0; T::MaxTagLen::get() as usize]]);
+		let caller: T::AccountId = whitelisted_caller();
+	}: _(RawOrigin::Signed(caller.clone()), bounty_id)
+	verify {
+		ensure!(!BountyDescriptions::<I>::contains_key(bounty_id), "Description not purged");
+		assert_last_event::<T, I>(
+			RawEvent::OrphanedDescriptionPurged(bounty_id, caller, T::DescriptionPurgeReward::get()).into()
+		)
+	}
+
+	sweep_bounty_account {
+		setup_pot_account::<T, I>();
+		let bounty_id = 0;
+		BountyCount::<I>::put(1);
+		let amount = T::BountyValueMinimum::get();
+		let _ = T::Currency::make_free_balance_be(&Bounties::<T, I>::bounty_account_id(bounty_id), amount);
+		let caller: T::AccountId = whitelisted_caller();
+	}: _(RawOrigin::Signed(caller), bounty_id)
+	verify {
+		assert_last_event::<T, I>(RawEvent::BountyAccountSwept(bounty_id, amount).into())
+	}
+
+	award_bounty_to_location {
+		setup_pot_account::<T, I>();
+		let (curator_lookup, bounty_id) = create_bounty::<T, I>()?;
+		Bounties::<T, I>::on_initialize(T::BlockNumber::zero());
+
+		let bounty_id = BountyCount::<I>::get() - 1;
+		let curator = T::Lookup::lookup(curator_lookup)?;
+		let location = T::Paymaster::benchmark_beneficiary().ok_or("Paymaster pays no beneficiary")?;
+		let remark = vec![0; T::MaximumReasonLength::get() as usize];
+	}: _(RawOrigin::Signed(curator), bounty_id, location.clone(), Some(remark))
+	verify {
+		ensure!(BountyBeneficiaryLocations::<T, I>::get(bounty_id) == Some(location), "Not awarded to location");
+	}
+
+	claim_bounty_to_location {
+		setup_pot_account::<T, I>();
+		let (curator_lookup, bounty_id) = create_bounty::<T, I>()?;
+		Bounties::<T, I>::on_initialize(T::BlockNumber::zero());
+
+		let bounty_id = BountyCount::<I>::get() - 1;
+		let curator = T::Lookup::lookup(curator_lookup)?;
+		let location = T::Paymaster::benchmark_beneficiary().ok_or("Paymaster pays no beneficiary")?;
+		Bounties::<T, I>::award_bounty_to_location(RawOrigin::Signed(curator.clone()).into(), bounty_id, location, None)?;
+
+		frame_system::Module::<T>::set_block_number(T::BountyDepositPayoutDelay::get());
+	}: claim_bounty(RawOrigin::Signed(curator), bounty_id)
+	verify {
+		ensure!(!BountyBeneficiaryLocations::<T, I>::contains_key(bounty_id), "Payout not sent");
+	}
+
+	veto_bounty {
+		setup_pot_account::<T, I>();
+		let (curator_lookup, bounty_id) = create_bounty::<T, I>()?;
+		let curator = T::Lookup::lookup(curator_lookup)?;
+		let beneficiary = T::Lookup::unlookup(account("beneficiary", 0, SEED));
+		Bounties::<T, I>::award_bounty(RawOrigin::Signed(curator).into(), bounty_id, beneficiary, None)?;
+
+		let disputer: T::AccountId = whitelisted_caller();
+		let deposit = T::DisputeDeposit::get().saturating_add(T::Currency::minimum_balance());
+		let _ = T::Currency::make_free_balance_be(&disputer, deposit);
+		let reason_hash = T::Hashing::hash(b"not done");
+		Bounties::<T, I>::dispute_bounty(RawOrigin::Signed(disputer).into(), bounty_id, reason_hash)?;
+
+		let remark = vec![0; T::MaximumReasonLength::get() as usize];
+		let origin = T::VetoOrigin::successful_origin();
+		let call = Call::<T, I>::veto_bounty(bounty_id, Some(remark));
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		ensure!(Bounties::<T, I>::bounties(bounty_id).is_none(), "Bounty not vetoed");
+	}
+
+	increase_bounty_value {
+		setup_pot_account::<T, I>();
+		let (curator_lookup, bounty_id) = create_bounty::<T, I>()?;
+		let additional = T::BountyValueMinimum::get();
+	}: _(RawOrigin::Root, bounty_id, additional)
+	verify {
+		ensure!(BountyValueIncreases::<T, I>::get(bounty_id) == Some(additional), "Value not increased");
+	}
+
+	sponsor_curator {
+		setup_pot_account::<T, I>();
+		let (curator_lookup, bounty_id, _) = create_bounty_with_proposed_curator::<T, I>()?;
+		let curator = T::Lookup::lookup(curator_lookup.clone())?;
+		let sponsor: T::AccountId = whitelisted_caller();
+		let max_deposit = BalanceOf::<T>::max_value();
+	}: _(RawOrigin::Signed(sponsor.clone()), bounty_id, curator_lookup, max_deposit)
+	verify {
+		ensure!(CuratorSponsorships::<T, I>::get(bounty_id, &sponsor) == Some((curator, max_deposit)), "Not sponsored");
+	}
+
+	withdraw_curator_sponsorship {
+		setup_pot_account::<T, I>();
+		let (curator_lookup, bounty_id, _) = create_bounty_with_proposed_curator::<T, I>()?;
+		let sponsor: T::AccountId = whitelisted_caller();
+		Bounties::<T, I>::sponsor_curator(
+			RawOrigin::Signed(sponsor.clone()).into(),
+			bounty_id,
+			curator_lookup,
+			BalanceOf::<T>::max_value(),
+		)?;
+	}: _(RawOrigin::Signed(sponsor.clone()), bounty_id)
+	verify {
+		ensure!(!CuratorSponsorships::<T, I>::contains_key(bounty_id, &sponsor), "Still sponsored");
+	}
+
+	accept_curator_with_sponsor {
+		setup_pot_account::<T, I>();
+		let (curator_lookup, bounty_id, fee) = create_bounty_with_proposed_curator::<T, I>()?;
+		let curator = T::Lookup::lookup(curator_lookup.clone())?;
+
+		let sponsor: T::AccountId = account("sponsor", 0, SEED);
+		let _ = T::Currency::make_free_balance_be(&sponsor, fee);
+		let max_deposit = Bounties::<T, I>::calculate_curator_deposit(&fee);
+		Bounties::<T, I>::sponsor_curator(RawOrigin::Signed(sponsor.clone()).into(), bounty_id, curator_lookup, max_deposit)?;
+		let sponsor_lookup = T::Lookup::unlookup(sponsor.clone());
+	}: _(RawOrigin::Signed(curator), bounty_id, sponsor_lookup)
+	verify {
+		assert_last_event::<T, I>(RawEvent::CuratorDepositSponsored(bounty_id, sponsor, max_deposit).into())
+	}
+
+	clone_bounty {
+		let d in 1 .. MAX_BYTES;
+
+		let (caller, curator, fee, value, description) = setup_bounty::<T, I>(0, d);
+		Bounties::<T, I>::propose_bounty(RawOrigin::Signed(caller).into(), value, description)?;
+		let bounty_id = BountyCount::<I>::get() - 1;
+		let (cloner, _, _, _, _) = setup_bounty::<T, I>(1, d);
+	}: _(RawOrigin::Signed(cloner), bounty_id)
+	verify {
+		assert_last_event::<T, I>(RawEvent::BountyCloned(bounty_id + 1, bounty_id).into())
+	}
+
+	keep_bounty_outline {
+		let (caller, _curator, _fee, value, description) = setup_bounty::<T, I>(0, MAX_BYTES);
+		Bounties::<T, I>::propose_bounty(RawOrigin::Signed(caller).into(), value, description)?;
+		let bounty_id = BountyCount::<I>::get() - 1;
+		let (keeper, _, _, _, _) = setup_bounty::<T, I>(1, 0);
+	}: _(RawOrigin::Signed(keeper), bounty_id)
+	verify {
+		ensure!(Bounties::<T, I>::bounty_outline_deposit(bounty_id).is_some(), "no outline deposit");
+	}
+
+	forget_bounty_outline {
+		let (caller, _curator, _fee, value, description) = setup_bounty::<T, I>(0, MAX_BYTES);
+		Bounties::<T, I>::propose_bounty(RawOrigin::Signed(caller).into(), value, description)?;
+		let bounty_id = BountyCount::<I>::get() - 1;
+		let (keeper, _, _, _, _) = setup_bounty::<T, I>(1, 0);
+		Bounties::<T, I>::keep_bounty_outline(RawOrigin::Signed(keeper.clone()).into(), bounty_id)?;
+		Bounties::<T, I>::close_bounty(RawOrigin::Root.into(), bounty_id, None)?;
+		ensure!(Bounties::<T, I>::bounty_outline(bounty_id).is_some(), "outline not kept");
+	}: _(RawOrigin::Signed(keeper), bounty_id)
+	verify {
+		assert_last_event::<T, I>(RawEvent::BountyOutlineForgotten(bounty_id).into())
 	}
 
 	spend_funds {
-		let b in 1 .. 100;
-		setup_pot_account::<T>();
-		create_approved_bounties::<T>(b)?;
+		let b in 1 .. T::MaxApprovalsPerBlock::get();
+		setup_pot_account::<T, I>();
+		create_approved_bounties::<T, I>(b)?;
 
 		let mut budget_remaining = BalanceOf::<T>::max_value();
 		let mut imbalance = PositiveImbalanceOf::<T>::zero();
 		let mut total_weight = Weight::zero();
 		let mut missed_any = false;
 	}: {
-		<Bounties<T> as pallet_treasury::SpendFunds<T>>::spend_funds(
+		<Bounties<T, I> as pallet_treasury::SpendFunds<T>>::spend_funds(
 			&mut budget_remaining,
 			&mut imbalance,
 			&mut total_weight,
@@ -216,7 +793,7 @@ benchmarks! {
 	verify {
 		ensure!(budget_remaining < BalanceOf::<T>::max_value(), "Budget not used");
 		ensure!(missed_any == false, "Missed some");
-		assert_last_event::<T>(RawEvent::BountyBecameActive(b - 1).into())
+		assert_last_event::<T, I>(RawEvent::BountyBecameActive(b - 1).into())
 	}
 }
 
@@ -230,15 +807,56 @@ mod tests {
 	fn test_benchmarks() {
 		new_test_ext().execute_with(|| {
 			assert_ok!(test_benchmark_propose_bounty::<Test>());
+			assert_ok!(test_benchmark_propose_bounty_with_hash::<Test>());
+			assert_ok!(test_benchmark_note_bounty_description::<Test>());
+			assert_ok!(test_benchmark_set_bounty_tags::<Test>());
 			assert_ok!(test_benchmark_approve_bounty::<Test>());
+			assert_ok!(test_benchmark_approve_bounties::<Test>());
+			assert_ok!(test_benchmark_set_bounty_priority::<Test>());
+			assert_ok!(test_benchmark_set_bounty_payout_delay::<Test>());
 			assert_ok!(test_benchmark_propose_curator::<Test>());
+			assert_ok!(test_benchmark_propose_curator_with_rate::<Test>());
+			assert_ok!(test_benchmark_unassign_curator_proposed::<Test>());
 			assert_ok!(test_benchmark_unassign_curator::<Test>());
 			assert_ok!(test_benchmark_accept_curator::<Test>());
 			assert_ok!(test_benchmark_award_bounty::<Test>());
+			assert_ok!(test_benchmark_approve_self_award::<Test>());
+			assert_ok!(test_benchmark_reject_award::<Test>());
+			assert_ok!(test_benchmark_update_award_beneficiary::<Test>());
 			assert_ok!(test_benchmark_claim_bounty::<Test>());
+			assert_ok!(test_benchmark_sweep_unclaimed_bounty::<Test>());
 			assert_ok!(test_benchmark_close_bounty_proposed::<Test>());
 			assert_ok!(test_benchmark_close_bounty_active::<Test>());
 			assert_ok!(test_benchmark_extend_bounty_expiry::<Test>());
+			assert_ok!(test_benchmark_set_tip_co_funding::<Test>());
+			assert_ok!(test_benchmark_contribute_to_bounty::<Test>());
+			assert_ok!(test_benchmark_refund_contribution::<Test>());
+			assert_ok!(test_benchmark_restore_curator_slash::<Test>());
+			assert_ok!(test_benchmark_settle_curator_slash::<Test>());
+			assert_ok!(test_benchmark_pause_new_bounties::<Test>());
+			assert_ok!(test_benchmark_resume_new_bounties::<Test>());
+			assert_ok!(test_benchmark_pause_bounty::<Test>());
+			assert_ok!(test_benchmark_unpause_bounty::<Test>());
+			assert_ok!(test_benchmark_submit_work::<Test>());
+			assert_ok!(test_benchmark_apply_for_curator::<Test>());
+			assert_ok!(test_benchmark_select_curator::<Test>());
+			assert_ok!(test_benchmark_fast_track_bounty::<Test>());
+			assert_ok!(test_benchmark_ban_curator::<Test>());
+			assert_ok!(test_benchmark_unban_curator::<Test>());
+			assert_ok!(test_benchmark_dispute_bounty::<Test>());
+			assert_ok!(test_benchmark_resolve_dispute::<Test>());
+			assert_ok!(test_benchmark_purge_orphaned_description::<Test>());
+			assert_ok!(test_benchmark_sweep_bounty_account::<Test>());
+			assert_ok!(test_benchmark_award_bounty_to_location::<Test>());
+			assert_ok!(test_benchmark_claim_bounty_to_location::<Test>());
+			assert_ok!(test_benchmark_veto_bounty::<Test>());
+			assert_ok!(test_benchmark_increase_bounty_value::<Test>());
+			assert_ok!(test_benchmark_sponsor_curator::<Test>());
+			assert_ok!(test_benchmark_withdraw_curator_sponsorship::<Test>());
+			assert_ok!(test_benchmark_accept_curator_with_sponsor::<Test>());
+			assert_ok!(test_benchmark_clone_bounty::<Test>());
+			assert_ok!(test_benchmark_keep_bounty_outline::<Test>());
+			assert_ok!(test_benchmark_forget_bounty_outline::<Test>());
 			assert_ok!(test_benchmark_spend_funds::<Test>());
 		});
 	}
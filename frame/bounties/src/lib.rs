@@ -35,6 +35,21 @@
 //! curator or once the bounty is active or payout is pending, resulting in the slash of the
 //! curator's deposit.
 //!
+//! The pallet is instantiable, so that a runtime can run several bounty tracks with their own
+//! parameters, storage and events. All of them are funded by the treasury this pallet is coupled
+//! to; give each instance its own `BountyAccountTag` and add it to the treasury's `SpendFunds`.
+//!
+//! Once a bounty is awarded, its claim is scheduled with the `Scheduler` for the end of the payout
+//! delay, so that nobody has to remember to call `claim_bounty`. A scheduled claim that fails
+//! because the bounty is paused or disputed at the time is scheduled again once it is unpaused or
+//! the dispute is resolved.
+//!
+//! The `ProposerNotifier` is told when a bounty proposal is approved, rejected or funded, so that a
+//! runtime can pass the news on to the proposer, e.g. as an on-chain message.
+//!
+//! The pallet keeps count of the bounties funded and of those still active, and sums up the
+//! value paid out to beneficiaries and curators, for dashboards to read without scanning storage.
+//!
 //!
 //! ### Terminology
 //!
@@ -54,6 +69,12 @@
 //! - **Payout Delay:** The delay period for which a bounty beneficiary needs to wait before
 //!   claiming.
 //! - **Curator fee:** The reserved upfront payment for a curator for work related to the bounty.
+//! - **Appeal period:** The delay for which a curator deposit slashed by the Council is held and
+//!   may be restored before it is finally slashed.
+//! - **Dispute:** A challenge of the award of a bounty, backed by a deposit, which keeps the
+//!   payout from being claimed until `DisputeResolutionOrigin` rules on it.
+//! - **Funding source:** The pot, such as a treasury instance, that an approved bounty is funded
+//!   from and that its remaining funds return to if it is cancelled.
 //!
 //! ## Interface
 //!
@@ -62,38 +83,93 @@
 //! Bounty protocol:
 //! - `propose_bounty` - Propose a specific treasury amount to be earmarked for a predefined set of
 //!   tasks and stake the required deposit.
+//! - `propose_bounty_with_hash` - Like `propose_bounty`, but only the hash of the description is
+//!   stored on chain.
+//! - `note_bounty_description` - Store the description matching the hash of a bounty on chain.
+//! - `set_bounty_tags` - Tag a bounty proposal, e.g. with the domain of the work.
 //! - `approve_bounty` - Accept a specific treasury amount to be earmarked for a predefined body of
 //!   work.
+//! - `approve_bounties` - Approve several bounty proposals in one call.
+//! - `set_bounty_priority` - Have an approved bounty funded before others.
+//! - `set_bounty_payout_delay` - Shorten or lengthen the payout delay of a single bounty.
 //! - `propose_curator` - Assign an account to a bounty as candidate curator.
+//! - `propose_curator_with_rate` - Assign a candidate curator whose fee is a share of the bounty
+//!   paid out.
 //! - `accept_curator` - Accept a bounty assignment from the Council, setting a curator deposit.
 //! - `extend_bounty_expiry` - Extend the expiry block number of the bounty and stay active.
 //! - `award_bounty` - Close and pay out the specified amount for the completed work.
+//! - `approve_self_award` - Allow the curator of a bounty to award it to themselves.
+//! - `reject_award` - Turn down an award as its beneficiary, so the curator can award it again.
+//! - `update_award_beneficiary` - Correct the beneficiary of an award before it can be claimed.
 //! - `claim_bounty` - Claim a specific bounty amount from the Payout Address.
+//! - `sweep_unclaimed_bounty` - Return an awarded bounty that was never claimed to its funding
+//!   source.
 //! - `unassign_curator` - Unassign an accepted curator from a specific earmark.
 //! - `close_bounty` - Cancel the earmark for a specific treasury amount and close the bounty.
+//! - `contribute_to_bounty` - Add funds to a funded or active bounty.
+//! - `set_tip_co_funding` - Let an active bounty co-fund the tips for some reasons.
+//! - `refund_contribution` - Get back a contribution to a cancelled bounty.
+//! - `restore_curator_slash` - Return a curator deposit slashed by the Council on appeal.
+//! - `settle_curator_slash` - Pass on a slashed curator deposit once it can no longer be appealed.
+//! - `pause_new_bounties` - Stop accepting new bounty proposals.
+//! - `resume_new_bounties` - Accept new bounty proposals again.
+//! - `pause_bounty` - Freeze a bounty in its current status.
+//! - `unpause_bounty` - Let a paused bounty progress again.
+//! - `submit_work` - Record work done for an active bounty.
+//! - `apply_for_curator` - Apply to curate a funded bounty for a fee.
+//! - `select_curator` - Assign one of the applicants to a bounty as candidate curator.
+//! - `fast_track_bounty` - Halve the payout delay and update period of an urgent bounty.
+//! - `ban_curator` - Stop an account from being proposed as curator.
+//! - `unban_curator` - Let a banned account be proposed as curator again.
+//! - `dispute_bounty` - Stop an awarded bounty from being claimed until the dispute is resolved.
+//! - `resolve_dispute` - Rule on a disputed award: release, re-award or slash the curator.
+//! - `purge_orphaned_description` - Remove the description of a bounty that no longer exists, for
+//!   a reward.
+//! - `sweep_bounty_account` - Return what is left in the account of a bounty that no longer exists
+//!   to the treasury.
+//! - `award_bounty_to_location` - Award a bounty to a beneficiary that is not a local account,
+//!   e.g. on another chain.
+//! - `veto_bounty` - Cancel a bounty at any stage, even once its payout is pending.
+//! - `increase_bounty_value` - Increase the value of a funded bounty as the work grows.
+//! - `sponsor_curator` - Agree to have the deposit of a curator reserved from the origin.
+//! - `withdraw_curator_sponsorship` - Withdraw from sponsoring the deposit of a curator.
+//! - `accept_curator_with_sponsor` - Accept the curator role with the deposit reserved from a
+//!   sponsor.
+//! - `clone_bounty` - Propose a new bounty with the value and description of another.
+//! - `keep_bounty_outline` - Keep the value and description hash of a bounty once it is removed.
+//! - `forget_bounty_outline` - Stop keeping the outline of a bounty and get the deposit back.
+//!
+//! ### Signed Extensions
+//!
+//! - `CuratorFeeExemption` - Refund the weight fees of curators for looking after their bounties.
 
 #![cfg_attr(not(feature = "std"), no_std)]
+#![recursion_limit = "256"]
 
 mod tests;
 mod benchmarking;
 pub mod weights;
 
-use sp_std::prelude::*;
+use sp_std::{prelude::*, marker::PhantomData, cmp::Reverse};
 
-use frame_support::{decl_module, decl_storage, decl_event, ensure, decl_error};
+use frame_support::{
+	decl_module, decl_storage, decl_event, ensure, decl_error, IterableStorageMap, StorageMap, Parameter,
+	transactional,
+};
 
 use frame_support::traits::{
-	Currency, Get, Imbalance, OnUnbalanced, ExistenceRequirement::{AllowDeath},
-	ReservableCurrency};
+	Currency, Get, Imbalance, OnUnbalanced, ExistenceRequirement::{AllowDeath, KeepAlive},
+	ReservableCurrency, WithdrawReasons, IsSubType, Filter};
 
-use sp_runtime::{Permill, RuntimeDebug, DispatchResult, traits::{
-	Zero, StaticLookup, AccountIdConversion, Saturating, BadOrigin
-}};
+use sp_runtime::{Permill, RuntimeDebug, DispatchResult, DispatchError, traits::{
+	Zero, One, StaticLookup, AccountIdConversion, Saturating, BadOrigin, Hash, AtLeast32BitUnsigned,
+	SignedExtension, Dispatchable, DispatchInfoOf, PostDispatchInfoOf,
+}, transaction_validity::{TransactionValidity, TransactionValidityError}};
 
 use frame_support::dispatch::DispatchResultWithPostInfo;
-use frame_support::traits::{EnsureOrigin};
+use frame_support::traits::{EnsureOrigin, schedule::{Named as ScheduleNamed, DispatchTime, LOWEST_PRIORITY}};
 
-use frame_support::weights::{Weight};
+use frame_support::weights::{Weight, PostDispatchInfo};
 
 use codec::{Encode, Decode};
 use frame_system::{self as system, ensure_signed};
@@ -103,7 +179,95 @@ type BalanceOf<T> = pallet_treasury::BalanceOf<T>;
 
 type PositiveImbalanceOf<T> = pallet_treasury::PositiveImbalanceOf<T>;
 
-pub trait Config: frame_system::Config + pallet_treasury::Config {
+type NegativeImbalanceOf<T> = pallet_treasury::NegativeImbalanceOf<T>;
+
+/// The location of a beneficiary that is not a local account, as understood by the `Paymaster`.
+pub type BeneficiaryLocationOf<T, I = DefaultInstance> =
+	<<T as Config<I>>::Paymaster as BountyPaymaster<<T as frame_system::Config>::AccountId, BalanceOf<T>>>::Beneficiary;
+
+/// The prefix of the names of the claims scheduled for awarded bounties.
+const BOUNTIES_ID: [u8; 8] = *b"bounties";
+
+/// A pot which approved bounties can be funded from, such as a treasury instance.
+///
+/// The default funding source is the treasury this pallet is coupled to.
+pub trait BountyFundingSource<T: Config<I>, I: Instance = DefaultInstance>: Parameter + Copy + Default {
+	/// The account that the remaining funds of a cancelled bounty are returned to.
+	fn account_id(&self) -> T::AccountId;
+}
+
+impl<T: Config<I>, I: Instance> BountyFundingSource<T, I> for () {
+	fn account_id(&self) -> T::AccountId {
+		Module::<T, I>::account_id()
+	}
+}
+
+/// Lookup of a human-readable handle for an account, such as the hash of its on-chain identity.
+///
+/// Used to annotate the events of this pallet for dashboards; `()` never finds a handle.
+pub trait IdentityProvider<AccountId, Hash> {
+	/// The handle of `who`, if it has one.
+	fn identity_of(who: &AccountId) -> Option<Hash>;
+}
+
+impl<AccountId, Hash> IdentityProvider<AccountId, Hash> for () {
+	fn identity_of(_: &AccountId) -> Option<Hash> {
+		None
+	}
+}
+
+/// Hook to notify the proposer of a bounty when its proposal is approved, rejected or funded,
+/// e.g. through an on-chain messaging pallet.
+///
+/// Every method does nothing by default, as does `()`.
+pub trait ProposerNotifier<AccountId> {
+	/// The proposal `bounty_id` of `proposer` was approved.
+	fn on_approved(_proposer: &AccountId, _bounty_id: BountyIndex) {}
+
+	/// The proposal `bounty_id` of `proposer` was rejected.
+	fn on_rejected(_proposer: &AccountId, _bounty_id: BountyIndex) {}
+
+	/// The approved bounty `bounty_id` of `proposer` was funded, and their bond returned.
+	fn on_funded(_proposer: &AccountId, _bounty_id: BountyIndex) {}
+}
+
+impl<AccountId> ProposerNotifier<AccountId> for () {}
+
+/// Payout of awarded bounties to beneficiaries that are not local accounts, e.g. accounts on
+/// another chain that are paid through XCM.
+///
+/// `()` knows no such beneficiaries, so bounties can only be awarded to local accounts with it.
+pub trait BountyPaymaster<AccountId, Balance> {
+	/// The location of a beneficiary, e.g. an XCM `MultiLocation`.
+	type Beneficiary: Parameter;
+
+	/// Whether payouts can be sent to `to`.
+	fn can_pay(to: &Self::Beneficiary) -> bool;
+
+	/// Send `amount` from the local account `from` to `to`.
+	fn pay(from: &AccountId, to: &Self::Beneficiary, amount: Balance) -> DispatchResult;
+
+	/// A beneficiary that payouts can be sent to, to benchmark awarding and claiming bounties
+	/// with. `None` if there is none, in which case these benchmarks cannot be run.
+	#[cfg(feature = "runtime-benchmarks")]
+	fn benchmark_beneficiary() -> Option<Self::Beneficiary> {
+		None
+	}
+}
+
+impl<AccountId, Balance> BountyPaymaster<AccountId, Balance> for () {
+	type Beneficiary = ();
+
+	fn can_pay(_: &()) -> bool {
+		false
+	}
+
+	fn pay(_: &AccountId, _: &(), _: Balance) -> DispatchResult {
+		Err(DispatchError::Other("no beneficiaries other than local accounts"))
+	}
+}
+
+pub trait Config<I: Instance = DefaultInstance>: frame_system::Config + pallet_treasury::Config {
 
 	/// The amount held on deposit for placing a bounty proposal.
 	type BountyDepositBase: Get<BalanceOf<Self>>;
@@ -114,9 +278,36 @@ pub trait Config: frame_system::Config + pallet_treasury::Config {
 	/// Bounty duration in blocks.
 	type BountyUpdatePeriod: Get<Self::BlockNumber>;
 
+	/// The number of blocks after the payout delay during which the beneficiary can claim an
+	/// awarded bounty, after which it may be swept back to its funding source.
+	type ClaimTimeout: Get<Self::BlockNumber>;
+
 	/// Percentage of the curator fee that will be reserved upfront as deposit for bounty curator.
 	type BountyCuratorDeposit: Get<Permill>;
 
+	/// Percentage of the proposer's bond that is slashed when a bounty proposal is rejected. The
+	/// rest of the bond is returned to the proposer.
+	type BountyRejectionSlash: Get<Permill>;
+
+	/// The number of blocks for which a curator deposit slashed by `RejectOrigin` is held, and
+	/// may be restored by `AppealOrigin`, before it is passed on to `OnSlash`.
+	type AppealPeriod: Get<Self::BlockNumber>;
+
+	/// Origin from which a curator deposit slashed by `RejectOrigin` may be restored.
+	type AppealOrigin: EnsureOrigin<Self::Origin>;
+
+	/// Whether a curator needs the approval of `ApproveOrigin`, through `approve_self_award`, to
+	/// award a bounty to themselves.
+	type RequireSelfAwardApproval: Get<bool>;
+
+	/// Minimum amount of funds that should be placed in a deposit for accepting a curator role,
+	/// regardless of the curator fee. `None` means there is no floor.
+	type CuratorDepositMin: Get<Option<BalanceOf<Self>>>;
+
+	/// Maximum amount of funds that should be placed in a deposit for accepting a curator role,
+	/// regardless of the curator fee. `None` means there is no ceiling.
+	type CuratorDepositMax: Get<Option<BalanceOf<Self>>>;
+
 	/// Minimum value for a bounty.
 	type BountyValueMinimum: Get<BalanceOf<Self>>;
 
@@ -124,11 +315,106 @@ pub trait Config: frame_system::Config + pallet_treasury::Config {
 	type DataDepositPerByte: Get<BalanceOf<Self>>;
 
 	/// The overarching event type.
-	type Event: From<Event<Self>> + Into<<Self as frame_system::Config>::Event>;
+	type Event: From<Event<Self, I>> + Into<<Self as frame_system::Config>::Event>;
 
 	/// Maximum acceptable reason length.
 	type MaximumReasonLength: Get<u32>;
 
+	/// Maximum number of tags a bounty proposal can carry.
+	type MaxTags: Get<u32>;
+
+	/// Maximum length of a single bounty tag, in bytes.
+	type MaxTagLen: Get<u32>;
+
+	/// Maximum number of tip reasons a single bounty can co-fund.
+	type MaxTipReasons: Get<u32>;
+
+	/// Maximum number of bounties that may be approved but not yet funded at any one time.
+	type MaxApprovals: Get<u32>;
+
+	/// Maximum number of approved bounties of a funding source considered for funding in a
+	/// single spend period. The rest are considered in later spend periods.
+	type MaxApprovalsPerBlock: Get<u32>;
+
+	/// Maximum number of bounties an account may be the proposer, or the curator, of at any one
+	/// time.
+	type MaxBountiesPerAccount: Get<u32>;
+
+	/// Maximum number of bounty proposals an account may have waiting for approval or rejection
+	/// at any one time.
+	type MaxProposalsPerAccount: Get<u32>;
+
+	/// Maximum total value of the bounties that are approved and not yet paid out or closed, so
+	/// that bounties cannot lock up the whole pot.
+	type MaxTotalActiveBountyValue: Get<BalanceOf<Self>>;
+
+	/// Maximum number of work submissions recorded for a single bounty.
+	type MaxWorkSubmissions: Get<u32>;
+
+	/// The amount held on deposit for each work submission.
+	type WorkSubmissionDeposit: Get<BalanceOf<Self>>;
+
+	/// Maximum number of accounts that may apply to curate a single bounty.
+	type MaxCuratorApplicants: Get<u32>;
+
+	/// The amount held on deposit for applying to curate a bounty.
+	type CuratorApplicationDeposit: Get<BalanceOf<Self>>;
+
+	/// Origin from which a bounty may be fast-tracked, e.g. for an urgent security fix.
+	type FastTrackOrigin: EnsureOrigin<Self::Origin>;
+
+	/// The amount held on deposit for disputing the award of a bounty.
+	type DisputeDeposit: Get<BalanceOf<Self>>;
+
+	/// Origin from which disputed awards are ruled on.
+	type DisputeResolutionOrigin: EnsureOrigin<Self::Origin>;
+
+	/// The reward paid from the treasury pot to whoever purges an orphaned bounty description.
+	type DescriptionPurgeReward: Get<BalanceOf<Self>>;
+
+	/// The tip paid from the account of a bounty to whoever claims it for its beneficiary or
+	/// sweeps it once unclaimed.
+	type MaintenanceTip: Get<BalanceOf<Self>>;
+
+	/// The tag of the treasury sub-accounts that the bounties of this instance are held in, e.g.
+	/// `"bt"`. Each instance needs its own tag, of at most two bytes, so that their bounty accounts
+	/// differ.
+	type BountyAccountTag: Get<&'static str>;
+
+	/// Lookup of the identity handle included in curator and beneficiary events.
+	type IdentityProvider: IdentityProvider<Self::AccountId, Self::Hash>;
+
+	/// The hook told when a bounty proposal is approved, rejected or funded.
+	type ProposerNotifier: ProposerNotifier<Self::AccountId>;
+
+	/// The accounts that may curate bounties, e.g. those with a registered identity or the
+	/// members of a collective. `()` lets anyone through.
+	type CuratorFilter: Filter<Self::AccountId>;
+
+	/// The pots that a bounty may be funded from. Bounties of the default source are funded by
+	/// this pallet's `SpendFunds` implementation, those of any other source through
+	/// `SpendFundsFrom`.
+	type FundingSource: BountyFundingSource<Self, I>;
+
+	/// The runtime call type, which the automatic claims of awarded bounties are scheduled as.
+	type BountyCall: From<Call<Self, I>>;
+
+	/// The scheduler that claims each awarded bounty once its payout delay is over.
+	type Scheduler: ScheduleNamed<Self::BlockNumber, Self::BountyCall, Self::PalletsOrigin>;
+
+	/// The caller origin of the runtime, which automatic claims are dispatched with.
+	type PalletsOrigin: From<frame_system::RawOrigin<Self::AccountId>>;
+
+	/// Pays out bounties awarded to beneficiaries that are not local accounts.
+	type Paymaster: BountyPaymaster<Self::AccountId, BalanceOf<Self>>;
+
+	/// Origin from which a bounty may be vetoed at any stage, even once its payout is pending.
+	type VetoOrigin: EnsureOrigin<Self::Origin>;
+
+	/// Maximum number of accounts that may agree to sponsor the curator deposit of a single
+	/// bounty at once.
+	type MaxCuratorSponsors: Get<u32>;
+
 	/// Weight information for extrinsics in this pallet.
 	type WeightInfo: WeightInfo;
 }
@@ -136,19 +422,115 @@ pub trait Config: frame_system::Config + pallet_treasury::Config {
 /// An index of a bounty. Just a `u32`.
 pub type BountyIndex = u32;
 
-/// A bounty proposal.
+/// The priority of an approved bounty in being funded. Just a `u8`; higher is funded first.
+pub type BountyPriority = u8;
+
+/// The bounty type of a runtime.
+pub type BountyOf<T> = Bounty<
+	<T as frame_system::Config>::AccountId,
+	BalanceOf<T>,
+	<T as frame_system::Config>::BlockNumber,
+>;
+
+/// The rules in force when a bounty was proposed. The bounty is settled under them even if the
+/// runtime changes them while it is in flight.
 #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
-pub struct Bounty<AccountId, Balance, BlockNumber> {
-	/// The account proposing it.
-	proposer: AccountId,
+pub struct BountyTerms<BlockNumber> {
+	/// The `BountyDepositPayoutDelay` of the bounty.
+	pub payout_delay: BlockNumber,
+	/// The `BountyRejectionSlash` of the bond of the bounty proposal.
+	pub rejection_slash: Permill,
+}
+
+/// The track record of an account as a bounty curator.
+#[derive(Encode, Decode, Clone, Default, PartialEq, Eq, RuntimeDebug)]
+pub struct CuratorRecord<Balance> {
+	/// The number of bounties the account curated until they were paid out.
+	pub bounties_completed: u32,
+	/// The number of times the account was slashed when unassigned as curator, not counting
+	/// slashes restored on appeal.
+	pub times_slashed: u32,
+	/// The total curator fees the account was paid.
+	pub fees_earned: Balance,
+}
+
+/// A dispute of the award of a bounty.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct BountyDispute<AccountId, Balance, Hash> {
+	/// The account that raised the dispute.
+	pub disputer: AccountId,
+	/// The amount held on deposit for the dispute.
+	pub deposit: Balance,
+	/// The hash of the reason for the dispute.
+	pub reason_hash: Hash,
+}
+
+/// The ruling of `DisputeResolutionOrigin` on a disputed award.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub enum DisputeVerdict<AccountId> {
+	/// The award stands and may be claimed. The deposit of the disputer is slashed.
+	Release,
+	/// The bounty is awarded to another beneficiary, with a new payout delay. The deposit of the
+	/// disputer is returned.
+	ReAward(AccountId),
+	/// The curator is unassigned and slashed, and the bounty is funded again. The deposit of the
+	/// disputer is returned.
+	SlashCurator,
+}
+
+/// The balances of a bounty, and the arithmetic on them when a bounty is settled.
+#[derive(Encode, Decode, Clone, Default, PartialEq, Eq, RuntimeDebug)]
+pub struct BountyFinance<Balance> {
 	/// The (total) amount that should be paid if the bounty is rewarded.
-	value: Balance,
+	pub value: Balance,
 	/// The curator fee. Included in value.
-	fee: Balance,
+	pub fee: Balance,
 	/// The deposit of curator.
-	curator_deposit: Balance,
+	pub curator_deposit: Balance,
 	/// The amount held on deposit (reserved) for making this proposal.
-	bond: Balance,
+	pub bond: Balance,
+}
+
+impl<Balance: AtLeast32BitUnsigned + Copy> BountyFinance<Balance> {
+	/// Split `balance`, the amount paid out of the bounty account, into the curator fee and the
+	/// rest. The fee is `rate` of `balance` if the curator was proposed with a rate, else `fee`,
+	/// and never more than `balance`.
+	pub fn payout_split(&self, balance: Balance, rate: Option<Permill>) -> (Balance, Balance) {
+		let fee = match rate {
+			Some(rate) => rate * balance,
+			None => self.fee,
+		}.min(balance);
+		(fee, balance - fee)
+	}
+
+	/// The deposit due from the curator for `fee`: `rate` of it, clamped between `min` and `max`
+	/// when those are set.
+	pub fn deposit_due(&self, rate: Permill, min: Option<Balance>, max: Option<Balance>) -> Balance {
+		let mut deposit = rate * self.fee;
+		if let Some(max) = max {
+			deposit = deposit.min(max);
+		}
+		if let Some(min) = min {
+			deposit = deposit.max(min);
+		}
+		deposit
+	}
+
+	/// Split `bond` into the part slashed for a rejected proposal, `rejection_slash` of it, and
+	/// the part refunded to the proposer.
+	pub fn bond_split(&self, rejection_slash: Permill) -> (Balance, Balance) {
+		let slash = rejection_slash * self.bond;
+		(slash, self.bond - slash)
+	}
+}
+
+/// A bounty proposal.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct Bounty<AccountId, Balance, BlockNumber> {
+	/// The account proposing it.
+	proposer: AccountId,
+	/// The value, curator fee and deposits of this bounty.
+	finance: BountyFinance<Balance>,
 	/// The status of this bounty.
 	status: BountyStatus<AccountId, BlockNumber>,
 }
@@ -185,12 +567,113 @@ pub enum BountyStatus<AccountId, BlockNumber> {
 	},
 }
 
+impl<AccountId, BlockNumber> BountyStatus<AccountId, BlockNumber> {
+	/// The kind of this status, without its details.
+	pub fn kind(&self) -> BountyStatusKind {
+		match self {
+			BountyStatus::Proposed => BountyStatusKind::Proposed,
+			BountyStatus::Approved => BountyStatusKind::Approved,
+			BountyStatus::Funded => BountyStatusKind::Funded,
+			BountyStatus::CuratorProposed { .. } => BountyStatusKind::CuratorProposed,
+			BountyStatus::Active { .. } => BountyStatusKind::Active,
+			BountyStatus::PendingPayout { .. } => BountyStatusKind::PendingPayout,
+		}
+	}
+
+	/// The curator of the bounty, whether only proposed or already accepted.
+	pub fn curator(&self) -> Option<&AccountId> {
+		match self {
+			BountyStatus::CuratorProposed { curator } |
+			BountyStatus::Active { curator, .. } |
+			BountyStatus::PendingPayout { curator, .. } => Some(curator),
+			_ => None,
+		}
+	}
+
+	/// Move the bounty on to the status `to`.
+	///
+	/// The move must be an edge of the state machine, see `BountyStatusKind::can_transition_to`.
+	/// This is only checked in debug builds; callers check the status they start from themselves.
+	pub fn transition(&mut self, to: Self) {
+		debug_assert!(
+			self.kind().can_transition_to(to.kind()),
+			"illegal bounty status transition from {:?} to {:?}",
+			self.kind(),
+			to.kind(),
+		);
+		*self = to;
+	}
+}
+
+/// The kind of a `BountyStatus`, used to look up bounties by status.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub enum BountyStatusKind {
+	/// See `BountyStatus::Proposed`.
+	Proposed,
+	/// See `BountyStatus::Approved`.
+	Approved,
+	/// See `BountyStatus::Funded`.
+	Funded,
+	/// See `BountyStatus::CuratorProposed`.
+	CuratorProposed,
+	/// See `BountyStatus::Active`.
+	Active,
+	/// See `BountyStatus::PendingPayout`.
+	PendingPayout,
+}
+
+impl BountyStatusKind {
+	/// Whether a bounty may move on from a status of this kind to one of the kind `to`.
+	///
+	/// A bounty leaves the state machine by being closed, claimed or swept, which removes it rather
+	/// than moving it on. A status changing only in its details, such as an active bounty being
+	/// extended, is not a move either, except for the award of a disputed bounty being given to
+	/// another beneficiary.
+	pub fn can_transition_to(self, to: BountyStatusKind) -> bool {
+		use BountyStatusKind::*;
+		matches!(
+			(self, to),
+			// approval and funding.
+			(Proposed, Approved) |
+			(Approved, Funded) |
+			// curator assignment, which may start before funding.
+			(Proposed, CuratorProposed) |
+			(Approved, CuratorProposed) |
+			(Funded, CuratorProposed) |
+			(CuratorProposed, Active) |
+			// award, rejection of the award and re-award by dispute resolution.
+			(Active, PendingPayout) |
+			(PendingPayout, Active) |
+			(PendingPayout, PendingPayout) |
+			// unassignment of the curator, including when funding a bounty with a proposed one.
+			(CuratorProposed, Funded) |
+			(Active, Funded) |
+			(PendingPayout, Funded)
+		)
+	}
+}
+
+// A value placed in storage that represents the current version of the Bounties storage.
+// This value is used by the `on_runtime_upgrade` logic to determine whether we run
+// storage migration logic.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+enum Releases {
+	V1,
+	V2,
+}
+
+impl Default for Releases {
+	fn default() -> Self {
+		Releases::V1
+	}
+}
+
 // Note :: For backward compatibility reasons,
 // pallet-bounties uses Treasury for storage.
 // This is temporary solution, soon will get replaced with
 // Own storage identifier.
 decl_storage! {
-	trait Store for Module<T: Config> as Treasury {
+	trait Store for Module<T: Config<I>, I: Instance=DefaultInstance> as Treasury {
 
 		/// Number of bounty proposals that have been made.
 		pub BountyCount get(fn bounty_count): BountyIndex;
@@ -203,37 +686,337 @@ decl_storage! {
 		/// The description of each bounty.
 		pub BountyDescriptions get(fn bounty_descriptions): map hasher(twox_64_concat) BountyIndex => Option<Vec<u8>>;
 
-		/// Bounty indices that have been approved but not yet funded.
+		/// The tags of each bounty that has any, such as the domain of the work, for filtering
+		/// bounties without parsing their descriptions.
+		pub BountyTags get(fn bounty_tags): map hasher(twox_64_concat) BountyIndex => Vec<Vec<u8>>;
+
+		/// The description hash of each bounty proposed through `propose_bounty_with_hash`.
+		pub BountyDescriptionHashes get(fn bounty_description_hash):
+		map hasher(twox_64_concat) BountyIndex => Option<T::Hash>;
+
+		/// The account which noted the description preimage of a bounty and the amount it holds on
+		/// deposit for it.
+		pub BountyDescriptionDeposits get(fn bounty_description_deposit):
+		map hasher(twox_64_concat) BountyIndex => Option<(T::AccountId, BalanceOf<T>)>;
+
+		/// The hash of each bounty's description as it was when the bounty was approved.
+		pub BountyApprovedDescriptionHashes get(fn bounty_approved_description_hash):
+		map hasher(twox_64_concat) BountyIndex => Option<T::Hash>;
+
+		/// The amount each account has contributed to a bounty on top of its value. Refundable
+		/// through `refund_contribution` if the bounty is cancelled.
+		pub BountyContributions get(fn bounty_contribution):
+		double_map hasher(twox_64_concat) BountyIndex, hasher(twox_64_concat) T::AccountId
+		=> BalanceOf<T>;
+
+		/// The total amount contributed to each bounty that has not been paid out or refunded.
+		pub BountyContributionTotals get(fn bounty_contribution_total):
+		map hasher(twox_64_concat) BountyIndex => BalanceOf<T>;
+
+		/// The priority of each approved bounty in `spend_funds`, if set. Bounties of higher
+		/// priority are funded first.
+		pub BountyPriorities get(fn bounty_priority):
+		map hasher(twox_64_concat) BountyIndex => BountyPriority;
+
+		/// The delay between awarding each bounty and the payout being claimable, where it was set
+		/// through `set_bounty_payout_delay`.
+		pub BountyPayoutDelays get(fn bounty_payout_delay):
+		map hasher(twox_64_concat) BountyIndex => Option<T::BlockNumber>;
+
+		/// The rules in force when each bounty was proposed. Bounties proposed before they were
+		/// recorded have none, and are settled under the current rules.
+		pub BountyProposalTerms get(fn bounty_terms):
+		map hasher(twox_64_concat) BountyIndex => Option<BountyTerms<T::BlockNumber>>;
+
+		/// The curator fee of each bounty, as a share of the bounty account balance at payout,
+		/// where it was proposed as a rate rather than an absolute amount.
+		pub BountyCuratorFeeRates get(fn bounty_curator_fee_rate):
+		map hasher(twox_64_concat) BountyIndex => Option<Permill>;
+
+		/// The bounty that co-funds the tips for each tip reason hash, and the most it pays
+		/// towards any one of them.
+		pub TipCoFunding get(fn tip_co_funding):
+		map hasher(identity) T::Hash => Option<(BountyIndex, BalanceOf<T>)>;
+
+		/// The tip reason hashes each bounty co-funds. Holds at most `MaxTipReasons` entries per
+		/// bounty.
+		pub BountyTipReasons get(fn bounty_tip_reasons):
+		map hasher(twox_64_concat) BountyIndex => Vec<T::Hash>;
+
+		/// The curator that `ApproveOrigin` allowed to award each bounty to themselves.
+		pub SelfAwardApprovals get(fn self_award_approval):
+		map hasher(twox_64_concat) BountyIndex => Option<T::AccountId>;
+
+		/// The funding source each bounty was approved to be funded from.
+		pub BountyFundingSources get(fn bounty_funding_source):
+		map hasher(twox_64_concat) BountyIndex => T::FundingSource;
+
+		/// Whether new bounty proposals are currently rejected. Existing bounties are unaffected.
+		pub NewBountiesPaused get(fn new_bounties_paused): bool;
+
+		/// The bounties that `RejectOrigin` has paused. A paused bounty keeps its status until it
+		/// is unpaused.
+		pub PausedBounties get(fn bounty_paused):
+		map hasher(twox_64_concat) BountyIndex => bool;
+
+		/// Bounty indices that have been approved but not yet funded. Holds at most
+		/// `MaxApprovals` entries.
 		pub BountyApprovals get(fn bounty_approvals): Vec<BountyIndex>;
+
+		/// The total value of the bounties that are approved and not yet paid out or closed.
+		/// Bounded by `MaxTotalActiveBountyValue`.
+		pub TotalActiveBountyValue get(fn total_active_bounty_value): BalanceOf<T>;
+
+		/// The bounties proposed by each account, sorted. Holds at most `MaxBountiesPerAccount`
+		/// entries per account.
+		pub BountiesByProposer get(fn bounties_by_proposer):
+		map hasher(twox_64_concat) T::AccountId => Vec<BountyIndex>;
+
+		/// The number of bounty proposals of each account that are waiting for approval or
+		/// rejection. At most `MaxProposalsPerAccount`.
+		pub OpenProposalCounts get(fn open_proposal_count):
+		map hasher(twox_64_concat) T::AccountId => u32;
+
+		/// The bounties each account is the proposed or accepted curator of, sorted. Holds at
+		/// most `MaxBountiesPerAccount` entries per account.
+		pub BountiesByCurator get(fn bounties_by_curator):
+		map hasher(twox_64_concat) T::AccountId => Vec<BountyIndex>;
+
+		/// The curator deposits slashed by `RejectOrigin` that are held in the appeal account, and
+		/// the block after which the appeal period of each ends.
+		pub CuratorSlashAppeals get(fn curator_slash_appeal):
+		double_map hasher(twox_64_concat) BountyIndex, hasher(twox_64_concat) T::AccountId
+		=> Option<(BalanceOf<T>, T::BlockNumber)>;
+
+		/// The work submitted for each active bounty: who submitted it, the hash of the work and
+		/// the block it was submitted in. Holds at most `MaxWorkSubmissions` entries per bounty.
+		pub WorkSubmissions get(fn work_submissions):
+		map hasher(twox_64_concat) BountyIndex => Vec<(T::AccountId, T::Hash, T::BlockNumber)>;
+
+		/// The total deposit reserved from each account for its work submissions to a bounty.
+		/// Returned once the bounty is removed.
+		pub WorkSubmissionDeposits get(fn work_submission_deposit):
+		double_map hasher(twox_64_concat) BountyIndex, hasher(twox_64_concat) T::AccountId
+		=> Option<BalanceOf<T>>;
+
+		/// The accounts that applied to curate each funded bounty, with the fee each asks for, in
+		/// the order they applied. Holds at most `MaxCuratorApplicants` entries per bounty.
+		pub CuratorApplications get(fn curator_applications):
+		map hasher(twox_64_concat) BountyIndex => Vec<(T::AccountId, BalanceOf<T>)>;
+
+		/// The deposit reserved from each applicant to curate a bounty. Returned once the
+		/// applications for the bounty are dropped.
+		pub CuratorApplicationDeposits get(fn curator_application_deposit):
+		double_map hasher(twox_64_concat) BountyIndex, hasher(twox_64_concat) T::AccountId
+		=> Option<BalanceOf<T>>;
+
+		/// The bounties that `FastTrackOrigin` has fast-tracked. Their payout delay and update
+		/// period are halved.
+		pub FastTrackedBounties get(fn bounty_fast_tracked):
+		map hasher(twox_64_concat) BountyIndex => bool;
+
+		/// The track record of each account that has curated a bounty.
+		pub CuratorRecords get(fn curator_record):
+		map hasher(twox_64_concat) T::AccountId => CuratorRecord<BalanceOf<T>>;
+
+		/// The accounts that `RejectOrigin` has banned from being proposed as curator.
+		pub BannedCurators get(fn curator_banned):
+		map hasher(twox_64_concat) T::AccountId => bool;
+
+		/// The open dispute of each awarded bounty that has one. A disputed bounty cannot be
+		/// claimed until `DisputeResolutionOrigin` rules on it.
+		pub BountyDisputes get(fn bounty_dispute):
+		map hasher(twox_64_concat) BountyIndex => Option<BountyDispute<T::AccountId, BalanceOf<T>, T::Hash>>;
+
+		/// The beneficiary of each bounty awarded through `award_bounty_to_location`, which the
+		/// `Paymaster` pays when the bounty is claimed.
+		pub BountyBeneficiaryLocations get(fn bounty_beneficiary_location):
+		map hasher(twox_64_concat) BountyIndex => Option<BeneficiaryLocationOf<T, I>>;
+
+		/// The number of bounties that have ever been funded.
+		pub TotalBountiesFunded get(fn total_bounties_funded): u32;
+
+		/// The total paid out by claimed bounties to their beneficiaries and curators, and to the
+		/// curators of unclaimed bounties that were swept.
+		pub TotalValuePaidOut get(fn total_value_paid_out): BalanceOf<T>;
+
+		/// The number of funded bounties that are not yet paid out or closed.
+		pub ActiveBountyCount get(fn active_bounty_count): u32;
+
+		/// The increase of the value of each funded bounty that is waiting to be funded along with
+		/// the approved bounties.
+		pub BountyValueIncreases get(fn bounty_value_increase):
+		map hasher(twox_64_concat) BountyIndex => Option<BalanceOf<T>>;
+
+		/// The curators that each sponsor agreed to have the curator deposit for a bounty reserved
+		/// from the sponsor for, and the most the sponsor agreed to have reserved.
+		pub CuratorSponsorships get(fn curator_sponsorship):
+		double_map hasher(twox_64_concat) BountyIndex, hasher(twox_64_concat) T::AccountId
+		=> Option<(T::AccountId, BalanceOf<T>)>;
+
+		/// The number of `CuratorSponsorships` of each bounty, at most `MaxCuratorSponsors`.
+		pub CuratorSponsorshipCounts get(fn curator_sponsorship_count):
+		map hasher(twox_64_concat) BountyIndex => u32;
+
+		/// The sponsor that the deposit of the curator of each bounty is reserved from, if not the
+		/// curator.
+		pub BountyCuratorSponsors get(fn bounty_curator_sponsor):
+		map hasher(twox_64_concat) BountyIndex => Option<T::AccountId>;
+
+		/// The sponsor that each curator slash held for appeal was taken from, and is restored to.
+		pub SlashedCuratorSponsors get(fn slashed_curator_sponsor):
+		double_map hasher(twox_64_concat) BountyIndex, hasher(twox_64_concat) T::AccountId
+		=> Option<T::AccountId>;
+
+		/// The value and description hash of each bounty that was removed, so that it can still
+		/// be cloned. Only kept for bounties that an outline deposit was made for.
+		pub BountyOutlines get(fn bounty_outline):
+		map hasher(twox_64_concat) BountyIndex => Option<(BalanceOf<T>, T::Hash)>;
+
+		/// The account that made the deposit for keeping the outline of each bounty, and the
+		/// amount of it.
+		pub BountyOutlineDeposits get(fn bounty_outline_deposit):
+		map hasher(twox_64_concat) BountyIndex => Option<(T::AccountId, BalanceOf<T>)>;
+
+		/// Storage version of the pallet.
+		///
+		/// V2 records the funding source of every bounty approved before it was recorded.
+		StorageVersion: Releases;
 	}
 }
 
 decl_event!(
-	pub enum Event<T>
+	pub enum Event<T, I=DefaultInstance>
 	where
 		Balance = BalanceOf<T>,
 		<T as frame_system::Config>::AccountId,
+		<T as frame_system::Config>::Hash,
+		<T as frame_system::Config>::BlockNumber,
+		Location = BeneficiaryLocationOf<T, I>,
 	{
 		/// New bounty proposal. \[index\]
 		BountyProposed(BountyIndex),
-		/// A bounty proposal was rejected; funds were slashed. \[index, bond\]
-		BountyRejected(BountyIndex, Balance),
+		/// A bounty proposal was rejected; part of its bond was slashed and the rest returned.
+		/// \[index, slashed, refunded, remark_hash\]
+		BountyRejected(BountyIndex, Balance, Balance, Option<Hash>),
+		/// A bounty proposal was approved. \[index, description_hash\]
+		BountyApproved(BountyIndex, Hash),
 		/// A bounty proposal is funded and became active. \[index\]
 		BountyBecameActive(BountyIndex),
-		/// A bounty is awarded to a beneficiary. \[index, beneficiary\]
-		BountyAwarded(BountyIndex, AccountId),
-		/// A bounty is claimed by beneficiary. \[index, payout, beneficiary\]
-		BountyClaimed(BountyIndex, Balance, AccountId),
-		/// A bounty is cancelled. \[index\]
-		BountyCanceled(BountyIndex),
+		/// A curator was proposed for a bounty. \[index, curator, fee\]
+		CuratorProposed(BountyIndex, AccountId, Balance),
+		/// A curator was unassigned from a bounty, and whether their deposit was slashed.
+		/// \[index, curator, slashed\]
+		CuratorUnassigned(BountyIndex, AccountId, bool),
+		/// A curator accepted to curate a bounty. \[index, curator, curator_identity\]
+		CuratorAccepted(BountyIndex, AccountId, Option<Hash>),
+		/// A bounty is awarded to a beneficiary, with the hash of the curator's remark if any.
+		/// \[index, beneficiary, beneficiary_identity, remark_hash\]
+		BountyAwarded(BountyIndex, AccountId, Option<Hash>, Option<Hash>),
+		/// The beneficiary of a bounty turned down the award, and the bounty is active again.
+		/// \[index, beneficiary\]
+		BountyAwardRejected(BountyIndex, AccountId),
+		/// The curator of a bounty was allowed to award it to themselves. \[index, curator\]
+		SelfAwardApproved(BountyIndex, AccountId),
+		/// The curator of an awarded bounty changed its beneficiary.
+		/// \[index, old_beneficiary, new_beneficiary\]
+		BountyBeneficiaryUpdated(BountyIndex, AccountId, AccountId),
+		/// A bounty is claimed by beneficiary. The curator was paid their fee and got their deposit
+		/// back. \[index, payout, beneficiary, curator_fee, deposit_refunded\]
+		BountyClaimed(BountyIndex, Balance, AccountId, Balance, Balance),
+		/// A bounty is cancelled, with the hash of the remark given if any. \[index, remark_hash\]
+		BountyCanceled(BountyIndex, Option<Hash>),
+		/// An awarded bounty was not claimed in time. The curator was paid their fee and the rest
+		/// returned to the funding source. \[index, curator_fee, returned\]
+		BountyUnclaimedSwept(BountyIndex, Balance, Balance),
 		/// A bounty expiry is extended. \[index\]
 		BountyExtended(BountyIndex),
+		/// The description preimage of a bounty has been noted. \[index, who\]
+		BountyDescriptionNoted(BountyIndex, AccountId),
+		/// The tags of a bounty proposal were set. \[index\]
+		BountyTagsSet(BountyIndex),
+		/// The tip reasons a bounty co-funds were set. \[index\]
+		BountyTipCoFundingSet(BountyIndex),
+		/// The priority of an approved bounty was set. \[index, priority\]
+		BountyPrioritySet(BountyIndex, BountyPriority),
+		/// An approved bounty could not be funded from the remaining budget this spend period.
+		/// \[index\]
+		BountyFundingSkipped(BountyIndex),
+		/// New bounty proposals have been paused.
+		NewBountiesPaused,
+		/// New bounty proposals have been resumed.
+		NewBountiesResumed,
+		/// Funds were contributed to a bounty. \[index, contributor, amount\]
+		BountyContribution(BountyIndex, AccountId, Balance),
+		/// The contribution to a cancelled bounty was refunded. \[index, contributor, amount\]
+		ContributionRefunded(BountyIndex, AccountId, Balance),
+		/// The slashed deposit of a curator is held until its appeal period ends.
+		/// \[index, curator, amount, appeal_end\]
+		CuratorSlashHeld(BountyIndex, AccountId, Balance, BlockNumber),
+		/// A held curator slash was restored on appeal. \[index, curator, amount\]
+		CuratorSlashRestored(BountyIndex, AccountId, Balance),
+		/// A held curator slash was passed on to `OnSlash`. \[index, curator, amount\]
+		CuratorSlashEnacted(BountyIndex, AccountId, Balance),
+		/// The payout delay of a bounty was set, or reset to the one it was proposed with if `None`.
+		/// \[index, delay\]
+		BountyPayoutDelaySet(BountyIndex, Option<BlockNumber>),
+		/// A curator was proposed for a bounty, for a fee as a share of the bounty paid out.
+		/// \[index, curator, rate\]
+		CuratorProposedWithRate(BountyIndex, AccountId, Permill),
+		/// A bounty was paused. \[index\]
+		BountyPaused(BountyIndex),
+		/// A paused bounty was unpaused. \[index\]
+		BountyUnpaused(BountyIndex),
+		/// Work was submitted for a bounty. \[index, who, submission_hash\]
+		WorkSubmitted(BountyIndex, AccountId, Hash),
+		/// An account applied to curate a bounty, or changed its fee bid. \[index, applicant, fee_bid\]
+		CuratorApplied(BountyIndex, AccountId, Balance),
+		/// A bounty was fast-tracked. \[index\]
+		BountyFastTracked(BountyIndex),
+		/// An account was banned from curating bounties. \[who\]
+		CuratorBanned(AccountId),
+		/// An account may curate bounties again. \[who\]
+		CuratorUnbanned(AccountId),
+		/// The award of a bounty was disputed. \[index, disputer, reason_hash\]
+		BountyDisputed(BountyIndex, AccountId, Hash),
+		/// The dispute of a bounty was ruled on. \[index, verdict\]
+		DisputeResolved(BountyIndex, DisputeVerdict<AccountId>),
+		/// The description of a bounty that no longer exists was purged. \[index, purger, reward\]
+		OrphanedDescriptionPurged(BountyIndex, AccountId, Balance),
+		/// What was left in the account of a bounty that no longer exists was returned to the
+		/// treasury pot. \[index, amount\]
+		BountyAccountSwept(BountyIndex, Balance),
+		/// Whoever claimed or swept a bounty was tipped from its account. \[index, who, tip\]
+		MaintenanceTipPaid(BountyIndex, AccountId, Balance),
+		/// A bounty is awarded to a beneficiary that is not a local account, with the hash of the
+		/// curator's remark if any. \[index, location, remark_hash\]
+		BountyAwardedToLocation(BountyIndex, Location, Option<Hash>),
+		/// A bounty was vetoed, with the curator deposit slashed and the hash of the remark given,
+		/// if any. \[index, curator_slash, remark_hash\]
+		BountyVetoed(BountyIndex, Balance, Option<Hash>),
+		/// The value of a funded bounty was increased, and the increase is waiting to be funded.
+		/// \[index, additional, new_value\]
+		BountyValueIncreased(BountyIndex, Balance, Balance),
+		/// The increase of the value of a bounty was funded. \[index, amount\]
+		BountyValueIncreaseFunded(BountyIndex, Balance),
+		/// An account agreed to have the deposit of a curator of a bounty reserved from it, or
+		/// withdrew its agreement if `None`. \[index, sponsor, curator_and_max_deposit\]
+		CuratorSponsorshipSet(BountyIndex, AccountId, Option<(AccountId, Balance)>),
+		/// The deposit of the curator of a bounty was reserved from a sponsor.
+		/// \[index, sponsor, deposit\]
+		CuratorDepositSponsored(BountyIndex, AccountId, Balance),
+		/// A bounty was proposed as a clone of another. \[index, source\]
+		BountyCloned(BountyIndex, BountyIndex),
+		/// The outline of a bounty will be kept once it is removed. \[index, depositor, deposit\]
+		BountyOutlineKept(BountyIndex, AccountId, Balance),
+		/// The outline of a bounty is no longer kept. \[index\]
+		BountyOutlineForgotten(BountyIndex),
 	}
 );
 
 decl_error! {
 	/// Error for the treasury module.
-	pub enum Error for Module<T: Config> {
+	pub enum Error for Module<T: Config<I>, I: Instance> {
 		/// Proposer's balance is too low.
 		InsufficientProposersBalance,
 		/// No proposal or bounty at that index.
@@ -253,11 +1036,87 @@ decl_error! {
 		PendingPayout,
 		/// The bounties cannot be claimed/closed because it's still in the countdown period.
 		Premature,
+		/// The bounty was not proposed with a description hash.
+		NoDescriptionHash,
+		/// The description does not match the hash the bounty was proposed with.
+		DescriptionHashMismatch,
+		/// The description of the bounty is already known.
+		DescriptionAlreadyNoted,
+		/// Too many approved bounties are already waiting to be funded.
+		TooManyApprovals,
+		/// The same bounty index was given more than once.
+		DuplicateIndex,
+		/// Too many tags were given for a bounty.
+		TooManyTags,
+		/// A bounty tag is too long.
+		TagTooLong,
+		/// New bounty proposals are currently paused.
+		Paused,
+		/// The account is already the proposer, or the curator, of too many bounties.
+		TooManyBounties,
+		/// The bounty has not been cancelled, so contributions cannot be refunded.
+		BountyNotCancelled,
+		/// The account has no contribution to refund.
+		NoContribution,
+		/// No slash of that curator is held for the bounty.
+		NoPendingSlash,
+		/// Too many tip reasons were given for a bounty.
+		TooManyTipReasons,
+		/// The tips for a reason are already co-funded by another bounty.
+		TipReasonTaken,
+		/// The appeal period of the slash is over.
+		AppealPeriodOver,
+		/// The description of a bounty must not be empty.
+		ReasonEmpty,
+		/// The payout of the bounty is already unlocked.
+		PayoutUnlocked,
+		/// The curator deposit is higher than the most the curator agreed to.
+		DepositTooHigh,
+		/// The curator needs the approval of `ApproveOrigin` to award the bounty to themselves.
+		SelfAwardRequiresApproval,
+		/// Approving the bounty would take the total value of active bounties over
+		/// `MaxTotalActiveBountyValue`.
+		TooMuchActiveBountyValue,
+		/// The account already has too many bounty proposals waiting for approval.
+		TooManyProposals,
+		/// The bounty is paused.
+		BountyPaused,
+		/// The bounty already has the most work submissions it can record.
+		TooManySubmissions,
+		/// The bounty already has the most curator applicants it can record.
+		TooManyApplicants,
+		/// The account has not applied to curate the bounty.
+		NotApplicant,
+		/// The account is banned from curating bounties.
+		BannedCurator,
+		/// The award of the bounty is disputed.
+		Disputed,
+		/// The bounty has no open dispute.
+		NotDisputed,
+		/// There is no description for the index, or a bounty still uses it.
+		NotOrphaned,
+		/// The bounty account holds nothing beyond the contributions still to be refunded.
+		NothingToSweep,
+		/// The account does not pass the `CuratorFilter`.
+		CuratorNotAllowed,
+		/// The `Paymaster` cannot pay out to the beneficiary.
+		UnsupportedBeneficiary,
+		/// The sponsor has not agreed to have the deposit of the curator reserved from it.
+		NoSponsorship,
+		/// A slash of the curator for the bounty is held for appeal, and would be restored to
+		/// another account than the one the deposit would now be reserved from.
+		HeldSlashOfOtherDepositor,
+		/// The bounty already has `MaxCuratorSponsors` curator sponsorships.
+		TooManySponsors,
+		/// A deposit was already made for keeping the outline of the bounty.
+		OutlineAlreadyKept,
+		/// No deposit was made by the origin for keeping the outline of the bounty.
+		NoOutlineDeposit,
 	}
 }
 
 decl_module! {
-	pub struct Module<T: Config>
+	pub struct Module<T: Config<I>, I: Instance=DefaultInstance>
 		for enum Call
 		where origin: T::Origin
 	{
@@ -273,19 +1132,100 @@ decl_module! {
 		/// Bounty duration in blocks.
 		const BountyUpdatePeriod: T::BlockNumber = T::BountyUpdatePeriod::get();
 
+		/// The number of blocks after the payout delay during which an awarded bounty can be
+		/// claimed before it may be swept.
+		const ClaimTimeout: T::BlockNumber = T::ClaimTimeout::get();
+
+		/// Whether a curator needs approval to award a bounty to themselves.
+		const RequireSelfAwardApproval: bool = T::RequireSelfAwardApproval::get();
+
 		/// Percentage of the curator fee that will be reserved upfront as deposit for bounty curator.
 		const BountyCuratorDeposit: Permill = T::BountyCuratorDeposit::get();
 
+		/// Percentage of the proposer's bond that is slashed when a bounty proposal is rejected.
+		const BountyRejectionSlash: Permill = T::BountyRejectionSlash::get();
+
+		/// The number of blocks for which a curator deposit slashed by `RejectOrigin` may be
+		/// appealed.
+		const AppealPeriod: T::BlockNumber = T::AppealPeriod::get();
+
+		/// Minimum amount of funds that should be placed in a deposit for accepting a curator role.
+		const CuratorDepositMin: Option<BalanceOf<T>> = T::CuratorDepositMin::get();
+
+		/// Maximum amount of funds that should be placed in a deposit for accepting a curator role.
+		const CuratorDepositMax: Option<BalanceOf<T>> = T::CuratorDepositMax::get();
+
 		/// Minimum value for a bounty.
 		const BountyValueMinimum: BalanceOf<T> = T::BountyValueMinimum::get();
 
 		/// Maximum acceptable reason length.
 		const MaximumReasonLength: u32 = T::MaximumReasonLength::get();
 
-		type Error = Error<T>;
+		/// Maximum number of tags a bounty proposal can carry.
+		const MaxTags: u32 = T::MaxTags::get();
+
+		/// Maximum length of a single bounty tag, in bytes.
+		const MaxTagLen: u32 = T::MaxTagLen::get();
+
+		/// Maximum number of tip reasons a single bounty can co-fund.
+		const MaxTipReasons: u32 = T::MaxTipReasons::get();
+
+		/// Maximum number of bounties that may be approved but not yet funded at any one time.
+		const MaxApprovals: u32 = T::MaxApprovals::get();
+
+		/// Maximum number of approved bounties of a funding source considered for funding in a
+		/// single spend period.
+		const MaxApprovalsPerBlock: u32 = T::MaxApprovalsPerBlock::get();
+
+		/// Maximum number of bounties an account may be the proposer, or the curator, of.
+		const MaxBountiesPerAccount: u32 = T::MaxBountiesPerAccount::get();
+
+		/// Maximum number of bounty proposals an account may have waiting for approval.
+		const MaxProposalsPerAccount: u32 = T::MaxProposalsPerAccount::get();
+
+		/// Maximum total value of the bounties that are approved and not yet paid out or closed.
+		const MaxTotalActiveBountyValue: BalanceOf<T> = T::MaxTotalActiveBountyValue::get();
+
+		/// Maximum number of work submissions recorded for a single bounty.
+		const MaxWorkSubmissions: u32 = T::MaxWorkSubmissions::get();
+
+		/// The amount held on deposit for each work submission.
+		const WorkSubmissionDeposit: BalanceOf<T> = T::WorkSubmissionDeposit::get();
+
+		/// Maximum number of accounts that may apply to curate a single bounty.
+		const MaxCuratorApplicants: u32 = T::MaxCuratorApplicants::get();
+
+		/// The amount held on deposit for applying to curate a bounty.
+		const CuratorApplicationDeposit: BalanceOf<T> = T::CuratorApplicationDeposit::get();
+
+		/// The amount held on deposit for disputing the award of a bounty.
+		const DisputeDeposit: BalanceOf<T> = T::DisputeDeposit::get();
+
+		/// The tag of the treasury sub-accounts that the bounties of this instance are held in.
+		const BountyAccountTag: &'static str = T::BountyAccountTag::get();
+
+		/// The reward paid to whoever purges an orphaned bounty description.
+		const DescriptionPurgeReward: BalanceOf<T> = T::DescriptionPurgeReward::get();
+
+		/// The tip paid to whoever claims a bounty for its beneficiary or sweeps an unclaimed one.
+		const MaintenanceTip: BalanceOf<T> = T::MaintenanceTip::get();
+
+		/// Maximum number of accounts that may agree to sponsor the curator deposit of a bounty.
+		const MaxCuratorSponsors: u32 = T::MaxCuratorSponsors::get();
+
+		type Error = Error<T, I>;
 
 		fn deposit_event() = default;
 
+		fn on_runtime_upgrade() -> Weight {
+			if StorageVersion::<I>::get() == Releases::V1 {
+				StorageVersion::<I>::put(Releases::V2);
+				Self::migrate_funding_sources()
+			} else {
+				0
+			}
+		}
+
 		/// Propose a new bounty.
 		///
 		/// The dispatch origin for this call must be _Signed_.
@@ -297,128 +1237,386 @@ decl_module! {
 		/// - `curator`: The curator account whom will manage this bounty.
 		/// - `fee`: The curator fee.
 		/// - `value`: The total payment amount of this bounty, curator fee included.
-		/// - `description`: The description of this bounty.
-		#[weight = <T as Config>::WeightInfo::propose_bounty(description.len() as u32)]
+		/// - `description`: The description of this bounty. Must not be empty.
+		#[weight = <T as Config<I>>::WeightInfo::propose_bounty(description.len() as u32)]
 		fn propose_bounty(
 			origin,
 			#[compact] value: BalanceOf<T>,
 			description: Vec<u8>,
 		) {
 			let proposer = ensure_signed(origin)?;
-			Self::create_bounty(proposer, description, value)?;
+			ensure!(!description.is_empty(), Error::<T, I>::ReasonEmpty);
+			ensure!(description.len() <= T::MaximumReasonLength::get() as usize, Error::<T, I>::ReasonTooBig);
+
+			let index = Self::create_bounty(proposer, description.len() as u32, value)?;
+			BountyDescriptions::<I>::insert(index, description);
 		}
 
-		/// Approve a bounty proposal. At a later time, the bounty will be funded and become active
-		/// and the original deposit will be returned.
+		/// Propose a new bounty, storing only the hash of its description on chain.
 		///
-		/// May only be called from `T::ApproveOrigin`.
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// Payment: `BountyDepositBase` will be reserved from the origin account, as well as
+		/// `DataDepositPerByte` for each byte of the hash. It will be unreserved upon approval,
+		/// or slashed when rejected.
+		///
+		/// The description itself can be stored later through `note_bounty_description`.
+		///
+		/// - `value`: The total payment amount of this bounty, curator fee included.
+		/// - `description_hash`: The hash of the description of this bounty.
 		///
 		/// # <weight>
 		/// - O(1).
 		/// # </weight>
-		#[weight = <T as Config>::WeightInfo::approve_bounty()]
-		fn approve_bounty(origin, #[compact] bounty_id: BountyIndex) {
-			T::ApproveOrigin::ensure_origin(origin)?;
+		#[weight = <T as Config<I>>::WeightInfo::propose_bounty_with_hash()]
+		fn propose_bounty_with_hash(
+			origin,
+			#[compact] value: BalanceOf<T>,
+			description_hash: T::Hash,
+		) {
+			let proposer = ensure_signed(origin)?;
+
+			let index = Self::create_bounty(proposer, description_hash.as_ref().len() as u32, value)?;
+			BountyDescriptionHashes::<T, I>::insert(index, description_hash);
+		}
+
+		/// Store the description of a bounty proposed through `propose_bounty_with_hash`.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// Payment: `DataDepositPerByte` for each byte in `description` will be reserved from the
+		/// origin account. It will be unreserved when the bounty is removed.
+		///
+		/// - `bounty_id`: Bounty ID whose description is noted.
+		/// - `description`: The description matching the hash of the bounty.
+		///
+		/// # <weight>
+		/// - O(D) where `D` is the length of `description`.
+		/// # </weight>
+		#[weight = <T as Config<I>>::WeightInfo::note_bounty_description(description.len() as u32)]
+		fn note_bounty_description(
+			origin,
+			#[compact] bounty_id: BountyIndex,
+			description: Vec<u8>,
+		) {
+			let who = ensure_signed(origin)?;
+			ensure!(description.len() <= T::MaximumReasonLength::get() as usize, Error::<T, I>::ReasonTooBig);
+
+			let description_hash = Self::bounty_description_hash(bounty_id)
+				.ok_or(Error::<T, I>::NoDescriptionHash)?;
+			ensure!(!BountyDescriptions::<I>::contains_key(bounty_id), Error::<T, I>::DescriptionAlreadyNoted);
+			ensure!(
+				T::Hashing::hash(&description[..]) == description_hash,
+				Error::<T, I>::DescriptionHashMismatch
+			);
 
-			Bounties::<T>::try_mutate_exists(bounty_id, |maybe_bounty| -> DispatchResult {
-				let mut bounty = maybe_bounty.as_mut().ok_or(Error::<T>::InvalidIndex)?;
-				ensure!(bounty.status == BountyStatus::Proposed, Error::<T>::UnexpectedStatus);
+			let deposit = T::DataDepositPerByte::get() * (description.len() as u32).into();
+			T::Currency::reserve(&who, deposit)?;
 
-				bounty.status = BountyStatus::Approved;
+			BountyDescriptions::<I>::insert(bounty_id, description);
+			BountyDescriptionDeposits::<T, I>::insert(bounty_id, (&who, deposit));
 
-				BountyApprovals::append(bounty_id);
+			Self::deposit_event(Event::<T, I>::BountyDescriptionNoted(bounty_id, who));
+		}
 
+		/// Set the tags of a bounty proposal, replacing any it had.
+		///
+		/// The dispatch origin for this call must be _Signed_ by the proposer, and the bounty must
+		/// not be approved yet.
+		///
+		/// Payment: `DataDepositPerByte` for each byte of the tags is added to the bond of the
+		/// proposal, and treated alike.
+		///
+		/// - `bounty_id`: Bounty ID to tag.
+		/// - `tags`: At most `MaxTags` tags of at most `MaxTagLen` bytes each. Empty to remove
+		///   the tags.
+		///
+		/// # <weight>
+		/// - O(T) where `T` is the number of tags.
+		/// # </weight>
+		#[weight = <T as Config<I>>::WeightInfo::set_bounty_tags(tags.len() as u32)]
+		fn set_bounty_tags(
+			origin,
+			#[compact] bounty_id: BountyIndex,
+			tags: Vec<Vec<u8>>,
+		) {
+			let proposer = ensure_signed(origin)?;
+			ensure!(tags.len() <= T::MaxTags::get() as usize, Error::<T, I>::TooManyTags);
+			ensure!(
+				tags.iter().all(|tag| tag.len() <= T::MaxTagLen::get() as usize),
+				Error::<T, I>::TagTooLong
+			);
+
+			Bounties::<T, I>::try_mutate_exists(bounty_id, |maybe_bounty| -> DispatchResult {
+				let bounty = maybe_bounty.as_mut().ok_or(Error::<T, I>::InvalidIndex)?;
+				ensure!(bounty.proposer == proposer, BadOrigin);
+				ensure!(bounty.status == BountyStatus::Proposed, Error::<T, I>::UnexpectedStatus);
+
+				let old_deposit = Self::tags_deposit(&Self::bounty_tags(bounty_id));
+				let new_deposit = Self::tags_deposit(&tags);
+				if new_deposit > old_deposit {
+					T::Currency::reserve(&proposer, new_deposit - old_deposit)
+						.map_err(|_| Error::<T, I>::InsufficientProposersBalance)?;
+				} else {
+					let _ = T::Currency::unreserve(&proposer, old_deposit - new_deposit);
+				}
+				bounty.finance.bond = bounty.finance.bond.saturating_sub(old_deposit).saturating_add(new_deposit);
 				Ok(())
 			})?;
+
+			if tags.is_empty() {
+				BountyTags::<I>::remove(bounty_id);
+			} else {
+				BountyTags::<I>::insert(bounty_id, tags);
+			}
+
+			Self::deposit_event(Event::<T, I>::BountyTagsSet(bounty_id));
 		}
 
-		/// Assign a curator to a funded bounty.
+		/// Approve a bounty proposal. At a later time, the bounty will be funded from
+		/// `funding_source` and become active and the original deposit will be returned.
+		///
+		/// The hash of the description is recorded as it was at approval time.
 		///
 		/// May only be called from `T::ApproveOrigin`.
 		///
 		/// # <weight>
 		/// - O(1).
 		/// # </weight>
-		#[weight = <T as Config>::WeightInfo::propose_curator()]
-		fn propose_curator(
+		#[weight = <T as Config<I>>::WeightInfo::approve_bounty()]
+		fn approve_bounty(
 			origin,
 			#[compact] bounty_id: BountyIndex,
-			curator: <T::Lookup as StaticLookup>::Source,
-			#[compact] fee: BalanceOf<T>,
+			funding_source: T::FundingSource,
 		) {
 			T::ApproveOrigin::ensure_origin(origin)?;
 
-			let curator = T::Lookup::lookup(curator)?;
-			Bounties::<T>::try_mutate_exists(bounty_id, |maybe_bounty| -> DispatchResult {
-
-				let mut bounty = maybe_bounty.as_mut().ok_or(Error::<T>::InvalidIndex)?;
-				match bounty.status {
-					BountyStatus::Proposed | BountyStatus::Approved | BountyStatus::Funded => {},
-					_ => return Err(Error::<T>::UnexpectedStatus.into()),
-				};
+			let value = Self::ensure_approvable(bounty_id)?;
+			Self::ensure_approval_capacity(1)?;
+			let total_value = Self::ensure_active_value_capacity(value)?;
 
-				ensure!(fee < bounty.value, Error::<T>::InvalidFee);
+			Self::approve(bounty_id, funding_source);
+			TotalActiveBountyValue::<T, I>::put(total_value);
+		}
 
-				bounty.status = BountyStatus::CuratorProposed { curator };
-				bounty.fee = fee;
+		/// Approve several bounty proposals at once, to be funded from `funding_source` in the
+		/// given order. Either all of them are approved or none is.
+		///
+		/// May only be called from `T::ApproveOrigin`.
+		///
+		/// - `bounty_ids`: The proposed bounties to approve, at most `MaxApprovals` of them.
+		///
+		/// # <weight>
+		/// - O(B) where B is the number of bounties approved.
+		/// # </weight>
+		#[weight = <T as Config<I>>::WeightInfo::approve_bounties(bounty_ids.len() as u32)]
+		fn approve_bounties(
+			origin,
+			bounty_ids: Vec<BountyIndex>,
+			funding_source: T::FundingSource,
+		) {
+			T::ApproveOrigin::ensure_origin(origin)?;
 
-				Ok(())
-			})?;
+			ensure!(bounty_ids.len() <= T::MaxApprovals::get() as usize, Error::<T, I>::TooManyApprovals);
+			let mut sorted_ids = bounty_ids.clone();
+			sorted_ids.sort_unstable();
+			ensure!(sorted_ids.windows(2).all(|w| w[0] != w[1]), Error::<T, I>::DuplicateIndex);
+
+			let mut value = BalanceOf::<T>::zero();
+			for &bounty_id in bounty_ids.iter() {
+				value = value.saturating_add(Self::ensure_approvable(bounty_id)?);
+			}
+			Self::ensure_approval_capacity(bounty_ids.len())?;
+			let total_value = Self::ensure_active_value_capacity(value)?;
+
+			for bounty_id in bounty_ids {
+				Self::approve(bounty_id, funding_source);
+			}
+			TotalActiveBountyValue::<T, I>::put(total_value);
 		}
 
-		/// Unassign curator from a bounty.
+		/// Set the priority of an approved bounty in being funded.
 		///
-		/// This function can only be called by the `RejectOrigin` a signed origin.
+		/// At each spend period, approved bounties of higher priority are funded first. Bounties
+		/// of the same priority are funded smallest first, then in order of approval. Bounties
+		/// default to the lowest priority, zero.
 		///
-		/// If this function is called by the `RejectOrigin`, we assume that the curator is malicious
-		/// or inactive. As a result, we will slash the curator when possible.
+		/// May only be called from `T::ApproveOrigin`.
 		///
-		/// If the origin is the curator, we take this as a sign they are unable to do their job and
-		/// they willingly give up. We could slash them, but for now we allow them to recover their
-		/// deposit and exit without issue. (We may want to change this if it is abused.)
+		/// - `bounty_id`: The approved bounty.
+		/// - `priority`: Its new priority.
 		///
-		/// Finally, the origin can be anyone if and only if the curator is "inactive". This allows
-		/// anyone in the community to call out that a curator is not doing their due diligence, and
-		/// we should pick a new curator. In this case the curator should also be slashed.
+		/// # <weight>
+		/// - O(1).
+		/// # </weight>
+		#[weight = <T as Config<I>>::WeightInfo::set_bounty_priority()]
+		fn set_bounty_priority(
+			origin,
+			#[compact] bounty_id: BountyIndex,
+			priority: BountyPriority,
+		) {
+			T::ApproveOrigin::ensure_origin(origin)?;
+
+			let bounty = Self::bounties(bounty_id).ok_or(Error::<T, I>::InvalidIndex)?;
+			ensure!(bounty.status == BountyStatus::Approved, Error::<T, I>::UnexpectedStatus);
+
+			BountyPriorities::<I>::insert(bounty_id, priority);
+
+			Self::deposit_event(Event::<T, I>::BountyPrioritySet(bounty_id, priority));
+		}
+
+		/// Set the delay between awarding a bounty and its payout being claimable, e.g. shorter
+		/// for a small fix or longer for a high-value bounty that needs a longer challenge window.
+		///
+		/// May only be called from `T::ApproveOrigin`, before the bounty is awarded.
+		///
+		/// - `bounty_id`: The bounty.
+		/// - `delay`: Its payout delay, or `None` to use the one it was proposed with again.
 		///
 		/// # <weight>
 		/// - O(1).
 		/// # </weight>
-		#[weight = <T as Config>::WeightInfo::unassign_curator()]
-		fn unassign_curator(
+		#[weight = <T as Config<I>>::WeightInfo::set_bounty_payout_delay()]
+		fn set_bounty_payout_delay(
 			origin,
 			#[compact] bounty_id: BountyIndex,
+			delay: Option<T::BlockNumber>,
 		) {
-			let maybe_sender = ensure_signed(origin.clone())
-				.map(Some)
-				.or_else(|_| T::RejectOrigin::ensure_origin(origin).map(|_| None))?;
+			T::ApproveOrigin::ensure_origin(origin)?;
 
-			Bounties::<T>::try_mutate_exists(bounty_id, |maybe_bounty| -> DispatchResult {
-				let mut bounty = maybe_bounty.as_mut().ok_or(Error::<T>::InvalidIndex)?;
+			let bounty = Self::bounties(bounty_id).ok_or(Error::<T, I>::InvalidIndex)?;
+			if let BountyStatus::PendingPayout { .. } = bounty.status {
+				return Err(Error::<T, I>::UnexpectedStatus.into());
+			}
 
-				let slash_curator = |curator: &T::AccountId, curator_deposit: &mut BalanceOf<T>| {
-					let imbalance = T::Currency::slash_reserved(curator, *curator_deposit).0;
-					T::OnSlash::on_unbalanced(imbalance);
-					*curator_deposit = Zero::zero();
-				};
+			BountyPayoutDelays::<T, I>::mutate(bounty_id, |d| *d = delay);
 
-				match bounty.status {
-					BountyStatus::Proposed | BountyStatus::Approved | BountyStatus::Funded => {
-						// No curator to unassign at this point.
-						return Err(Error::<T>::UnexpectedStatus.into())
-					}
-					BountyStatus::CuratorProposed { ref curator } => {
+			Self::deposit_event(Event::<T, I>::BountyPayoutDelaySet(bounty_id, delay));
+		}
+
+		/// Assign a curator to a funded bounty.
+		///
+		/// The curator must pass the `CuratorFilter` and not be banned.
+		///
+		/// May only be called from `T::ApproveOrigin`.
+		///
+		/// # <weight>
+		/// - O(1).
+		/// # </weight>
+		#[weight = <T as Config<I>>::WeightInfo::propose_curator()]
+		fn propose_curator(
+			origin,
+			#[compact] bounty_id: BountyIndex,
+			curator: <T::Lookup as StaticLookup>::Source,
+			#[compact] fee: BalanceOf<T>,
+		) {
+			T::ApproveOrigin::ensure_origin(origin)?;
+
+			let curator = T::Lookup::lookup(curator)?;
+			Self::do_propose_curator(bounty_id, &curator, |_| fee)?;
+			BountyCuratorFeeRates::<I>::remove(bounty_id);
+
+			Self::deposit_event(Event::<T, I>::CuratorProposed(bounty_id, curator, fee));
+		}
+
+		/// Assign a curator to a funded bounty, for a fee as a share of the bounty.
+		///
+		/// May only be called from `T::ApproveOrigin`.
+		///
+		/// The fee is worked out from the balance of the bounty account when the bounty is paid
+		/// out, so contributions and dust are shared out at the same rate as the bounty value. The
+		/// curator deposit is worked out from the fee on the bounty value.
+		///
+		/// - `bounty_id`: The bounty.
+		/// - `curator`: The candidate curator.
+		/// - `rate`: The share of the bounty the curator is paid. Must be less than 100%.
+		///
+		/// # <weight>
+		/// - O(1).
+		/// # </weight>
+		#[weight = <T as Config<I>>::WeightInfo::propose_curator_with_rate()]
+		fn propose_curator_with_rate(
+			origin,
+			#[compact] bounty_id: BountyIndex,
+			curator: <T::Lookup as StaticLookup>::Source,
+			rate: Permill,
+		) {
+			T::ApproveOrigin::ensure_origin(origin)?;
+
+			let curator = T::Lookup::lookup(curator)?;
+			ensure!(rate < Permill::one(), Error::<T, I>::InvalidFee);
+			Self::do_propose_curator(bounty_id, &curator, |value| rate * value)?;
+			BountyCuratorFeeRates::<I>::insert(bounty_id, rate);
+
+			Self::deposit_event(Event::<T, I>::CuratorProposedWithRate(bounty_id, curator, rate));
+		}
+
+		/// Unassign curator from a bounty.
+		///
+		/// This function can only be called by the `RejectOrigin` a signed origin.
+		///
+		/// If this function is called by the `RejectOrigin`, we assume that the curator is malicious
+		/// or inactive. As a result, we will slash the curator when possible. The slashed deposit is
+		/// held for `AppealPeriod` blocks, during which `AppealOrigin` may restore it.
+		///
+		/// If the origin is the curator, we take this as a sign they are unable to do their job and
+		/// they willingly give up. We could slash them, but for now we allow them to recover their
+		/// deposit and exit without issue. (We may want to change this if it is abused.)
+		///
+		/// Finally, the origin can be anyone if and only if the curator is "inactive". This allows
+		/// anyone in the community to call out that a curator is not doing their due diligence, and
+		/// we should pick a new curator. In this case the curator should also be slashed.
+		///
+		/// # <weight>
+		/// - O(1).
+		/// - Only the weight of unassigning a proposed curator is charged in that case.
+		/// # </weight>
+		#[weight = <T as Config<I>>::WeightInfo::unassign_curator_proposed()
+			.max(<T as Config<I>>::WeightInfo::unassign_curator())]
+		fn unassign_curator(
+			origin,
+			#[compact] bounty_id: BountyIndex,
+		) -> DispatchResultWithPostInfo {
+			let maybe_sender = ensure_signed(origin.clone())
+				.map(Some)
+				.or_else(|_| T::RejectOrigin::ensure_origin(origin).map(|_| None))?;
+			Self::ensure_not_paused(bounty_id)?;
+
+			let (curator, slashed, proposed) = Bounties::<T, I>::try_mutate_exists(bounty_id, |maybe_bounty| -> Result<_, DispatchError> {
+				let mut bounty = maybe_bounty.as_mut().ok_or(Error::<T, I>::InvalidIndex)?;
+
+				// Only slashes by `RejectOrigin` may be appealed.
+				let appealable = maybe_sender.is_none();
+				let slash_curator = |curator: &T::AccountId, curator_deposit: &mut BalanceOf<T>| {
+					let depositor = Self::curator_depositor(bounty_id, curator);
+					let imbalance = T::Currency::slash_reserved(&depositor, *curator_deposit).0;
+					if appealable {
+						Self::hold_curator_slash(bounty_id, curator, imbalance);
+					} else {
+						T::OnSlash::on_unbalanced(imbalance);
+					}
+					*curator_deposit = Zero::zero();
+				};
+
+				let unassigned = match bounty.status {
+					BountyStatus::Proposed | BountyStatus::Approved | BountyStatus::Funded => {
+						// No curator to unassign at this point.
+						return Err(Error::<T, I>::UnexpectedStatus.into())
+					}
+					BountyStatus::CuratorProposed { ref curator } => {
 						// A curator has been proposed, but not accepted yet.
 						// Either `RejectOrigin` or the proposed curator can unassign the curator.
 						ensure!(maybe_sender.map_or(true, |sender| sender == *curator), BadOrigin);
+						(curator.clone(), false, true)
 					},
 					BountyStatus::Active { ref curator, ref update_due } => {
 						// The bounty is active.
 						match maybe_sender {
 							// If the `RejectOrigin` is calling this function, slash the curator.
 							None => {
-								slash_curator(curator, &mut bounty.curator_deposit);
+								slash_curator(curator, &mut bounty.finance.curator_deposit);
 								// Continue to change bounty status below...
+								(curator.clone(), true, false)
 							},
 							Some(sender) => {
 								// If the sender is not the curator, and the curator is inactive,
@@ -426,17 +1624,20 @@ decl_module! {
 								if sender != *curator {
 									let block_number = system::Module::<T>::block_number();
 									if *update_due < block_number {
-										slash_curator(curator, &mut bounty.curator_deposit);
+										slash_curator(curator, &mut bounty.finance.curator_deposit);
 										// Continue to change bounty status below...
+										(curator.clone(), true, false)
 									} else {
 										// Curator has more time to give an update.
-										return Err(Error::<T>::Premature.into())
+										return Err(Error::<T, I>::Premature.into())
 									}
 								} else {
 									// Else this is the curator, willingly giving up their role.
 									// Give back their deposit.
-									let _ = T::Currency::unreserve(&curator, bounty.curator_deposit);
+									let depositor = Self::curator_depositor(bounty_id, curator);
+									let _ = T::Currency::unreserve(&depositor, bounty.finance.curator_deposit);
 									// Continue to change bounty status below...
+									(curator.clone(), false, false)
 								}
 							},
 						}
@@ -446,14 +1647,33 @@ decl_module! {
 						// By doing so, they are claiming the curator is acting maliciously, so
 						// we slash the curator.
 						ensure!(maybe_sender.is_none(), BadOrigin);
-						slash_curator(curator, &mut bounty.curator_deposit);
+						slash_curator(curator, &mut bounty.finance.curator_deposit);
 						// Continue to change bounty status below...
+						(curator.clone(), true, false)
 					}
 				};
 
-				bounty.status = BountyStatus::Funded;
-				Ok(())
+				bounty.status.transition(BountyStatus::Funded);
+				Ok(unassigned)
 			})?;
+			Self::cancel_claim(bounty_id);
+			BountyBeneficiaryLocations::<T, I>::remove(bounty_id);
+			BountyCuratorSponsors::<T, I>::remove(bounty_id);
+			// the award is gone, and any dispute of it with it.
+			Self::drop_dispute(bounty_id);
+			Self::unindex_bounty::<BountiesByCurator<T, I>>(&curator, bounty_id);
+			if slashed {
+				CuratorRecords::<T, I>::mutate(&curator, |record| {
+					record.times_slashed = record.times_slashed.saturating_add(1);
+				});
+			}
+
+			Self::deposit_event(Event::<T, I>::CuratorUnassigned(bounty_id, curator, slashed));
+			if proposed {
+				Ok(Some(<T as Config<I>>::WeightInfo::unassign_curator_proposed()).into())
+			} else {
+				Ok(Some(<T as Config<I>>::WeightInfo::unassign_curator()).into())
+			}
 		}
 
 		/// Accept the curator role for a bounty.
@@ -461,104 +1681,306 @@ decl_module! {
 		///
 		/// May only be called from the curator.
 		///
+		/// - `bounty_id`: Bounty ID to curate.
+		/// - `max_deposit`: The most the curator agrees to have reserved, if any. The call fails
+		///   if the deposit turns out higher, e.g. because the deposit constants changed.
+		///
 		/// # <weight>
 		/// - O(1).
 		/// # </weight>
-		#[weight = <T as Config>::WeightInfo::accept_curator()]
-		fn accept_curator(origin, #[compact] bounty_id: BountyIndex) {
+		#[weight = <T as Config<I>>::WeightInfo::accept_curator()]
+		fn accept_curator(
+			origin,
+			#[compact] bounty_id: BountyIndex,
+			max_deposit: Option<BalanceOf<T>>,
+		) {
 			let signer = ensure_signed(origin)?;
+			Self::ensure_not_paused(bounty_id)?;
 
-			Bounties::<T>::try_mutate_exists(bounty_id, |maybe_bounty| -> DispatchResult {
-				let mut bounty = maybe_bounty.as_mut().ok_or(Error::<T>::InvalidIndex)?;
-
-				match bounty.status {
-					BountyStatus::CuratorProposed { ref curator } => {
-						ensure!(signer == *curator, Error::<T>::RequireCurator);
-
-						let deposit = T::BountyCuratorDeposit::get() * bounty.fee;
-						T::Currency::reserve(curator, deposit)?;
-						bounty.curator_deposit = deposit;
-
-						let update_due = system::Module::<T>::block_number() + T::BountyUpdatePeriod::get();
-						bounty.status = BountyStatus::Active { curator: curator.clone(), update_due };
+			Self::do_accept_curator(bounty_id, &signer, None, max_deposit)?;
 
-						Ok(())
-					},
-					_ => Err(Error::<T>::UnexpectedStatus.into()),
-				}
-			})?;
+			let identity = T::IdentityProvider::identity_of(&signer);
+			Self::deposit_event(Event::<T, I>::CuratorAccepted(bounty_id, signer, identity));
 		}
 
 		/// Award bounty to a beneficiary account. The beneficiary will be able to claim the funds after a delay.
 		///
-		/// The dispatch origin for this call must be the curator of this bounty.
+		/// The dispatch origin for this call must be the curator of this bounty. If
+		/// `RequireSelfAwardApproval` is set, the curator can only be the beneficiary once
+		/// `ApproveOrigin` allowed it through `approve_self_award`.
 		///
 		/// - `bounty_id`: Bounty ID to award.
 		/// - `beneficiary`: The beneficiary account whom will receive the payout.
+		/// - `remark`: An optional justification of the award, of at most `MaximumReasonLength`
+		///   bytes. Only its hash is kept, in the `BountyAwarded` event.
+		///
+		/// The delay is the `BountyDepositPayoutDelay` in force when the bounty was proposed, unless
+		/// `set_bounty_payout_delay` set another one for this bounty.
 		///
 		/// # <weight>
 		/// - O(1).
 		/// # </weight>
-		#[weight = <T as Config>::WeightInfo::award_bounty()]
-		fn award_bounty(origin, #[compact] bounty_id: BountyIndex, beneficiary: <T::Lookup as StaticLookup>::Source) {
+		#[weight = <T as Config<I>>::WeightInfo::award_bounty()]
+		fn award_bounty(
+			origin,
+			#[compact] bounty_id: BountyIndex,
+			beneficiary: <T::Lookup as StaticLookup>::Source,
+			remark: Option<Vec<u8>>,
+		) {
 			let signer = ensure_signed(origin)?;
 			let beneficiary = T::Lookup::lookup(beneficiary)?;
+			let remark_hash = Self::remark_hash(remark)?;
+			Self::ensure_not_paused(bounty_id)?;
+
+			let self_award = beneficiary == signer;
+			Self::do_award(bounty_id, signer, &beneficiary, self_award)?;
+
+			let identity = T::IdentityProvider::identity_of(&beneficiary);
+			Self::deposit_event(Event::<T, I>::BountyAwarded(bounty_id, beneficiary, identity, remark_hash));
+		}
+
+		/// Allow the curator of an active bounty to award it to themselves, when
+		/// `RequireSelfAwardApproval` is set.
+		///
+		/// May only be called from `T::ApproveOrigin`. The approval only holds for the current
+		/// curator, and is used up by the next award.
+		///
+		/// - `bounty_id`: Bounty ID the curator may award to themselves.
+		///
+		/// # <weight>
+		/// - O(1).
+		/// # </weight>
+		#[weight = <T as Config<I>>::WeightInfo::approve_self_award()]
+		fn approve_self_award(origin, #[compact] bounty_id: BountyIndex) {
+			T::ApproveOrigin::ensure_origin(origin)?;
+
+			let bounty = Self::bounties(bounty_id).ok_or(Error::<T, I>::InvalidIndex)?;
+			let curator = match bounty.status {
+				BountyStatus::Active { curator, .. } => curator,
+				_ => return Err(Error::<T, I>::UnexpectedStatus.into()),
+			};
+			SelfAwardApprovals::<T, I>::insert(bounty_id, &curator);
+
+			Self::deposit_event(Event::<T, I>::SelfAwardApproved(bounty_id, curator));
+		}
+
+		/// Turn down the award of a bounty, e.g. because it was made to a wrong address or for
+		/// work that is disputed. The bounty becomes active again, so that the curator can award
+		/// it anew.
+		///
+		/// The dispatch origin for this call must be the beneficiary of this bounty.
+		///
+		/// - `bounty_id`: Bounty ID whose award to reject.
+		///
+		/// # <weight>
+		/// - O(1).
+		/// # </weight>
+		#[weight = <T as Config<I>>::WeightInfo::reject_award()]
+		fn reject_award(origin, #[compact] bounty_id: BountyIndex) {
+			let signer = ensure_signed(origin)?;
+			Self::ensure_not_paused(bounty_id)?;
+			Self::ensure_not_disputed(bounty_id)?;
 
-			Bounties::<T>::try_mutate_exists(bounty_id, |maybe_bounty| -> DispatchResult {
-				let mut bounty = maybe_bounty.as_mut().ok_or(Error::<T>::InvalidIndex)?;
+			Bounties::<T, I>::try_mutate_exists(bounty_id, |maybe_bounty| -> DispatchResult {
+				let bounty = maybe_bounty.as_mut().ok_or(Error::<T, I>::InvalidIndex)?;
 				match &bounty.status {
-					BountyStatus::Active {
-						curator,
-						..
-					} => {
-						ensure!(signer == *curator, Error::<T>::RequireCurator);
+					BountyStatus::PendingPayout { curator, beneficiary, .. } => {
+						ensure!(signer == *beneficiary, BadOrigin);
+						let update_due = system::Module::<T>::block_number() + Self::update_period(bounty_id);
+						bounty.status.transition(BountyStatus::Active { curator: curator.clone(), update_due });
 					},
-					_ => return Err(Error::<T>::UnexpectedStatus.into()),
+					_ => return Err(Error::<T, I>::UnexpectedStatus.into()),
 				}
-				bounty.status = BountyStatus::PendingPayout {
-					curator: signer,
-					beneficiary: beneficiary.clone(),
-					unlock_at: system::Module::<T>::block_number() + T::BountyDepositPayoutDelay::get(),
-				};
 
 				Ok(())
 			})?;
+			Self::cancel_claim(bounty_id);
+			BountyBeneficiaryLocations::<T, I>::remove(bounty_id);
+
+			Self::deposit_event(Event::<T, I>::BountyAwardRejected(bounty_id, signer));
+		}
+
+		/// Change the beneficiary of an awarded bounty, e.g. to correct a mistyped address.
+		///
+		/// The dispatch origin for this call must be the curator of this bounty, and the payout
+		/// must not be unlocked yet. The payout is unlocked at the same block as before.
+		///
+		/// - `bounty_id`: Bounty ID whose beneficiary to change.
+		/// - `new_beneficiary`: The beneficiary account whom will receive the payout instead.
+		///
+		/// # <weight>
+		/// - O(1).
+		/// # </weight>
+		#[weight = <T as Config<I>>::WeightInfo::update_award_beneficiary()]
+		fn update_award_beneficiary(
+			origin,
+			#[compact] bounty_id: BountyIndex,
+			new_beneficiary: <T::Lookup as StaticLookup>::Source,
+		) {
+			let signer = ensure_signed(origin)?;
+			let new_beneficiary = T::Lookup::lookup(new_beneficiary)?;
+			Self::ensure_not_paused(bounty_id)?;
+			Self::ensure_not_disputed(bounty_id)?;
+
+			let old_beneficiary = Bounties::<T, I>::try_mutate_exists(
+				bounty_id,
+				|maybe_bounty| -> Result<T::AccountId, DispatchError> {
+					let bounty = maybe_bounty.as_mut().ok_or(Error::<T, I>::InvalidIndex)?;
+					match &mut bounty.status {
+						BountyStatus::PendingPayout { curator, beneficiary, unlock_at } => {
+							ensure!(signer == *curator, Error::<T, I>::RequireCurator);
+							ensure!(
+								system::Module::<T>::block_number() < *unlock_at,
+								Error::<T, I>::PayoutUnlocked
+							);
+							Ok(sp_std::mem::replace(beneficiary, new_beneficiary.clone()))
+						},
+						_ => Err(Error::<T, I>::UnexpectedStatus.into()),
+					}
+				}
+			)?;
+			BountyBeneficiaryLocations::<T, I>::remove(bounty_id);
 
-			Self::deposit_event(Event::<T>::BountyAwarded(bounty_id, beneficiary));
+			Self::deposit_event(Event::<T, I>::BountyBeneficiaryUpdated(bounty_id, old_beneficiary, new_beneficiary));
 		}
 
 		/// Claim the payout from an awarded bounty after payout delay.
 		///
-		/// The dispatch origin for this call must be the beneficiary of this bounty.
+		/// The claim is also scheduled automatically when the bounty is awarded, as signed by the
+		/// beneficiary, so this only needs calling if the scheduled claim failed.
+		///
+		/// The dispatch origin for this call must be _Signed_, by any account, or `T::RejectOrigin`,
+		/// so that the Council can settle a bounty whose beneficiary does not claim it. The payout
+		/// always goes to the beneficiary of this bounty. A disputed bounty cannot be claimed until
+		/// the dispute is resolved.
+		///
+		/// A signed caller other than the beneficiary is paid `MaintenanceTip` out of the bounty
+		/// before it is split between the curator and the beneficiary.
+		///
+		/// The payout of a bounty awarded through `award_bounty_to_location` is sent by the
+		/// `Paymaster`. If it cannot be sent, the claim fails and the bounty stays as it was.
 		///
 		/// - `bounty_id`: Bounty ID to claim.
 		///
 		/// # <weight>
 		/// - O(1).
 		/// # </weight>
-		#[weight = <T as Config>::WeightInfo::claim_bounty()]
+		#[weight = <T as Config<I>>::WeightInfo::claim_bounty()
+			.max(<T as Config<I>>::WeightInfo::claim_bounty_to_location())
+			.saturating_add(Module::<T, I>::entries_removal_weight())]
+		#[transactional]
 		fn claim_bounty(origin, #[compact] bounty_id: BountyIndex) {
-			let _ = ensure_signed(origin)?; // anyone can trigger claim
-
-			Bounties::<T>::try_mutate_exists(bounty_id, |maybe_bounty| -> DispatchResult {
-				let bounty = maybe_bounty.take().ok_or(Error::<T>::InvalidIndex)?;
+			// anyone can trigger claim
+			let caller = match ensure_signed(origin.clone()) {
+				Ok(who) => Some(who),
+				Err(_) => T::RejectOrigin::ensure_origin(origin).map(|_| None)?,
+			};
+			Self::ensure_not_paused(bounty_id)?;
+			Self::ensure_not_disputed(bounty_id)?;
+
+			Bounties::<T, I>::try_mutate_exists(bounty_id, |maybe_bounty| -> DispatchResult {
+				let bounty = maybe_bounty.take().ok_or(Error::<T, I>::InvalidIndex)?;
 				if let BountyStatus::PendingPayout { curator, beneficiary, unlock_at } = bounty.status {
-					ensure!(system::Module::<T>::block_number() >= unlock_at, Error::<T>::Premature);
+					ensure!(system::Module::<T>::block_number() >= unlock_at, Error::<T, I>::Premature);
 					let bounty_account = Self::bounty_account_id(bounty_id);
-					let balance = T::Currency::free_balance(&bounty_account);
-					let fee = bounty.fee.min(balance); // just to be safe
-					let payout = balance.saturating_sub(fee);
-					let _ = T::Currency::unreserve(&curator, bounty.curator_deposit);
+					let mut balance = T::Currency::free_balance(&bounty_account);
+					if let Some(caller) = caller.filter(|caller| *caller != beneficiary) {
+						balance -= Self::pay_maintenance_tip(bounty_id, &caller, balance);
+					}
+					let rate = Self::bounty_curator_fee_rate(bounty_id);
+					let (fee, payout) = bounty.finance.payout_split(balance, rate);
+					let depositor = Self::curator_depositor(bounty_id, &curator);
+					let deposit_refunded = bounty.finance.curator_deposit
+						.saturating_sub(T::Currency::unreserve(&depositor, bounty.finance.curator_deposit));
 					let _ = T::Currency::transfer(&bounty_account, &curator, fee, AllowDeath); // should not fail
-					let _ = T::Currency::transfer(&bounty_account, &beneficiary, payout, AllowDeath); // should not fail
+					match BountyBeneficiaryLocations::<T, I>::take(bounty_id) {
+						Some(location) => T::Paymaster::pay(&bounty_account, &location, payout)?,
+						None => {
+							let _ = T::Currency::transfer(&bounty_account, &beneficiary, payout, AllowDeath); // should not fail
+						},
+					}
 					*maybe_bounty = None;
 
-					BountyDescriptions::remove(bounty_id);
+					Self::remove_bounty_data(bounty_id);
+					Self::release_active_value(bounty.finance.value);
+					BountyFundingSources::<T, I>::remove(bounty_id);
+					// contributions have been paid out along with the bounty.
+					BountyContributions::<T, I>::remove_prefix(bounty_id);
+					BountyContributionTotals::<T, I>::remove(bounty_id);
+					Self::unindex_bounty::<BountiesByProposer<T, I>>(&bounty.proposer, bounty_id);
+					Self::unindex_bounty::<BountiesByCurator<T, I>>(&curator, bounty_id);
+					Self::record_completed(&curator, fee);
+					Self::record_removed(fee.saturating_add(payout));
+					Self::cancel_claim(bounty_id);
+
+					Self::deposit_event(Event::<T, I>::BountyClaimed(
+						bounty_id,
+						payout,
+						beneficiary,
+						fee,
+						deposit_refunded,
+					));
+					Ok(())
+				} else {
+					Err(Error::<T, I>::UnexpectedStatus.into())
+				}
+			})?;
+		}
 
-					Self::deposit_event(Event::<T>::BountyClaimed(bounty_id, payout, beneficiary));
+		/// Sweep an awarded bounty that the beneficiary did not claim within `ClaimTimeout` blocks
+		/// of the end of its payout delay.
+		///
+		/// The dispatch origin for this call must be _Signed_, and is paid `MaintenanceTip` out of
+		/// the bounty.
+		///
+		/// The curator is paid their fee and gets their deposit back, and the rest of the bounty
+		/// is returned to its funding source. Contributions stay in the bounty account until they
+		/// are refunded.
+		///
+		/// - `bounty_id`: Bounty ID to sweep.
+		///
+		/// # <weight>
+		/// - O(1).
+		/// # </weight>
+		#[weight = <T as Config<I>>::WeightInfo::sweep_unclaimed_bounty()
+			.saturating_add(Module::<T, I>::entries_removal_weight())]
+		fn sweep_unclaimed_bounty(origin, #[compact] bounty_id: BountyIndex) {
+			let who = ensure_signed(origin)?; // anyone can trigger the sweep
+			Self::ensure_not_paused(bounty_id)?;
+			Self::ensure_not_disputed(bounty_id)?;
+
+			Bounties::<T, I>::try_mutate_exists(bounty_id, |maybe_bounty| -> DispatchResult {
+				let bounty = maybe_bounty.take().ok_or(Error::<T, I>::InvalidIndex)?;
+				if let BountyStatus::PendingPayout { curator, unlock_at, .. } = bounty.status {
+					ensure!(
+						system::Module::<T>::block_number() > unlock_at + T::ClaimTimeout::get(),
+						Error::<T, I>::Premature
+					);
+					let bounty_account = Self::bounty_account_id(bounty_id);
+					let mut balance = T::Currency::free_balance(&bounty_account)
+						.saturating_sub(Self::bounty_contribution_total(bounty_id));
+					balance -= Self::pay_maintenance_tip(bounty_id, &who, balance);
+					let rate = Self::bounty_curator_fee_rate(bounty_id);
+					let (fee, returned) = bounty.finance.payout_split(balance, rate);
+					let funding_account = BountyFundingSources::<T, I>::take(bounty_id).account_id();
+					let depositor = Self::curator_depositor(bounty_id, &curator);
+					let _ = T::Currency::unreserve(&depositor, bounty.finance.curator_deposit);
+					let _ = T::Currency::transfer(&bounty_account, &curator, fee, AllowDeath); // should not fail
+					let _ = T::Currency::transfer(&bounty_account, &funding_account, returned, AllowDeath); // should not fail
+
+					Self::remove_bounty_data(bounty_id);
+					Self::release_active_value(bounty.finance.value);
+					Self::unindex_bounty::<BountiesByProposer<T, I>>(&bounty.proposer, bounty_id);
+					Self::unindex_bounty::<BountiesByCurator<T, I>>(&curator, bounty_id);
+					Self::record_completed(&curator, fee);
+					Self::record_removed(fee);
+					Self::cancel_claim(bounty_id);
+					BountyBeneficiaryLocations::<T, I>::remove(bounty_id);
+
+					Self::deposit_event(Event::<T, I>::BountyUnclaimedSwept(bounty_id, fee, returned));
 					Ok(())
 				} else {
-					Err(Error::<T>::UnexpectedStatus.into())
+					Err(Error::<T, I>::UnexpectedStatus.into())
 				}
 			})?;
 		}
@@ -566,37 +1988,74 @@ decl_module! {
 		/// Cancel a proposed or active bounty. All the funds will be sent to treasury and
 		/// the curator deposit will be unreserved if possible.
 		///
+		/// When rejecting a proposed bounty, `BountyRejectionSlash` of the proposer's bond is
+		/// slashed and the rest is unreserved.
+		///
 		/// Only `T::RejectOrigin` is able to cancel a bounty.
 		///
 		/// - `bounty_id`: Bounty ID to cancel.
+		/// - `remark`: An optional justification of the cancellation, of at most
+		///   `MaximumReasonLength` bytes. Only its hash is kept, in the emitted event.
 		///
 		/// # <weight>
 		/// - O(1).
 		/// # </weight>
-		#[weight = <T as Config>::WeightInfo::close_bounty_proposed().max(<T as Config>::WeightInfo::close_bounty_active())]
-		fn close_bounty(origin, #[compact] bounty_id: BountyIndex) -> DispatchResultWithPostInfo {
+		#[weight = <T as Config<I>>::WeightInfo::close_bounty_proposed()
+			.max(<T as Config<I>>::WeightInfo::close_bounty_active())
+			.saturating_add(Module::<T, I>::entries_removal_weight())]
+		fn close_bounty(
+			origin,
+			#[compact] bounty_id: BountyIndex,
+			remark: Option<Vec<u8>>,
+		) -> DispatchResultWithPostInfo {
 			T::RejectOrigin::ensure_origin(origin)?;
+			let remark_hash = Self::remark_hash(remark)?;
+			Self::ensure_not_paused(bounty_id)?;
 
-			Bounties::<T>::try_mutate_exists(bounty_id, |maybe_bounty| -> DispatchResultWithPostInfo {
-				let bounty = maybe_bounty.as_ref().ok_or(Error::<T>::InvalidIndex)?;
+			Bounties::<T, I>::try_mutate_exists(bounty_id, |maybe_bounty| -> DispatchResultWithPostInfo {
+				let bounty = maybe_bounty.as_ref().ok_or(Error::<T, I>::InvalidIndex)?;
+				// a curator may be proposed, and unassigned again, before the bounty is approved
+				// or funded, so its status alone does not tell.
+				let approved = Self::is_approved(bounty_id, bounty);
+				let funded = Self::is_funded(bounty_id, bounty);
 
 				match &bounty.status {
-					BountyStatus::Proposed => {
+					BountyStatus::Proposed |
+					BountyStatus::CuratorProposed { .. } |
+					BountyStatus::Funded if !approved => {
 						// The reject origin would like to cancel a proposed bounty.
-						BountyDescriptions::remove(bounty_id);
-						let value = bounty.bond;
-						let imbalance = T::Currency::slash_reserved(&bounty.proposer, value).0;
+						let rejection_slash = Self::bounty_terms(bounty_id)
+							.map_or_else(T::BountyRejectionSlash::get, |terms| terms.rejection_slash);
+						Self::remove_bounty_data(bounty_id);
+						Self::unindex_bounty::<BountiesByProposer<T, I>>(&bounty.proposer, bounty_id);
+						if let Some(curator) = bounty.status.curator() {
+							Self::unindex_bounty::<BountiesByCurator<T, I>>(curator, bounty_id);
+						}
+						Self::close_proposal(&bounty.proposer);
+						let (slash, refund) = bounty.finance.bond_split(rejection_slash);
+						let imbalance = T::Currency::slash_reserved(&bounty.proposer, slash).0;
 						T::OnSlash::on_unbalanced(imbalance);
+						let _ = T::Currency::unreserve(&bounty.proposer, refund);
+						T::ProposerNotifier::on_rejected(&bounty.proposer, bounty_id);
 						*maybe_bounty = None;
 
-						Self::deposit_event(Event::<T>::BountyRejected(bounty_id, value));
+						Self::deposit_event(Event::<T, I>::BountyRejected(bounty_id, slash, refund, remark_hash));
 						// Return early, nothing else to do.
-						return Ok(Some(<T as Config>::WeightInfo::close_bounty_proposed()).into())
+						return Ok(Some(
+							<T as Config<I>>::WeightInfo::close_bounty_proposed()
+								.saturating_add(Self::entries_removal_weight())
+						).into())
 					},
+					BountyStatus::Proposed |
 					BountyStatus::Approved => {
 						// For weight reasons, we don't allow a council to cancel in this phase.
 						// We ask for them to wait until it is funded before they can cancel.
-						return Err(Error::<T>::UnexpectedStatus.into())
+						return Err(Error::<T, I>::UnexpectedStatus.into())
+					},
+					BountyStatus::CuratorProposed { .. } |
+					BountyStatus::Funded if !funded => {
+						// Approved, but not funded yet, whatever its status says.
+						return Err(Error::<T, I>::UnexpectedStatus.into())
 					},
 					BountyStatus::Funded |
 					BountyStatus::CuratorProposed { .. } => {
@@ -604,7 +2063,8 @@ decl_module! {
 					},
 					BountyStatus::Active { curator, .. } => {
 						// Cancelled by council, refund deposit of the working curator.
-						let _ = T::Currency::unreserve(&curator, bounty.curator_deposit);
+						let depositor = Self::curator_depositor(bounty_id, curator);
+						let _ = T::Currency::unreserve(&depositor, bounty.finance.curator_deposit);
 						// Then execute removal of the bounty below.
 					},
 					BountyStatus::PendingPayout { .. } => {
@@ -612,20 +2072,18 @@ decl_module! {
 						// this bounty, it should mean the curator was acting maliciously.
 						// So the council should first unassign the curator, slashing their
 						// deposit.
-						return Err(Error::<T>::PendingPayout.into())
+						return Err(Error::<T, I>::PendingPayout.into())
 					}
 				}
 
-				let bounty_account = Self::bounty_account_id(bounty_id);
-
-				BountyDescriptions::remove(bounty_id);
-
-				let balance = T::Currency::free_balance(&bounty_account);
-				let _ = T::Currency::transfer(&bounty_account, &Self::account_id(), balance, AllowDeath); // should not fail
+				Self::close_funded(bounty_id, bounty);
 				*maybe_bounty = None;
 
-				Self::deposit_event(Event::<T>::BountyCanceled(bounty_id));
-				Ok(Some(<T as Config>::WeightInfo::close_bounty_active()).into())
+				Self::deposit_event(Event::<T, I>::BountyCanceled(bounty_id, remark_hash));
+				Ok(Some(
+					<T as Config<I>>::WeightInfo::close_bounty_active()
+						.saturating_add(Self::entries_removal_weight())
+				).into())
 			})
 		}
 
@@ -639,122 +2097,1992 @@ decl_module! {
 		/// # <weight>
 		/// - O(1).
 		/// # </weight>
-		#[weight = <T as Config>::WeightInfo::extend_bounty_expiry()]
+		#[weight = <T as Config<I>>::WeightInfo::extend_bounty_expiry()]
 		fn extend_bounty_expiry(origin, #[compact] bounty_id: BountyIndex, _remark: Vec<u8>) {
 			let signer = ensure_signed(origin)?;
+			Self::ensure_not_paused(bounty_id)?;
 
-			Bounties::<T>::try_mutate_exists(bounty_id, |maybe_bounty| -> DispatchResult {
-				let bounty = maybe_bounty.as_mut().ok_or(Error::<T>::InvalidIndex)?;
+			Bounties::<T, I>::try_mutate_exists(bounty_id, |maybe_bounty| -> DispatchResult {
+				let bounty = maybe_bounty.as_mut().ok_or(Error::<T, I>::InvalidIndex)?;
 
 				match bounty.status {
 					BountyStatus::Active { ref curator, ref mut update_due } => {
-						ensure!(*curator == signer, Error::<T>::RequireCurator);
-						*update_due = (system::Module::<T>::block_number() + T::BountyUpdatePeriod::get()).max(*update_due);
+						ensure!(*curator == signer, Error::<T, I>::RequireCurator);
+						*update_due = (system::Module::<T>::block_number() + Self::update_period(bounty_id)).max(*update_due);
 					},
-					_ => return Err(Error::<T>::UnexpectedStatus.into()),
+					_ => return Err(Error::<T, I>::UnexpectedStatus.into()),
 				}
 
 				Ok(())
 			})?;
 
-			Self::deposit_event(Event::<T>::BountyExtended(bounty_id));
+			Self::deposit_event(Event::<T, I>::BountyExtended(bounty_id));
 		}
-	}
-}
 
-impl<T: Config> Module<T> {
-	// Add public immutables and private mutables.
+		/// Set the tip reasons an active bounty co-funds, replacing any it had.
+		///
+		/// The dispatch origin for this call must be _Signed_ by the curator.
+		///
+		/// While the bounty is active, the tips pallet takes up to `max_per_tip` of each tip for
+		/// one of `reasons` from the bounty account, which reduces what the bounty pays out.
+		/// Contributions to the bounty are never used for tips.
+		///
+		/// - `bounty_id`: Bounty ID to co-fund tips from.
+		/// - `reasons`: At most `MaxTipReasons` tip reason hashes, none of them co-funded by
+		///   another bounty. Empty to stop co-funding tips.
+		/// - `max_per_tip`: The most the bounty pays towards a single tip.
+		///
+		/// # <weight>
+		/// - O(R) where `R` is `MaxTipReasons`.
+		/// - Only the weight of the reasons actually set is charged.
+		/// # </weight>
+		#[weight = <T as Config<I>>::WeightInfo::set_tip_co_funding(T::MaxTipReasons::get())]
+		fn set_tip_co_funding(
+			origin,
+			#[compact] bounty_id: BountyIndex,
+			reasons: Vec<T::Hash>,
+			#[compact] max_per_tip: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let signer = ensure_signed(origin)?;
+			let mut reasons = reasons;
+			reasons.sort();
+			reasons.dedup();
+			ensure!(reasons.len() <= T::MaxTipReasons::get() as usize, Error::<T, I>::TooManyTipReasons);
+
+			let bounty = Self::bounties(bounty_id).ok_or(Error::<T, I>::InvalidIndex)?;
+			match bounty.status {
+				BountyStatus::Active { ref curator, .. } => {
+					ensure!(*curator == signer, Error::<T, I>::RequireCurator);
+				},
+				_ => return Err(Error::<T, I>::UnexpectedStatus.into()),
+			}
+			ensure!(
+				reasons.iter().all(|reason| match Self::tip_co_funding(reason) {
+					Some((other_id, _)) => other_id == bounty_id,
+					None => true,
+				}),
+				Error::<T, I>::TipReasonTaken
+			);
+
+			Self::remove_tip_co_funding(bounty_id);
+			for reason in reasons.iter() {
+				TipCoFunding::<T, I>::insert(reason, (bounty_id, max_per_tip));
+			}
+			let reason_count = reasons.len() as u32;
+			if !reasons.is_empty() {
+				BountyTipReasons::<T, I>::insert(bounty_id, reasons);
+			}
+
+			Self::deposit_event(Event::<T, I>::BountyTipCoFundingSet(bounty_id));
+			Ok(Some(<T as Config<I>>::WeightInfo::set_tip_co_funding(reason_count)).into())
+		}
 
-	/// The account ID of the treasury pot.
-	///
-	/// This actually does computation. If you need to keep using it, then make sure you cache the
-	/// value and only call this once.
-	pub fn account_id() -> T::AccountId {
-		T::ModuleId::get().into_account()
-	}
+		/// Contribute funds to a funded or active bounty, increasing the amount it pays out.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// If the bounty is cancelled, the contribution can be refunded through
+		/// `refund_contribution`. Otherwise it is paid out together with the bounty.
+		///
+		/// - `bounty_id`: Bounty ID to contribute to.
+		/// - `amount`: The amount to transfer to the bounty account.
+		///
+		/// # <weight>
+		/// - O(1).
+		/// # </weight>
+		#[weight = <T as Config<I>>::WeightInfo::contribute_to_bounty()]
+		fn contribute_to_bounty(
+			origin,
+			#[compact] bounty_id: BountyIndex,
+			#[compact] amount: BalanceOf<T>,
+		) {
+			let contributor = ensure_signed(origin)?;
+			ensure!(!amount.is_zero(), Error::<T, I>::InvalidValue);
+			Self::ensure_not_paused(bounty_id)?;
 
-	/// The account ID of a bounty account
-	pub fn bounty_account_id(id: BountyIndex) -> T::AccountId {
-		// only use two byte prefix to support 16 byte account id (used by test)
-		// "modl" ++ "py/trsry" ++ "bt" is 14 bytes, and two bytes remaining for bounty index
-		T::ModuleId::get().into_sub_account(("bt", id))
-	}
+			let bounty = Self::bounties(bounty_id).ok_or(Error::<T, I>::InvalidIndex)?;
+			match bounty.status {
+				BountyStatus::Funded | BountyStatus::CuratorProposed { .. } | BountyStatus::Active { .. } => {},
+				_ => return Err(Error::<T, I>::UnexpectedStatus.into()),
+			}
 
-	fn create_bounty(
-		proposer: T::AccountId,
-		description: Vec<u8>,
-		value: BalanceOf<T>,
-	) -> DispatchResult {
-		ensure!(description.len() <= T::MaximumReasonLength::get() as usize, Error::<T>::ReasonTooBig);
-		ensure!(value >= T::BountyValueMinimum::get(), Error::<T>::InvalidValue);
+			T::Currency::transfer(&contributor, &Self::bounty_account_id(bounty_id), amount, KeepAlive)?;
 
-		let index = Self::bounty_count();
+			BountyContributions::<T, I>::mutate(bounty_id, &contributor, |c| *c = c.saturating_add(amount));
+			BountyContributionTotals::<T, I>::mutate(bounty_id, |t| *t = t.saturating_add(amount));
 
-		// reserve deposit for new bounty
-		let bond = T::BountyDepositBase::get()
-			+ T::DataDepositPerByte::get() * (description.len() as u32).into();
-		T::Currency::reserve(&proposer, bond)
-			.map_err(|_| Error::<T>::InsufficientProposersBalance)?;
+			Self::deposit_event(Event::<T, I>::BountyContribution(bounty_id, contributor, amount));
+		}
 
-		BountyCount::put(index + 1);
+		/// Refund the contribution of the origin to a bounty that has been cancelled.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// - `bounty_id`: Bounty ID the origin contributed to.
+		///
+		/// # <weight>
+		/// - O(1).
+		/// # </weight>
+		#[weight = <T as Config<I>>::WeightInfo::refund_contribution()]
+		fn refund_contribution(origin, #[compact] bounty_id: BountyIndex) {
+			let contributor = ensure_signed(origin)?;
+			ensure!(!Bounties::<T, I>::contains_key(bounty_id), Error::<T, I>::BountyNotCancelled);
 
-		let bounty = Bounty {
-			proposer,
-			value,
-			fee: 0u32.into(),
-			curator_deposit: 0u32.into(),
-			bond,
-			status: BountyStatus::Proposed,
-		};
+			let amount = BountyContributions::<T, I>::take(bounty_id, &contributor);
+			ensure!(!amount.is_zero(), Error::<T, I>::NoContribution);
 
-		Bounties::<T>::insert(index, &bounty);
-		BountyDescriptions::insert(index, description);
+			BountyContributionTotals::<T, I>::mutate_exists(bounty_id, |maybe_total| {
+				let total = maybe_total.unwrap_or_else(Zero::zero).saturating_sub(amount);
+				*maybe_total = Some(total).filter(|t| !t.is_zero());
+			});
+			let bounty_account = Self::bounty_account_id(bounty_id);
+			let _ = T::Currency::transfer(&bounty_account, &contributor, amount, AllowDeath); // should not fail
 
-		Self::deposit_event(RawEvent::BountyProposed(index));
+			Self::deposit_event(Event::<T, I>::ContributionRefunded(bounty_id, contributor, amount));
+		}
 
-		Ok(())
-	}
+		/// Restore a curator deposit slashed by `RejectOrigin` to the curator, or to the sponsor it
+		/// was reserved from, while the slash is still held for appeal.
+		///
+		/// May only be called from `T::AppealOrigin`.
+		///
+		/// - `bounty_id`: Bounty ID the curator was unassigned from.
+		/// - `curator`: The slashed curator.
+		///
+		/// # <weight>
+		/// - O(1).
+		/// # </weight>
+		#[weight = <T as Config<I>>::WeightInfo::restore_curator_slash()]
+		fn restore_curator_slash(
+			origin,
+			#[compact] bounty_id: BountyIndex,
+			curator: <T::Lookup as StaticLookup>::Source,
+		) {
+			T::AppealOrigin::ensure_origin(origin)?;
+			let curator = T::Lookup::lookup(curator)?;
 
-}
+			let (amount, appeal_end) = Self::curator_slash_appeal(bounty_id, &curator)
+				.ok_or(Error::<T, I>::NoPendingSlash)?;
+			ensure!(system::Module::<T>::block_number() <= appeal_end, Error::<T, I>::AppealPeriodOver);
 
-impl<T: Config> pallet_treasury::SpendFunds<T> for Module<T> {
-	fn spend_funds(
-		budget_remaining: &mut BalanceOf<T>,
-		imbalance: &mut PositiveImbalanceOf<T>,
-		total_weight: &mut Weight,
-		missed_any: &mut bool
-	) {
-		let bounties_len = BountyApprovals::mutate(|v| {
-			let bounties_approval_len = v.len() as u32;
-			v.retain(|&index| {
-				Bounties::<T>::mutate(index, |bounty| {
-					// Should always be true, but shouldn't panic if false or we're screwed.
-					if let Some(bounty) = bounty {
-						if bounty.value <= *budget_remaining {
-							*budget_remaining -= bounty.value;
-
-							bounty.status = BountyStatus::Funded;
-
-							// return their deposit.
-							let _ = T::Currency::unreserve(&bounty.proposer, bounty.bond);
-
-							// fund the bounty account
-							imbalance.subsume(T::Currency::deposit_creating(&Self::bounty_account_id(index), bounty.value));
-
-							Self::deposit_event(RawEvent::BountyBecameActive(index));
-							false
-						} else {
-							*missed_any = true;
-							true
-						}
-					} else {
-						false
-					}
-				})
+			CuratorSlashAppeals::<T, I>::remove(bounty_id, &curator);
+			let depositor = SlashedCuratorSponsors::<T, I>::take(bounty_id, &curator).unwrap_or_else(|| curator.clone());
+			let _ = T::Currency::transfer(&Self::curator_slash_account_id(), &depositor, amount, AllowDeath); // should not fail
+			CuratorRecords::<T, I>::mutate(&curator, |record| {
+				record.times_slashed = record.times_slashed.saturating_sub(1);
 			});
-			bounties_approval_len
-		});
 
-		*total_weight += <T as Config>::WeightInfo::spend_funds(bounties_len);
+			Self::deposit_event(Event::<T, I>::CuratorSlashRestored(bounty_id, curator, amount));
+		}
+
+		/// Pass a held curator slash on to `OnSlash` once its appeal period is over.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// - `bounty_id`: Bounty ID the curator was unassigned from.
+		/// - `curator`: The slashed curator.
+		///
+		/// # <weight>
+		/// - O(1).
+		/// # </weight>
+		#[weight = <T as Config<I>>::WeightInfo::settle_curator_slash()]
+		fn settle_curator_slash(
+			origin,
+			#[compact] bounty_id: BountyIndex,
+			curator: <T::Lookup as StaticLookup>::Source,
+		) {
+			ensure_signed(origin)?;
+			let curator = T::Lookup::lookup(curator)?;
+
+			let (amount, appeal_end) = Self::curator_slash_appeal(bounty_id, &curator)
+				.ok_or(Error::<T, I>::NoPendingSlash)?;
+			ensure!(system::Module::<T>::block_number() > appeal_end, Error::<T, I>::Premature);
+
+			CuratorSlashAppeals::<T, I>::remove(bounty_id, &curator);
+			SlashedCuratorSponsors::<T, I>::remove(bounty_id, &curator);
+			if let Ok(imbalance) = T::Currency::withdraw(
+				&Self::curator_slash_account_id(),
+				amount,
+				WithdrawReasons::TRANSFER,
+				AllowDeath,
+			) {
+				T::OnSlash::on_unbalanced(imbalance);
+			}
+
+			Self::deposit_event(Event::<T, I>::CuratorSlashEnacted(bounty_id, curator, amount));
+		}
+
+		/// Stop accepting new bounty proposals, e.g. during a treasury incident. Bounties that
+		/// already exist can still progress as usual.
+		///
+		/// May only be called from `T::RejectOrigin`.
+		///
+		/// # <weight>
+		/// - O(1).
+		/// # </weight>
+		#[weight = <T as Config<I>>::WeightInfo::pause_new_bounties()]
+		fn pause_new_bounties(origin) {
+			T::RejectOrigin::ensure_origin(origin)?;
+
+			NewBountiesPaused::<I>::put(true);
+
+			Self::deposit_event(Event::<T, I>::NewBountiesPaused);
+		}
+
+		/// Accept new bounty proposals again after `pause_new_bounties`.
+		///
+		/// May only be called from `T::RejectOrigin`.
+		///
+		/// # <weight>
+		/// - O(1).
+		/// # </weight>
+		#[weight = <T as Config<I>>::WeightInfo::resume_new_bounties()]
+		fn resume_new_bounties(origin) {
+			T::RejectOrigin::ensure_origin(origin)?;
+
+			NewBountiesPaused::<I>::kill();
+
+			Self::deposit_event(Event::<T, I>::NewBountiesResumed);
+		}
+
+		/// Pause a bounty, e.g. while a dispute about it is looked into. A paused bounty cannot be
+		/// approved, funded, curated, awarded, claimed or closed until it is unpaused. Nothing is
+		/// slashed or refunded.
+		///
+		/// May only be called from `T::RejectOrigin`.
+		///
+		/// - `bounty_id`: Bounty ID to pause.
+		///
+		/// # <weight>
+		/// - O(1).
+		/// # </weight>
+		#[weight = <T as Config<I>>::WeightInfo::pause_bounty()]
+		fn pause_bounty(origin, #[compact] bounty_id: BountyIndex) {
+			T::RejectOrigin::ensure_origin(origin)?;
+			ensure!(Bounties::<T, I>::contains_key(bounty_id), Error::<T, I>::InvalidIndex);
+
+			PausedBounties::<I>::insert(bounty_id, true);
+
+			Self::deposit_event(RawEvent::BountyPaused(bounty_id));
+		}
+
+		/// Unpause a bounty paused with `pause_bounty`. It carries on from the status it was paused
+		/// in.
+		///
+		/// May only be called from `T::RejectOrigin`.
+		///
+		/// - `bounty_id`: Bounty ID to unpause.
+		///
+		/// # <weight>
+		/// - O(1).
+		/// # </weight>
+		#[weight = <T as Config<I>>::WeightInfo::unpause_bounty()]
+		fn unpause_bounty(origin, #[compact] bounty_id: BountyIndex) {
+			T::RejectOrigin::ensure_origin(origin)?;
+			ensure!(Self::bounty_paused(bounty_id), Error::<T, I>::UnexpectedStatus);
+
+			PausedBounties::<I>::remove(bounty_id);
+			// a claim that fell due while the bounty was paused has failed.
+			if let Some(BountyStatus::PendingPayout { beneficiary, unlock_at, .. }) =
+				Self::bounties(bounty_id).map(|bounty| bounty.status)
+			{
+				Self::schedule_claim(bounty_id, &beneficiary, unlock_at);
+			}
+
+			Self::deposit_event(RawEvent::BountyUnpaused(bounty_id));
+		}
+
+		/// Record work submitted for an active bounty, so that the curator and any dispute can
+		/// refer to it.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// Payment: `WorkSubmissionDeposit` will be reserved from the origin account for each
+		/// submission. It is returned once the bounty is removed.
+		///
+		/// - `bounty_id`: Bounty ID to submit work for.
+		/// - `submission_hash`: The hash of the submitted work, e.g. of a link to it.
+		///
+		/// # <weight>
+		/// - O(1).
+		/// # </weight>
+		#[weight = <T as Config<I>>::WeightInfo::submit_work()]
+		fn submit_work(origin, #[compact] bounty_id: BountyIndex, submission_hash: T::Hash) {
+			let who = ensure_signed(origin)?;
+
+			let bounty = Self::bounties(bounty_id).ok_or(Error::<T, I>::InvalidIndex)?;
+			match bounty.status {
+				BountyStatus::Active { .. } => {},
+				_ => return Err(Error::<T, I>::UnexpectedStatus.into()),
+			}
+			let submissions = WorkSubmissions::<T, I>::decode_len(bounty_id).unwrap_or(0);
+			ensure!(submissions < T::MaxWorkSubmissions::get() as usize, Error::<T, I>::TooManySubmissions);
+
+			let deposit = T::WorkSubmissionDeposit::get();
+			T::Currency::reserve(&who, deposit)?;
+			WorkSubmissionDeposits::<T, I>::mutate(bounty_id, &who, |total| {
+				*total = Some(total.unwrap_or_else(Zero::zero).saturating_add(deposit))
+			});
+			let now = system::Module::<T>::block_number();
+			WorkSubmissions::<T, I>::append(bounty_id, (who.clone(), submission_hash, now));
+
+			Self::deposit_event(RawEvent::WorkSubmitted(bounty_id, who, submission_hash));
+		}
+
+		/// Apply to curate a funded bounty, for a fee of `fee_bid`. Applying again changes the fee
+		/// bid.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// Payment: `CuratorApplicationDeposit` will be reserved from the origin account when it
+		/// first applies. It is returned once a curator is selected among the applicants or the
+		/// bounty is removed.
+		///
+		/// - `bounty_id`: Bounty ID to curate.
+		/// - `fee_bid`: The fee the applicant asks for. Must be less than the bounty value.
+		///
+		/// # <weight>
+		/// - O(A) where `A` is `MaxCuratorApplicants`.
+		/// - Only the weight of the applications made before is charged.
+		/// # </weight>
+		#[weight = <T as Config<I>>::WeightInfo::apply_for_curator(T::MaxCuratorApplicants::get())]
+		fn apply_for_curator(
+			origin,
+			#[compact] bounty_id: BountyIndex,
+			#[compact] fee_bid: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let applicant = ensure_signed(origin)?;
+			Self::ensure_not_paused(bounty_id)?;
+			ensure!(!Self::curator_banned(&applicant), Error::<T, I>::BannedCurator);
+			ensure!(T::CuratorFilter::filter(&applicant), Error::<T, I>::CuratorNotAllowed);
+
+			let bounty = Self::bounties(bounty_id).ok_or(Error::<T, I>::InvalidIndex)?;
+			ensure!(bounty.status == BountyStatus::Funded, Error::<T, I>::UnexpectedStatus);
+			ensure!(fee_bid < bounty.finance.value, Error::<T, I>::InvalidFee);
+
+			let applicant_count = CuratorApplications::<T, I>::try_mutate(bounty_id, |applications| -> Result<_, DispatchError> {
+				let applicant_count = applications.len() as u32;
+				match applications.iter_mut().find(|(who, _)| *who == applicant) {
+					Some((_, bid)) => *bid = fee_bid,
+					None => {
+						ensure!(
+							applications.len() < T::MaxCuratorApplicants::get() as usize,
+							Error::<T, I>::TooManyApplicants
+						);
+						let deposit = T::CuratorApplicationDeposit::get();
+						T::Currency::reserve(&applicant, deposit)?;
+						CuratorApplicationDeposits::<T, I>::insert(bounty_id, &applicant, deposit);
+						applications.push((applicant.clone(), fee_bid));
+					},
+				}
+				Ok(applicant_count)
+			})?;
+
+			Self::deposit_event(RawEvent::CuratorApplied(bounty_id, applicant, fee_bid));
+			Ok(Some(<T as Config<I>>::WeightInfo::apply_for_curator(applicant_count)).into())
+		}
+
+		/// Propose one of the applicants of a funded bounty as its curator, for the fee they bid.
+		/// The other applications are dropped, and the deposits of all applicants returned.
+		///
+		/// May only be called from `T::ApproveOrigin`.
+		///
+		/// - `bounty_id`: The bounty.
+		/// - `applicant`: The applicant to propose as curator.
+		///
+		/// # <weight>
+		/// - O(A) where `A` is `MaxCuratorApplicants`.
+		/// - Only the weight of the applications there are is charged.
+		/// # </weight>
+		#[weight = <T as Config<I>>::WeightInfo::select_curator(T::MaxCuratorApplicants::get())]
+		fn select_curator(
+			origin,
+			#[compact] bounty_id: BountyIndex,
+			applicant: <T::Lookup as StaticLookup>::Source,
+		) -> DispatchResultWithPostInfo {
+			T::ApproveOrigin::ensure_origin(origin)?;
+
+			let applicant = T::Lookup::lookup(applicant)?;
+			let applications = Self::curator_applications(bounty_id);
+			let applicant_count = applications.len() as u32;
+			let fee = applications
+				.into_iter()
+				.find(|(who, _)| *who == applicant)
+				.map(|(_, fee_bid)| fee_bid)
+				.ok_or(Error::<T, I>::NotApplicant)?;
+			Self::do_propose_curator(bounty_id, &applicant, |_| fee)?;
+			BountyCuratorFeeRates::<I>::remove(bounty_id);
+			Self::drop_curator_applications(bounty_id);
+
+			Self::deposit_event(Event::<T, I>::CuratorProposed(bounty_id, applicant, fee));
+			Ok(Some(<T as Config<I>>::WeightInfo::select_curator(applicant_count)).into())
+		}
+
+		/// Fast-track a bounty, e.g. for an urgent security fix, halving its payout delay and
+		/// update period. The update period is halved from the next time it is set, i.e. when
+		/// the curator accepts or extends the bounty.
+		///
+		/// May only be called from `T::FastTrackOrigin`, and not once the bounty is awarded.
+		///
+		/// - `bounty_id`: Bounty ID to fast-track.
+		///
+		/// # <weight>
+		/// - O(1).
+		/// # </weight>
+		#[weight = <T as Config<I>>::WeightInfo::fast_track_bounty()]
+		fn fast_track_bounty(origin, #[compact] bounty_id: BountyIndex) {
+			T::FastTrackOrigin::ensure_origin(origin)?;
+
+			let bounty = Self::bounties(bounty_id).ok_or(Error::<T, I>::InvalidIndex)?;
+			if let BountyStatus::PendingPayout { .. } = bounty.status {
+				return Err(Error::<T, I>::UnexpectedStatus.into());
+			}
+
+			FastTrackedBounties::<I>::insert(bounty_id, true);
+
+			Self::deposit_event(RawEvent::BountyFastTracked(bounty_id));
+		}
+
+		/// Ban an account from being proposed as, or applying to be, the curator of a bounty.
+		/// Bounties it already curates are unaffected.
+		///
+		/// May only be called from `T::RejectOrigin`.
+		///
+		/// - `who`: The account to ban.
+		///
+		/// # <weight>
+		/// - O(1).
+		/// # </weight>
+		#[weight = <T as Config<I>>::WeightInfo::ban_curator()]
+		fn ban_curator(origin, who: <T::Lookup as StaticLookup>::Source) {
+			T::RejectOrigin::ensure_origin(origin)?;
+			let who = T::Lookup::lookup(who)?;
+
+			BannedCurators::<T, I>::insert(&who, true);
+
+			Self::deposit_event(RawEvent::CuratorBanned(who));
+		}
+
+		/// Lift the ban of an account from curating bounties.
+		///
+		/// May only be called from `T::RejectOrigin`.
+		///
+		/// - `who`: The account to unban.
+		///
+		/// # <weight>
+		/// - O(1).
+		/// # </weight>
+		#[weight = <T as Config<I>>::WeightInfo::unban_curator()]
+		fn unban_curator(origin, who: <T::Lookup as StaticLookup>::Source) {
+			T::RejectOrigin::ensure_origin(origin)?;
+			let who = T::Lookup::lookup(who)?;
+
+			BannedCurators::<T, I>::remove(&who);
+
+			Self::deposit_event(RawEvent::CuratorUnbanned(who));
+		}
+
+		/// Dispute the award of a bounty, e.g. because the work was not done or the beneficiary
+		/// is not who did it. The bounty cannot be claimed until `T::DisputeResolutionOrigin`
+		/// rules on the dispute through `resolve_dispute`.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// Payment: `DisputeDeposit` will be reserved from the origin account. It is returned if
+		/// the dispute is upheld, and slashed otherwise.
+		///
+		/// - `bounty_id`: Bounty ID to dispute.
+		/// - `reason_hash`: The hash of the reason for the dispute.
+		///
+		/// # <weight>
+		/// - O(1).
+		/// # </weight>
+		#[weight = <T as Config<I>>::WeightInfo::dispute_bounty()]
+		fn dispute_bounty(origin, #[compact] bounty_id: BountyIndex, reason_hash: T::Hash) {
+			let disputer = ensure_signed(origin)?;
+			Self::ensure_not_paused(bounty_id)?;
+			Self::ensure_not_disputed(bounty_id)?;
+
+			let bounty = Self::bounties(bounty_id).ok_or(Error::<T, I>::InvalidIndex)?;
+			ensure!(bounty.status.kind() == BountyStatusKind::PendingPayout, Error::<T, I>::UnexpectedStatus);
+
+			let deposit = T::DisputeDeposit::get();
+			T::Currency::reserve(&disputer, deposit)?;
+			BountyDisputes::<T, I>::insert(bounty_id, BountyDispute {
+				disputer: disputer.clone(),
+				deposit,
+				reason_hash,
+			});
+
+			Self::deposit_event(RawEvent::BountyDisputed(bounty_id, disputer, reason_hash));
+		}
+
+		/// Rule on the dispute of a bounty award.
+		///
+		/// May only be called from `T::DisputeResolutionOrigin`.
+		///
+		/// - `bounty_id`: Bounty ID whose dispute to rule on.
+		/// - `verdict`: Whether to release the payout as awarded, award the bounty to another
+		///   beneficiary, or unassign and slash the curator. A slash may be appealed like any
+		///   other slash of a curator deposit.
+		///
+		/// # <weight>
+		/// - O(1).
+		/// # </weight>
+		#[weight = <T as Config<I>>::WeightInfo::resolve_dispute()]
+		fn resolve_dispute(
+			origin,
+			#[compact] bounty_id: BountyIndex,
+			verdict: DisputeVerdict<T::AccountId>,
+		) {
+			T::DisputeResolutionOrigin::ensure_origin(origin)?;
+			Self::ensure_not_paused(bounty_id)?;
+
+			let dispute = Self::bounty_dispute(bounty_id).ok_or(Error::<T, I>::NotDisputed)?;
+			let mut bounty = Self::bounties(bounty_id).ok_or(Error::<T, I>::InvalidIndex)?;
+			let curator = match bounty.status {
+				BountyStatus::PendingPayout { ref curator, .. } => curator.clone(),
+				_ => return Err(Error::<T, I>::UnexpectedStatus.into()),
+			};
+
+			match verdict {
+				DisputeVerdict::Release => {
+					let imbalance = T::Currency::slash_reserved(&dispute.disputer, dispute.deposit).0;
+					T::OnSlash::on_unbalanced(imbalance);
+				},
+				DisputeVerdict::ReAward(ref beneficiary) => {
+					let _ = T::Currency::unreserve(&dispute.disputer, dispute.deposit);
+					bounty.status.transition(BountyStatus::PendingPayout {
+						curator,
+						beneficiary: beneficiary.clone(),
+						unlock_at: system::Module::<T>::block_number() + Self::payout_delay(bounty_id),
+					});
+					Bounties::<T, I>::insert(bounty_id, &bounty);
+				},
+				DisputeVerdict::SlashCurator => {
+					let _ = T::Currency::unreserve(&dispute.disputer, dispute.deposit);
+					let depositor = Self::curator_depositor(bounty_id, &curator);
+					let imbalance = T::Currency::slash_reserved(&depositor, bounty.finance.curator_deposit).0;
+					Self::hold_curator_slash(bounty_id, &curator, imbalance);
+					BountyCuratorSponsors::<T, I>::remove(bounty_id);
+					bounty.finance.curator_deposit = Zero::zero();
+					bounty.status.transition(BountyStatus::Funded);
+					Bounties::<T, I>::insert(bounty_id, &bounty);
+					Self::unindex_bounty::<BountiesByCurator<T, I>>(&curator, bounty_id);
+					CuratorRecords::<T, I>::mutate(&curator, |record| {
+						record.times_slashed = record.times_slashed.saturating_add(1);
+					});
+					Self::deposit_event(RawEvent::CuratorUnassigned(bounty_id, curator, true));
+				},
+			}
+			BountyDisputes::<T, I>::remove(bounty_id);
+			if verdict != DisputeVerdict::Release {
+				BountyBeneficiaryLocations::<T, I>::remove(bounty_id);
+			}
+			// a claim that fell due while the award was disputed has failed.
+			match bounty.status {
+				BountyStatus::PendingPayout { ref beneficiary, unlock_at, .. } =>
+					Self::schedule_claim(bounty_id, beneficiary, unlock_at),
+				_ => Self::cancel_claim(bounty_id),
+			}
+
+			Self::deposit_event(RawEvent::DisputeResolved(bounty_id, verdict));
+		}
+
+		/// Remove the description, and any other data kept alongside it, of a bounty that no
+		/// longer exists, e.g. left behind by a removal that missed it. A description deposit is
+		/// returned to whoever noted the description.
+		///
+		/// The dispatch origin for this call must be _Signed_, and is paid `DescriptionPurgeReward`
+		/// from the treasury pot if it holds enough.
+		///
+		/// - `bounty_id`: Index of the bounty the description was kept for.
+		///
+		/// # <weight>
+		/// - O(1).
+		/// # </weight>
+		#[weight = <T as Config<I>>::WeightInfo::purge_orphaned_description()]
+		fn purge_orphaned_description(origin, #[compact] bounty_id: BountyIndex) {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				BountyDescriptions::<I>::contains_key(bounty_id) && !Bounties::<T, I>::contains_key(bounty_id),
+				Error::<T, I>::NotOrphaned
+			);
+
+			Self::remove_bounty_data(bounty_id);
+
+			// best-effort only, so that a low pot never keeps orphans around.
+			let reward = T::DescriptionPurgeReward::get();
+			let paid = match T::Currency::transfer(&Self::account_id(), &who, reward, KeepAlive) {
+				Ok(()) => reward,
+				Err(_) => Zero::zero(),
+			};
+
+			Self::deposit_event(RawEvent::OrphanedDescriptionPurged(bounty_id, who, paid));
+		}
+
+		/// Return what is left in the account of a claimed or closed bounty to the treasury pot,
+		/// such as dust below the existential deposit of the beneficiary or transfers made to the
+		/// account after the bounty was removed. Contributions that are still to be refunded stay.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// - `bounty_id`: Index of the removed bounty.
+		///
+		/// # <weight>
+		/// - O(1).
+		/// # </weight>
+		#[weight = <T as Config<I>>::WeightInfo::sweep_bounty_account()]
+		fn sweep_bounty_account(origin, #[compact] bounty_id: BountyIndex) {
+			ensure_signed(origin)?;
+			ensure!(bounty_id < Self::bounty_count(), Error::<T, I>::InvalidIndex);
+			ensure!(!Bounties::<T, I>::contains_key(bounty_id), Error::<T, I>::BountyNotCancelled);
+
+			let bounty_account = Self::bounty_account_id(bounty_id);
+			let refundable = Self::bounty_contribution_total(bounty_id);
+			let amount = T::Currency::free_balance(&bounty_account).saturating_sub(refundable);
+			ensure!(!amount.is_zero(), Error::<T, I>::NothingToSweep);
+
+			// keep the account alive for as long as contributors are owed a refund out of it.
+			let existence = if refundable.is_zero() { AllowDeath } else { KeepAlive };
+			T::Currency::transfer(&bounty_account, &Self::account_id(), amount, existence)?;
+
+			Self::deposit_event(RawEvent::BountyAccountSwept(bounty_id, amount));
+		}
+
+		/// Award a bounty to a beneficiary that is not a local account, such as an account on
+		/// another chain. The `Paymaster` sends the payout there once the bounty is claimed.
+		///
+		/// The dispatch origin for this call must be the curator of this bounty. The curator stands
+		/// in for the beneficiary on this chain: the claim is scheduled on their behalf, and they
+		/// may turn the award down through `reject_award`.
+		///
+		/// - `bounty_id`: Bounty ID to award.
+		/// - `location`: The beneficiary, which the `Paymaster` must be able to pay.
+		/// - `remark`: An optional justification of the award, of at most `MaximumReasonLength`
+		///   bytes. Only its hash is kept, in the `BountyAwardedToLocation` event.
+		///
+		/// # <weight>
+		/// - O(1).
+		/// # </weight>
+		#[weight = <T as Config<I>>::WeightInfo::award_bounty_to_location()]
+		fn award_bounty_to_location(
+			origin,
+			#[compact] bounty_id: BountyIndex,
+			location: BeneficiaryLocationOf<T, I>,
+			remark: Option<Vec<u8>>,
+		) {
+			let signer = ensure_signed(origin)?;
+			ensure!(T::Paymaster::can_pay(&location), Error::<T, I>::UnsupportedBeneficiary);
+			let remark_hash = Self::remark_hash(remark)?;
+			Self::ensure_not_paused(bounty_id)?;
+
+			Self::do_award(bounty_id, signer.clone(), &signer, false)?;
+			BountyBeneficiaryLocations::<T, I>::insert(bounty_id, &location);
+
+			Self::deposit_event(RawEvent::BountyAwardedToLocation(bounty_id, location, remark_hash));
+		}
+
+		/// Veto a bounty at any stage, whether or not it is paused.
+		///
+		/// May only be called from `T::VetoOrigin`.
+		///
+		/// What happens to the deposits held for the bounty depends on its status:
+		/// - A proposal, or an approved bounty not funded yet, is dropped and the proposer's bond
+		///   returned in full: a veto is no judgement on the proposer.
+		/// - A funded bounty is closed like through `close_bounty`, and its funds returned to its
+		///   funding source. An accepted curator gets their deposit back.
+		/// - An awarded bounty is closed the same way, but the curator deposit is slashed, and held
+		///   for appeal like any slash by `RejectOrigin`. The deposit of a disputer is returned.
+		///
+		/// - `bounty_id`: Bounty ID to veto.
+		/// - `remark`: An optional justification of the veto, of at most `MaximumReasonLength`
+		///   bytes. Only its hash is kept, in the `BountyVetoed` event.
+		///
+		/// # <weight>
+		/// - O(A) where A is `MaxApprovals`, for the approved bounties waiting to be funded.
+		/// # </weight>
+		#[weight = <T as Config<I>>::WeightInfo::veto_bounty()
+			.saturating_add(Module::<T, I>::entries_removal_weight())]
+		fn veto_bounty(origin, #[compact] bounty_id: BountyIndex, remark: Option<Vec<u8>>) {
+			T::VetoOrigin::ensure_origin(origin)?;
+			let remark_hash = Self::remark_hash(remark)?;
+			let bounty = Self::bounties(bounty_id).ok_or(Error::<T, I>::InvalidIndex)?;
+
+			let mut curator_slash = Zero::zero();
+			// a curator may be proposed, and unassigned, before the bounty is funded: the status
+			// alone does not tell.
+			if !Self::is_funded(bounty_id, &bounty) {
+				if Self::is_approved(bounty_id, &bounty) {
+					BountyApprovals::<I>::mutate(|approvals| approvals.retain(|index| *index != bounty_id));
+					Self::release_active_value(bounty.finance.value);
+					BountyFundingSources::<T, I>::remove(bounty_id);
+					BountyPriorities::<I>::remove(bounty_id);
+				} else {
+					Self::close_proposal(&bounty.proposer);
+				}
+				// a curator may be proposed before the bounty is funded.
+				if let Some(curator) = bounty.status.curator() {
+					Self::unindex_bounty::<BountiesByCurator<T, I>>(curator, bounty_id);
+				}
+				Self::remove_bounty_data(bounty_id);
+				Self::unindex_bounty::<BountiesByProposer<T, I>>(&bounty.proposer, bounty_id);
+				let _ = T::Currency::unreserve(&bounty.proposer, bounty.finance.bond);
+				T::ProposerNotifier::on_rejected(&bounty.proposer, bounty_id);
+			} else {
+				match bounty.status {
+					BountyStatus::Active { ref curator, .. } => {
+						let depositor = Self::curator_depositor(bounty_id, curator);
+						let _ = T::Currency::unreserve(&depositor, bounty.finance.curator_deposit);
+					},
+					BountyStatus::PendingPayout { ref curator, .. } => {
+						let depositor = Self::curator_depositor(bounty_id, curator);
+						let imbalance = T::Currency::slash_reserved(&depositor, bounty.finance.curator_deposit).0;
+						curator_slash = imbalance.peek();
+						Self::hold_curator_slash(bounty_id, curator, imbalance);
+						CuratorRecords::<T, I>::mutate(curator, |record| {
+							record.times_slashed = record.times_slashed.saturating_add(1);
+						});
+						Self::drop_dispute(bounty_id);
+						BountyBeneficiaryLocations::<T, I>::remove(bounty_id);
+						Self::cancel_claim(bounty_id);
+					},
+					_ => {},
+				}
+				Self::close_funded(bounty_id, &bounty);
+			}
+			PausedBounties::<I>::remove(bounty_id);
+			Bounties::<T, I>::remove(bounty_id);
+
+			Self::deposit_event(RawEvent::BountyVetoed(bounty_id, curator_slash, remark_hash));
+		}
+
+		/// Increase the value of a funded bounty, e.g. because the scope of the work grew.
+		///
+		/// May only be called from `T::ApproveOrigin`.
+		///
+		/// The value of the bounty is increased right away, and the increase is funded from its
+		/// funding source at a spend period, along with the approved bounties. Increasing the
+		/// value again before then adds to the increase waiting to be funded.
+		///
+		/// - `bounty_id`: Bounty ID to increase the value of. It must be funded and not yet
+		///   awarded.
+		/// - `additional`: The amount to add to the value of the bounty.
+		///
+		/// # <weight>
+		/// - O(A) where A is `MaxApprovals`, for the approved bounties waiting to be funded.
+		/// # </weight>
+		#[weight = <T as Config<I>>::WeightInfo::increase_bounty_value()]
+		fn increase_bounty_value(
+			origin,
+			#[compact] bounty_id: BountyIndex,
+			#[compact] additional: BalanceOf<T>,
+		) {
+			T::ApproveOrigin::ensure_origin(origin)?;
+			ensure!(!additional.is_zero(), Error::<T, I>::InvalidValue);
+			Self::ensure_not_paused(bounty_id)?;
+
+			let mut bounty = Self::bounties(bounty_id).ok_or(Error::<T, I>::InvalidIndex)?;
+			let pending = Self::bounty_value_increase(bounty_id);
+			match bounty.status {
+				BountyStatus::Funded | BountyStatus::CuratorProposed { .. } | BountyStatus::Active { .. } => {},
+				_ => return Err(Error::<T, I>::UnexpectedStatus.into()),
+			}
+			if pending.is_none() {
+				// a curator may be proposed before the bounty is funded.
+				ensure!(!Self::bounty_approvals().contains(&bounty_id), Error::<T, I>::UnexpectedStatus);
+				Self::ensure_approval_capacity(1)?;
+			}
+			let total_value = Self::ensure_active_value_capacity(additional)?;
+
+			bounty.finance.value = bounty.finance.value.saturating_add(additional);
+			Bounties::<T, I>::insert(bounty_id, &bounty);
+			TotalActiveBountyValue::<T, I>::put(total_value);
+			BountyValueIncreases::<T, I>::insert(bounty_id, pending.unwrap_or_else(Zero::zero).saturating_add(additional));
+			if pending.is_none() {
+				BountyApprovals::<I>::append(bounty_id);
+			}
+
+			Self::deposit_event(RawEvent::BountyValueIncreased(bounty_id, additional, bounty.finance.value));
+		}
+
+		/// Agree to have the deposit of `curator` for a bounty reserved from the origin, should
+		/// `curator` accept to curate it through `accept_curator_with_sponsor`.
+		///
+		/// The dispatch origin for this call must be _Signed_ by the sponsor. Nothing is reserved
+		/// until the curator accepts, and the sponsor may withdraw through
+		/// `withdraw_curator_sponsorship` until then. At most `MaxCuratorSponsors` accounts may
+		/// sponsor a bounty at once.
+		///
+		/// - `bounty_id`: Bounty ID to sponsor the curator deposit for. It must be funded and not
+		///   have an accepted curator.
+		/// - `curator`: The curator to sponsor, replacing any other the origin sponsors for the
+		///   bounty.
+		/// - `max_deposit`: The most the sponsor agrees to have reserved.
+		///
+		/// # <weight>
+		/// - O(1).
+		/// # </weight>
+		#[weight = <T as Config<I>>::WeightInfo::sponsor_curator()]
+		fn sponsor_curator(
+			origin,
+			#[compact] bounty_id: BountyIndex,
+			curator: <T::Lookup as StaticLookup>::Source,
+			#[compact] max_deposit: BalanceOf<T>,
+		) {
+			let sponsor = ensure_signed(origin)?;
+			let curator = T::Lookup::lookup(curator)?;
+			let bounty = Self::bounties(bounty_id).ok_or(Error::<T, I>::InvalidIndex)?;
+			match bounty.status {
+				BountyStatus::Funded | BountyStatus::CuratorProposed { .. } => {},
+				_ => return Err(Error::<T, I>::UnexpectedStatus.into()),
+			}
+
+			if !CuratorSponsorships::<T, I>::contains_key(bounty_id, &sponsor) {
+				let count = Self::curator_sponsorship_count(bounty_id);
+				ensure!(count < T::MaxCuratorSponsors::get(), Error::<T, I>::TooManySponsors);
+				CuratorSponsorshipCounts::<I>::insert(bounty_id, count + 1);
+			}
+			CuratorSponsorships::<T, I>::insert(bounty_id, &sponsor, (&curator, max_deposit));
+
+			Self::deposit_event(RawEvent::CuratorSponsorshipSet(bounty_id, sponsor, Some((curator, max_deposit))));
+		}
+
+		/// Withdraw the agreement of the origin to sponsor the deposit of a curator for a bounty.
+		/// A deposit already reserved stays reserved until the curator is paid or unassigned.
+		///
+		/// The dispatch origin for this call must be _Signed_ by the sponsor.
+		///
+		/// - `bounty_id`: Bounty ID the origin sponsors a curator deposit for.
+		///
+		/// # <weight>
+		/// - O(1).
+		/// # </weight>
+		#[weight = <T as Config<I>>::WeightInfo::withdraw_curator_sponsorship()]
+		fn withdraw_curator_sponsorship(origin, #[compact] bounty_id: BountyIndex) {
+			let sponsor = ensure_signed(origin)?;
+			ensure!(CuratorSponsorships::<T, I>::contains_key(bounty_id, &sponsor), Error::<T, I>::NoSponsorship);
+
+			Self::remove_sponsorship(bounty_id, &sponsor);
+
+			Self::deposit_event(RawEvent::CuratorSponsorshipSet(bounty_id, sponsor, None));
+		}
+
+		/// Accept the curator role for a bounty, with the deposit reserved from a sponsor that
+		/// agreed to it through `sponsor_curator`, instead of from the curator.
+		///
+		/// May only be called from the curator.
+		///
+		/// The deposit is returned to, or slashed from, the sponsor like it would be from the
+		/// curator. A slash held for appeal is restored to the sponsor.
+		///
+		/// - `bounty_id`: Bounty ID to curate.
+		/// - `sponsor`: The account to reserve the deposit from. The call fails if the deposit is
+		///   higher than the sponsor agreed to.
+		///
+		/// # <weight>
+		/// - O(1).
+		/// # </weight>
+		#[weight = <T as Config<I>>::WeightInfo::accept_curator_with_sponsor()]
+		fn accept_curator_with_sponsor(
+			origin,
+			#[compact] bounty_id: BountyIndex,
+			sponsor: <T::Lookup as StaticLookup>::Source,
+		) {
+			let signer = ensure_signed(origin)?;
+			let sponsor = T::Lookup::lookup(sponsor)?;
+			Self::ensure_not_paused(bounty_id)?;
+
+			let max_deposit = match Self::curator_sponsorship(bounty_id, &sponsor) {
+				Some((curator, max_deposit)) if curator == signer => max_deposit,
+				_ => return Err(Error::<T, I>::NoSponsorship.into()),
+			};
+			let deposit = Self::do_accept_curator(bounty_id, &signer, Some(&sponsor), Some(max_deposit))?;
+			Self::remove_sponsorship(bounty_id, &sponsor);
+
+			let identity = T::IdentityProvider::identity_of(&signer);
+			Self::deposit_event(RawEvent::CuratorAccepted(bounty_id, signer, identity));
+			Self::deposit_event(RawEvent::CuratorDepositSponsored(bounty_id, sponsor, deposit));
+		}
+
+		/// Propose a new bounty with the value and description of another, e.g. for recurring
+		/// work.
+		///
+		/// The dispatch origin for this call must be _Signed_, and is the proposer of the new
+		/// bounty. The bond is reserved from the origin like through `propose_bounty`.
+		///
+		/// The description is copied in full while it is stored for `bounty_id`. Otherwise, e.g.
+		/// once `bounty_id` was removed, only its hash is, like through `propose_bounty_with_hash`.
+		///
+		/// - `bounty_id`: Bounty ID to copy, whether it still exists or not.
+		///
+		/// # <weight>
+		/// - O(D) where D is the length of the description copied.
+		/// - Only the weight of the description actually copied is charged.
+		/// # </weight>
+		#[weight = <T as Config<I>>::WeightInfo::clone_bounty(T::MaximumReasonLength::get())]
+		fn clone_bounty(origin, #[compact] bounty_id: BountyIndex) -> DispatchResultWithPostInfo {
+			let proposer = ensure_signed(origin)?;
+
+			let (value, description, description_hash) = match Self::bounties(bounty_id) {
+				Some(bounty) => (
+					bounty.finance.value,
+					Self::bounty_descriptions(bounty_id),
+					Self::description_hash(bounty_id),
+				),
+				None => {
+					let (value, description_hash) = Self::bounty_outline(bounty_id)
+						.ok_or(Error::<T, I>::InvalidIndex)?;
+					(value, None, description_hash)
+				},
+			};
+
+			let (index, copied) = match description {
+				Some(description) => {
+					let len = description.len() as u32;
+					let index = Self::create_bounty(proposer, len, value)?;
+					BountyDescriptions::<I>::insert(index, description);
+					(index, len)
+				},
+				None => {
+					let index = Self::create_bounty(proposer, description_hash.as_ref().len() as u32, value)?;
+					BountyDescriptionHashes::<T, I>::insert(index, description_hash);
+					(index, 0)
+				},
+			};
+
+			Self::deposit_event(RawEvent::BountyCloned(index, bounty_id));
+			Ok(Some(<T as Config<I>>::WeightInfo::clone_bounty(copied)).into())
+		}
+
+		/// Keep the value and description hash of a bounty once it is removed, so that it can
+		/// still be cloned through `clone_bounty`.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// Payment: `DataDepositPerByte` for each byte of the outline will be reserved from the
+		/// origin account. It will be unreserved through `forget_bounty_outline`.
+		///
+		/// - `bounty_id`: Bounty ID whose outline is kept. It must still exist.
+		///
+		/// # <weight>
+		/// - O(1).
+		/// # </weight>
+		#[weight = <T as Config<I>>::WeightInfo::keep_bounty_outline()]
+		fn keep_bounty_outline(origin, #[compact] bounty_id: BountyIndex) {
+			let who = ensure_signed(origin)?;
+			let bounty = Self::bounties(bounty_id).ok_or(Error::<T, I>::InvalidIndex)?;
+			ensure!(!BountyOutlineDeposits::<T, I>::contains_key(bounty_id), Error::<T, I>::OutlineAlreadyKept);
+
+			let outline_len = (bounty.finance.value, Self::description_hash(bounty_id)).encoded_size();
+			let deposit = T::DataDepositPerByte::get() * (outline_len as u32).into();
+			T::Currency::reserve(&who, deposit)?;
+			BountyOutlineDeposits::<T, I>::insert(bounty_id, (&who, deposit));
+
+			Self::deposit_event(RawEvent::BountyOutlineKept(bounty_id, who, deposit));
+		}
+
+		/// Stop keeping the outline of a bounty, and return the deposit made for it.
+		///
+		/// The dispatch origin for this call must be _Signed_ by the account that made the
+		/// deposit. The outline can no longer be cloned once the bounty is removed.
+		///
+		/// - `bounty_id`: Bounty ID whose outline is no longer kept.
+		///
+		/// # <weight>
+		/// - O(1).
+		/// # </weight>
+		#[weight = <T as Config<I>>::WeightInfo::forget_bounty_outline()]
+		fn forget_bounty_outline(origin, #[compact] bounty_id: BountyIndex) {
+			let who = ensure_signed(origin)?;
+			let deposit = match Self::bounty_outline_deposit(bounty_id) {
+				Some((depositor, deposit)) if depositor == who => deposit,
+				_ => return Err(Error::<T, I>::NoOutlineDeposit.into()),
+			};
+
+			let _ = T::Currency::unreserve(&who, deposit);
+			BountyOutlineDeposits::<T, I>::remove(bounty_id);
+			BountyOutlines::<T, I>::remove(bounty_id);
+
+			Self::deposit_event(RawEvent::BountyOutlineForgotten(bounty_id));
+		}
+	}
+}
+
+impl<T: Config<I>, I: Instance> Module<T, I> {
+	// Add public immutables and private mutables.
+
+	/// The account ID of the treasury pot.
+	///
+	/// This actually does computation. If you need to keep using it, then make sure you cache the
+	/// value and only call this once.
+	pub fn account_id() -> T::AccountId {
+		T::ModuleId::get().into_account()
+	}
+
+	/// The account ID of a bounty account
+	pub fn bounty_account_id(id: BountyIndex) -> T::AccountId {
+		// only use two byte prefix to support 16 byte account id (used by test)
+		// "modl" ++ "py/trsry" ++ "bt" is 14 bytes, and two bytes remaining for bounty index
+		T::ModuleId::get().into_sub_account((T::BountyAccountTag::get(), id))
+	}
+
+	/// The account ID of the pot holding curator deposits slashed by `RejectOrigin` while they may
+	/// be appealed.
+	pub fn curator_slash_account_id() -> T::AccountId {
+		// each instance holds its own slashes, while they share `ModuleId` with the treasury.
+		T::ModuleId::get().into_sub_account(("ap", I::PREFIX))
+	}
+
+	/// All bounties, ordered by index.
+	///
+	/// This iterates over the whole `Bounties` map and is meant for off-chain queries only.
+	pub fn all_bounties() -> Vec<(BountyIndex, BountyOf<T>)> {
+		let mut bounties = Bounties::<T, I>::iter().collect::<Vec<_>>();
+		bounties.sort_by_key(|(index, _)| *index);
+		bounties
+	}
+
+	/// All bounties whose status is of the given `kind`, ordered by index.
+	///
+	/// This iterates over the whole `Bounties` map and is meant for off-chain queries only.
+	pub fn bounties_by_status(kind: BountyStatusKind) -> Vec<(BountyIndex, BountyOf<T>)> {
+		let mut bounties = Self::all_bounties();
+		bounties.retain(|(_, bounty)| bounty.status.kind() == kind);
+		bounties
+	}
+
+	/// All bounties tagged with `tag`, ordered by index.
+	///
+	/// This iterates over the whole `BountyTags` map and is meant for off-chain queries only.
+	pub fn bounties_with_tag(tag: &[u8]) -> Vec<(BountyIndex, BountyOf<T>)> {
+		let mut bounties = BountyTags::<I>::iter()
+			.filter(|(_, tags)| tags.iter().any(|t| &t[..] == tag))
+			.filter_map(|(index, _)| Self::bounties(index).map(|bounty| (index, bounty)))
+			.collect::<Vec<_>>();
+		bounties.sort_by_key(|(index, _)| *index);
+		bounties
+	}
+
+	/// Check the invariants between the bounties and the storage kept about them, returning the
+	/// first one found broken.
+	///
+	/// Checked are that every bounty index was handed out by `BountyCount`, that `BountyApprovals`
+	/// holds exactly the approved bounties waiting for funding, that the per-account indices agree
+	/// with the proposer and curator of each bounty, and that each funded bounty not co-funding
+	/// tips still holds its value in its account.
+	///
+	/// This iterates over whole maps and is meant for tests and for checking the state of a chain
+	/// offline, e.g. before and after a runtime upgrade. Enabled by the `try-runtime` feature.
+	#[cfg(any(test, feature = "try-runtime"))]
+	pub fn do_try_state() -> Result<(), &'static str> {
+		let count = Self::bounty_count();
+		let bounties = Self::all_bounties();
+		let approvals = Self::bounty_approvals();
+
+		for &index in approvals.iter() {
+			match Self::bounties(index).map(|bounty| bounty.status.kind()) {
+				Some(BountyStatusKind::Approved) | Some(BountyStatusKind::CuratorProposed) => (),
+				Some(_) if BountyValueIncreases::<T, I>::contains_key(index) => (),
+				_ => return Err("approval of a bounty that is neither approved nor curator-proposed"),
+			}
+		}
+		if BountyValueIncreases::<T, I>::iter().any(|(index, _)| !approvals.contains(&index)) {
+			return Err("increase of a bounty value missing from approvals");
+		}
+		if approvals.iter().enumerate().any(|(i, index)| approvals[..i].contains(index)) {
+			return Err("bounty approved more than once");
+		}
+
+		for (index, bounty) in bounties.iter() {
+			if *index >= count {
+				return Err("bounty index beyond bounty count");
+			}
+			if bounty.status == BountyStatus::Approved && !approvals.contains(index) {
+				return Err("approved bounty missing from approvals");
+			}
+			if Self::bounties_by_proposer(&bounty.proposer).binary_search(index).is_err() {
+				return Err("bounty missing from the bounties of its proposer");
+			}
+			if let Some(curator) = bounty.status.curator() {
+				if Self::bounties_by_curator(curator).binary_search(index).is_err() {
+					return Err("bounty missing from the bounties of its curator");
+				}
+			}
+
+			let funded = matches!(
+				bounty.status.kind(),
+				BountyStatusKind::Funded | BountyStatusKind::Active | BountyStatusKind::PendingPayout
+			);
+			// tips co-funded by a bounty are paid out of its account.
+			let unfunded = Self::bounty_value_increase(index).unwrap_or_else(Zero::zero);
+			if funded && Self::bounty_tip_reasons(index).is_empty() &&
+				T::Currency::free_balance(&Self::bounty_account_id(*index)) < bounty.finance.value.saturating_sub(unfunded)
+			{
+				return Err("funded bounty account holds less than the bounty value");
+			}
+		}
+
+		for (proposer, ids) in BountiesByProposer::<T, I>::iter() {
+			if ids.is_empty() || ids.windows(2).any(|w| w[0] >= w[1]) {
+				return Err("bounties of a proposer empty or not sorted");
+			}
+			if ids.iter().any(|id| Self::bounties(id).filter(|bounty| bounty.proposer == proposer).is_none()) {
+				return Err("bounty indexed under an account that did not propose it");
+			}
+		}
+		for (curator, ids) in BountiesByCurator::<T, I>::iter() {
+			if ids.is_empty() || ids.windows(2).any(|w| w[0] >= w[1]) {
+				return Err("bounties of a curator empty or not sorted");
+			}
+			if ids.iter().any(|id| Self::bounties(id)
+				.filter(|bounty| bounty.status.curator() == Some(&curator)).is_none())
+			{
+				return Err("bounty indexed under an account that does not curate it");
+			}
+		}
+
+		Ok(())
+	}
+
+	/// Calculate the deposit required for a curator, i.e. `BountyCuratorDeposit` of the `fee`,
+	/// clamped between `CuratorDepositMin` and `CuratorDepositMax` when those are set.
+	pub fn calculate_curator_deposit(fee: &BalanceOf<T>) -> BalanceOf<T> {
+		BountyFinance { fee: *fee, ..Default::default() }.deposit_due(
+			T::BountyCuratorDeposit::get(),
+			T::CuratorDepositMin::get(),
+			T::CuratorDepositMax::get(),
+		)
+	}
+
+	/// Pay `MaintenanceTip`, but no more than `available`, from the account of `bounty_id` to
+	/// `who`, returning what was paid.
+	///
+	/// Best-effort only, so that a tip to an account which cannot take it never holds up the
+	/// bounty.
+	fn pay_maintenance_tip(bounty_id: BountyIndex, who: &T::AccountId, available: BalanceOf<T>) -> BalanceOf<T> {
+		let tip = T::MaintenanceTip::get().min(available);
+		if tip.is_zero() {
+			return tip
+		}
+		match T::Currency::transfer(&Self::bounty_account_id(bounty_id), who, tip, AllowDeath) {
+			Ok(()) => {
+				Self::deposit_event(RawEvent::MaintenanceTipPaid(bounty_id, who.clone(), tip));
+				tip
+			},
+			Err(_) => Zero::zero(),
+		}
+	}
+
+	/// The hash of the remark given with an award or a cancellation, if any, checking its length.
+	fn remark_hash(remark: Option<Vec<u8>>) -> Result<Option<T::Hash>, DispatchError> {
+		remark.map(|remark| {
+			ensure!(remark.len() <= T::MaximumReasonLength::get() as usize, Error::<T, I>::ReasonTooBig);
+			Ok(T::Hashing::hash(&remark[..]))
+		}).transpose()
+	}
+
+	/// The hash of a bounty's description, whether it was proposed in full or by hash.
+	fn description_hash(bounty_id: BountyIndex) -> T::Hash {
+		Self::bounty_description_hash(bounty_id).unwrap_or_else(|| {
+			T::Hashing::hash(&Self::bounty_descriptions(bounty_id).unwrap_or_default()[..])
+		})
+	}
+
+	/// Remove the data kept alongside a bounty that is being removed: its description, whether
+	/// stored in full or as a hash, its tags and terms, the tip reasons it co-funds, any increase
+	/// of its value still to be funded, its work submissions, curator applications and
+	/// sponsorships, and any dispute of its award. The deposits held for any of them are returned.
+	/// The outline of the bounty, if it still exists and a deposit was made for it, is kept for
+	/// `clone_bounty`.
+	fn remove_bounty_data(bounty_id: BountyIndex) {
+		if let Some(bounty) = Self::bounties(bounty_id) {
+			if BountyOutlineDeposits::<T, I>::contains_key(bounty_id) {
+				BountyOutlines::<T, I>::insert(bounty_id, (bounty.finance.value, Self::description_hash(bounty_id)));
+			}
+		}
+		BountyDescriptions::<I>::remove(bounty_id);
+		BountyTags::<I>::remove(bounty_id);
+		SelfAwardApprovals::<T, I>::remove(bounty_id);
+		BountyPayoutDelays::<T, I>::remove(bounty_id);
+		BountyProposalTerms::<T, I>::remove(bounty_id);
+		BountyCuratorFeeRates::<I>::remove(bounty_id);
+		Self::drop_work_submissions(bounty_id);
+		Self::drop_curator_applications(bounty_id);
+		FastTrackedBounties::<I>::remove(bounty_id);
+		Self::remove_tip_co_funding(bounty_id);
+		BountyDescriptionHashes::<T, I>::remove(bounty_id);
+		BountyApprovedDescriptionHashes::<T, I>::remove(bounty_id);
+		BountyCuratorSponsors::<T, I>::remove(bounty_id);
+		CuratorSponsorships::<T, I>::remove_prefix(bounty_id);
+		CuratorSponsorshipCounts::<I>::remove(bounty_id);
+		Self::drop_dispute(bounty_id);
+		if BountyValueIncreases::<T, I>::take(bounty_id).is_some() {
+			BountyApprovals::<I>::mutate(|v| v.retain(|index| *index != bounty_id));
+		}
+		if let Some((who, deposit)) = BountyDescriptionDeposits::<T, I>::take(bounty_id) {
+			let _ = T::Currency::unreserve(&who, deposit);
+		}
+	}
+
+	/// The delay between awarding `bounty_id` and its payout being claimable: the one set for
+	/// it, else the one it was proposed with, else `BountyDepositPayoutDelay`. Halved if the
+	/// bounty is fast-tracked.
+	fn payout_delay(bounty_id: BountyIndex) -> T::BlockNumber {
+		let delay = Self::bounty_payout_delay(bounty_id)
+			.or_else(|| Self::bounty_terms(bounty_id).map(|terms| terms.payout_delay))
+			.unwrap_or_else(T::BountyDepositPayoutDelay::get);
+		Self::fast_tracked(bounty_id, delay)
+	}
+
+	/// Award the active bounty `bounty_id` of `curator` to `beneficiary` and schedule its claim.
+	///
+	/// `self_award` is whether the award needs the approval of `ApproveOrigin` when
+	/// `RequireSelfAwardApproval` is set.
+	fn do_award(
+		bounty_id: BountyIndex,
+		curator: T::AccountId,
+		beneficiary: &T::AccountId,
+		self_award: bool,
+	) -> DispatchResult {
+		let unlock_at = system::Module::<T>::block_number() + Self::payout_delay(bounty_id);
+		Bounties::<T, I>::try_mutate_exists(bounty_id, |maybe_bounty| -> DispatchResult {
+			let mut bounty = maybe_bounty.as_mut().ok_or(Error::<T, I>::InvalidIndex)?;
+			match &bounty.status {
+				BountyStatus::Active {
+					curator: active_curator,
+					..
+				} => {
+					ensure!(curator == *active_curator, Error::<T, I>::RequireCurator);
+					if self_award && T::RequireSelfAwardApproval::get() {
+						ensure!(
+							Self::self_award_approval(bounty_id).as_ref() == Some(&curator),
+							Error::<T, I>::SelfAwardRequiresApproval
+						);
+					}
+				},
+				_ => return Err(Error::<T, I>::UnexpectedStatus.into()),
+			}
+			SelfAwardApprovals::<T, I>::remove(bounty_id);
+			bounty.status.transition(BountyStatus::PendingPayout {
+				curator,
+				beneficiary: beneficiary.clone(),
+				unlock_at,
+			});
+
+			Ok(())
+		})?;
+		Self::schedule_claim(bounty_id, beneficiary, unlock_at);
+		Ok(())
+	}
+
+	/// Make `curator`, proposed to curate `bounty_id`, its curator, reserving the curator deposit
+	/// from `sponsor` if given, else from `curator`, and returning the deposit.
+	///
+	/// The call fails if the deposit is higher than `max_deposit`.
+	fn do_accept_curator(
+		bounty_id: BountyIndex,
+		curator: &T::AccountId,
+		sponsor: Option<&T::AccountId>,
+		max_deposit: Option<BalanceOf<T>>,
+	) -> Result<BalanceOf<T>, DispatchError> {
+		if CuratorSlashAppeals::<T, I>::contains_key(bounty_id, curator) {
+			ensure!(
+				Self::slashed_curator_sponsor(bounty_id, curator).as_ref() == sponsor,
+				Error::<T, I>::HeldSlashOfOtherDepositor
+			);
+		}
+
+		Bounties::<T, I>::try_mutate_exists(bounty_id, |maybe_bounty| -> Result<_, DispatchError> {
+			let bounty = maybe_bounty.as_mut().ok_or(Error::<T, I>::InvalidIndex)?;
+
+			match bounty.status {
+				BountyStatus::CuratorProposed { curator: ref proposed } => {
+					ensure!(curator == proposed, Error::<T, I>::RequireCurator);
+
+					let deposit = Self::calculate_curator_deposit(&bounty.finance.fee);
+					if let Some(max_deposit) = max_deposit {
+						ensure!(deposit <= max_deposit, Error::<T, I>::DepositTooHigh);
+					}
+					T::Currency::reserve(sponsor.unwrap_or(curator), deposit)?;
+					bounty.finance.curator_deposit = deposit;
+					if let Some(sponsor) = sponsor {
+						BountyCuratorSponsors::<T, I>::insert(bounty_id, sponsor);
+					}
+
+					let update_due = system::Module::<T>::block_number() + Self::update_period(bounty_id);
+					bounty.status.transition(BountyStatus::Active { curator: curator.clone(), update_due });
+
+					Ok(deposit)
+				},
+				_ => Err(Error::<T, I>::UnexpectedStatus.into()),
+			}
+		})
+	}
+
+	/// The account the deposit of `curator`, the curator of `bounty_id`, is reserved from.
+	fn curator_depositor(bounty_id: BountyIndex, curator: &T::AccountId) -> T::AccountId {
+		Self::bounty_curator_sponsor(bounty_id).unwrap_or_else(|| curator.clone())
+	}
+
+	/// Propose `curator` for `bounty_id`, for the fee that `fee_of` gives on the bounty value.
+	fn do_propose_curator(
+		bounty_id: BountyIndex,
+		curator: &T::AccountId,
+		fee_of: impl FnOnce(BalanceOf<T>) -> BalanceOf<T>,
+	) -> DispatchResult {
+		Self::ensure_not_paused(bounty_id)?;
+		ensure!(!Self::curator_banned(curator), Error::<T, I>::BannedCurator);
+		ensure!(T::CuratorFilter::filter(curator), Error::<T, I>::CuratorNotAllowed);
+		Bounties::<T, I>::try_mutate_exists(bounty_id, |maybe_bounty| -> DispatchResult {
+			let mut bounty = maybe_bounty.as_mut().ok_or(Error::<T, I>::InvalidIndex)?;
+			match bounty.status {
+				BountyStatus::Proposed | BountyStatus::Approved | BountyStatus::Funded => {},
+				_ => return Err(Error::<T, I>::UnexpectedStatus.into()),
+			};
+
+			let fee = fee_of(bounty.finance.value);
+			ensure!(fee < bounty.finance.value, Error::<T, I>::InvalidFee);
+			Self::ensure_index_capacity::<BountiesByCurator<T, I>>(curator)?;
+
+			bounty.status.transition(BountyStatus::CuratorProposed { curator: curator.clone() });
+			bounty.finance.fee = fee;
+			Self::index_bounty::<BountiesByCurator<T, I>>(curator, bounty_id);
+
+			Ok(())
+		})
+	}
+
+	/// Record that `curator` saw a bounty through to its payout and was paid `fee` for it.
+	fn record_completed(curator: &T::AccountId, fee: BalanceOf<T>) {
+		CuratorRecords::<T, I>::mutate(curator, |record| {
+			record.bounties_completed = record.bounties_completed.saturating_add(1);
+			record.fees_earned = record.fees_earned.saturating_add(fee);
+		});
+	}
+
+	/// Stop co-funding the tips for any reason from `bounty_id`.
+	fn remove_tip_co_funding(bounty_id: BountyIndex) {
+		for reason in BountyTipReasons::<T, I>::take(bounty_id) {
+			TipCoFunding::<T, I>::remove(reason);
+		}
+	}
+
+	/// The deposit held for storing `tags`.
+	fn tags_deposit(tags: &[Vec<u8>]) -> BalanceOf<T> {
+		let len = tags.iter().map(|tag| tag.len() as u32).sum::<u32>();
+		T::DataDepositPerByte::get() * len.into()
+	}
+
+	/// Hold the slashed deposit of `curator` in the appeal account until `AppealPeriod` from now,
+	/// remembering the sponsor it was reserved from, if any.
+	///
+	/// Anything that cannot be held, because it is below the existential deposit of the appeal
+	/// account, is passed on to `OnSlash` right away.
+	fn hold_curator_slash(bounty_id: BountyIndex, curator: &T::AccountId, imbalance: NegativeImbalanceOf<T>) {
+		let held = T::Currency::deposit_creating(&Self::curator_slash_account_id(), imbalance.peek());
+		let amount = held.peek();
+		if let Ok(rest) = imbalance.offset(held) {
+			T::OnSlash::on_unbalanced(rest);
+		}
+		if amount.is_zero() {
+			return
+		}
+
+		let appeal_end = system::Module::<T>::block_number() + T::AppealPeriod::get();
+		CuratorSlashAppeals::<T, I>::mutate(bounty_id, curator, |maybe_held| {
+			let total = maybe_held.map_or(amount, |(held, _)| held.saturating_add(amount));
+			*maybe_held = Some((total, appeal_end));
+		});
+		if let Some(sponsor) = Self::bounty_curator_sponsor(bounty_id) {
+			SlashedCuratorSponsors::<T, I>::insert(bounty_id, curator, sponsor);
+		}
+
+		Self::deposit_event(RawEvent::CuratorSlashHeld(bounty_id, curator.clone(), amount, appeal_end));
+	}
+
+	/// `period`, halved if `bounty_id` was fast-tracked.
+	fn fast_tracked(bounty_id: BountyIndex, period: T::BlockNumber) -> T::BlockNumber {
+		if Self::bounty_fast_tracked(bounty_id) {
+			period / 2u32.into()
+		} else {
+			period
+		}
+	}
+
+	/// The period within which the curator of `bounty_id` must give an update.
+	fn update_period(bounty_id: BountyIndex) -> T::BlockNumber {
+		Self::fast_tracked(bounty_id, T::BountyUpdatePeriod::get())
+	}
+
+	/// The name of the claim scheduled for the awarded bounty `bounty_id`.
+	fn claim_task_id(bounty_id: BountyIndex) -> Vec<u8> {
+		(BOUNTIES_ID, I::PREFIX, bounty_id).encode()
+	}
+
+	/// Schedule the claim of the awarded bounty `bounty_id` for `unlock_at`, or the next block if
+	/// that has passed, replacing any claim scheduled for it before. The claim is dispatched as
+	/// signed by `beneficiary`, which any account may claim with.
+	fn schedule_claim(bounty_id: BountyIndex, beneficiary: &T::AccountId, unlock_at: T::BlockNumber) {
+		Self::cancel_claim(bounty_id);
+		let when = unlock_at.max(system::Module::<T>::block_number() + One::one());
+		if T::Scheduler::schedule_named(
+			Self::claim_task_id(bounty_id),
+			DispatchTime::At(when),
+			None,
+			LOWEST_PRIORITY,
+			frame_system::RawOrigin::Signed(beneficiary.clone()).into(),
+			Call::<T, I>::claim_bounty(bounty_id).into(),
+		).is_err() {
+			frame_support::print("LOGIC ERROR: schedule_claim/schedule_named failed");
+		}
+	}
+
+	/// Cancel the claim scheduled for `bounty_id`, if it is still to come.
+	fn cancel_claim(bounty_id: BountyIndex) {
+		let id = Self::claim_task_id(bounty_id);
+		// a claim due in this block is being, or has been, dispatched.
+		let now = system::Module::<T>::block_number();
+		if matches!(T::Scheduler::next_dispatch_time(id.clone()), Ok(when) if when > now) {
+			let _ = T::Scheduler::cancel_named(id);
+		}
+	}
+
+	/// Ensure that the bounty is not paused.
+	fn ensure_not_paused(bounty_id: BountyIndex) -> DispatchResult {
+		ensure!(!Self::bounty_paused(bounty_id), Error::<T, I>::BountyPaused);
+		Ok(())
+	}
+
+	/// Drop the work submitted for `bounty_id`, returning the deposits of the submitters.
+	fn drop_work_submissions(bounty_id: BountyIndex) {
+		for (who, _, _) in WorkSubmissions::<T, I>::take(bounty_id) {
+			if let Some(deposit) = WorkSubmissionDeposits::<T, I>::take(bounty_id, &who) {
+				let _ = T::Currency::unreserve(&who, deposit);
+			}
+		}
+	}
+
+	/// Drop the curator applications for `bounty_id`, returning the deposits of the applicants.
+	fn drop_curator_applications(bounty_id: BountyIndex) {
+		for (applicant, _) in CuratorApplications::<T, I>::take(bounty_id) {
+			if let Some(deposit) = CuratorApplicationDeposits::<T, I>::take(bounty_id, &applicant) {
+				let _ = T::Currency::unreserve(&applicant, deposit);
+			}
+		}
+	}
+
+	/// Withdraw the agreement of `sponsor` to sponsor the curator deposit of `bounty_id`.
+	fn remove_sponsorship(bounty_id: BountyIndex, sponsor: &T::AccountId) {
+		CuratorSponsorships::<T, I>::remove(bounty_id, sponsor);
+		CuratorSponsorshipCounts::<I>::mutate(bounty_id, |count| *count = count.saturating_sub(1));
+	}
+
+	/// The weight of removing the work submissions, curator applications and sponsorships of a
+	/// removed bounty, charged on top of the benchmarked weight of the calls that remove bounties.
+	fn entries_removal_weight() -> Weight {
+		let depositors = Weight::from(T::MaxWorkSubmissions::get())
+			.saturating_add(T::MaxCuratorApplicants::get().into());
+		T::DbWeight::get().reads_writes(2 * depositors, 2 * depositors)
+			.saturating_add(T::DbWeight::get().writes(T::MaxCuratorSponsors::get().into()))
+	}
+
+	/// Remove the dispute of the award of `bounty_id`, if any, returning the deposit of the
+	/// disputer.
+	fn drop_dispute(bounty_id: BountyIndex) {
+		if let Some(dispute) = BountyDisputes::<T, I>::take(bounty_id) {
+			let _ = T::Currency::unreserve(&dispute.disputer, dispute.deposit);
+		}
+	}
+
+	/// Ensure that the award of `bounty_id` is not disputed.
+	fn ensure_not_disputed(bounty_id: BountyIndex) -> DispatchResult {
+		ensure!(!BountyDisputes::<T, I>::contains_key(bounty_id), Error::<T, I>::Disputed);
+		Ok(())
+	}
+
+	/// Ensure that the bounty exists and is a proposal that may be approved, returning its value.
+	fn ensure_approvable(bounty_id: BountyIndex) -> Result<BalanceOf<T>, DispatchError> {
+		let bounty = Self::bounties(bounty_id).ok_or(Error::<T, I>::InvalidIndex)?;
+		Self::ensure_not_paused(bounty_id)?;
+		ensure!(bounty.status == BountyStatus::Proposed, Error::<T, I>::UnexpectedStatus);
+		Ok(bounty.finance.value)
+	}
+
+	/// Ensure that `count` more bounties can wait to be funded without exceeding `MaxApprovals`.
+	fn ensure_approval_capacity(count: usize) -> DispatchResult {
+		let approvals = BountyApprovals::<I>::decode_len().unwrap_or(0);
+		ensure!(
+			approvals.saturating_add(count) <= T::MaxApprovals::get() as usize,
+			Error::<T, I>::TooManyApprovals
+		);
+		Ok(())
+	}
+
+	/// Ensure that bounties worth `value` more can be approved without exceeding
+	/// `MaxTotalActiveBountyValue`, returning the new total value of active bounties.
+	fn ensure_active_value_capacity(value: BalanceOf<T>) -> Result<BalanceOf<T>, DispatchError> {
+		let total_value = Self::total_active_bounty_value().saturating_add(value);
+		ensure!(total_value <= T::MaxTotalActiveBountyValue::get(), Error::<T, I>::TooMuchActiveBountyValue);
+		Ok(total_value)
+	}
+
+	/// Take `value` of a bounty that was paid out or closed off the total value of active
+	/// bounties.
+	fn release_active_value(value: BalanceOf<T>) {
+		TotalActiveBountyValue::<T, I>::mutate(|total| *total = total.saturating_sub(value));
+	}
+
+	/// Update the bounty metrics for a funded bounty that was removed after paying out
+	/// `paid_out`.
+	fn record_removed(paid_out: BalanceOf<T>) {
+		ActiveBountyCount::<I>::mutate(|count| *count = count.saturating_sub(1));
+		if !paid_out.is_zero() {
+			TotalValuePaidOut::<T, I>::mutate(|total| *total = total.saturating_add(paid_out));
+		}
+	}
+
+	/// Whether `bounty` was approved, and so counts towards `TotalActiveBountyValue`.
+	fn is_approved(bounty_id: BountyIndex, bounty: &BountyOf<T>) -> bool {
+		match bounty.status {
+			BountyStatus::Proposed => false,
+			BountyStatus::Approved | BountyStatus::Active { .. } | BountyStatus::PendingPayout { .. } => true,
+			// a curator may be proposed, and unassigned, before the bounty is approved.
+			BountyStatus::Funded | BountyStatus::CuratorProposed { .. } =>
+				BountyFundingSources::<T, I>::contains_key(bounty_id),
+		}
+	}
+
+	/// Whether `bounty` was funded, and so counts towards `ActiveBountyCount`.
+	fn is_funded(bounty_id: BountyIndex, bounty: &BountyOf<T>) -> bool {
+		match bounty.status {
+			BountyStatus::Proposed | BountyStatus::Approved => false,
+			BountyStatus::Active { .. } | BountyStatus::PendingPayout { .. } => true,
+			// the approval of a funded bounty is for an increase of its value.
+			BountyStatus::Funded | BountyStatus::CuratorProposed { .. } =>
+				Self::is_approved(bounty_id, bounty) && (
+					BountyValueIncreases::<T, I>::contains_key(bounty_id) ||
+					!Self::bounty_approvals().contains(&bounty_id)
+				),
+		}
+	}
+
+	/// Record the default funding source for the bounties approved before funding sources were
+	/// recorded, so that they are not taken for proposals.
+	///
+	/// A bounty that got a curator proposed before it was approved has the status of a funded
+	/// one, so such bounties only count as approved if they wait in `BountyApprovals` or their
+	/// account holds their value.
+	pub fn migrate_funding_sources() -> Weight {
+		let approvals = Self::bounty_approvals();
+		let (mut reads, mut writes) = (1, 0);
+		for (bounty_id, bounty) in Bounties::<T, I>::iter() {
+			reads += 2;
+			if BountyFundingSources::<T, I>::contains_key(bounty_id) {
+				continue;
+			}
+			let approved = match bounty.status {
+				BountyStatus::Proposed => false,
+				BountyStatus::Approved | BountyStatus::Active { .. } | BountyStatus::PendingPayout { .. } => true,
+				BountyStatus::Funded | BountyStatus::CuratorProposed { .. } => {
+					reads += 1;
+					approvals.contains(&bounty_id) ||
+						T::Currency::free_balance(&Self::bounty_account_id(bounty_id)) >= bounty.finance.value
+				},
+			};
+			if approved {
+				BountyFundingSources::<T, I>::insert(bounty_id, T::FundingSource::default());
+				writes += 1;
+			}
+		}
+		T::DbWeight::get().reads_writes(reads, writes)
+	}
+
+	/// Remove everything kept for the funded bounty `bounty_id` but the bounty itself, and
+	/// return its funds, less the contributions still to be refunded, to its funding source.
+	fn close_funded(bounty_id: BountyIndex, bounty: &BountyOf<T>) {
+		let bounty_account = Self::bounty_account_id(bounty_id);
+
+		Self::remove_bounty_data(bounty_id);
+		Self::release_active_value(bounty.finance.value);
+		Self::unindex_bounty::<BountiesByProposer<T, I>>(&bounty.proposer, bounty_id);
+		if let Some(curator) = bounty.status.curator() {
+			Self::unindex_bounty::<BountiesByCurator<T, I>>(curator, bounty_id);
+		}
+
+		let funding_account = BountyFundingSources::<T, I>::take(bounty_id).account_id();
+		// contributions stay in the bounty account until they are refunded.
+		let balance = T::Currency::free_balance(&bounty_account)
+			.saturating_sub(Self::bounty_contribution_total(bounty_id));
+		let _ = T::Currency::transfer(&bounty_account, &funding_account, balance, AllowDeath); // should not fail
+		Self::record_removed(Zero::zero());
+	}
+
+	/// Approve a bounty proposal to be funded from `funding_source`, recording the hash of its
+	/// description.
+	///
+	/// The caller is responsible for checking `ensure_approvable` and `ensure_approval_capacity`
+	/// first.
+	fn approve(bounty_id: BountyIndex, funding_source: T::FundingSource) {
+		Bounties::<T, I>::mutate(bounty_id, |maybe_bounty| if let Some(bounty) = maybe_bounty {
+			bounty.status.transition(BountyStatus::Approved);
+			Self::close_proposal(&bounty.proposer);
+			T::ProposerNotifier::on_approved(&bounty.proposer, bounty_id);
+		});
+		BountyApprovals::<I>::append(bounty_id);
+
+		let description_hash = Self::description_hash(bounty_id);
+		BountyApprovedDescriptionHashes::<T, I>::insert(bounty_id, description_hash);
+		BountyFundingSources::<T, I>::insert(bounty_id, funding_source);
+
+		Self::deposit_event(RawEvent::BountyApproved(bounty_id, description_hash));
+	}
+
+	/// Take a bounty proposal of `proposer` that was approved or rejected off their open proposals.
+	fn close_proposal(proposer: &T::AccountId) {
+		OpenProposalCounts::<T, I>::mutate_exists(proposer, |count| {
+			*count = count.and_then(|c| c.checked_sub(1)).filter(|c| *c > 0);
+		});
+	}
+
+	/// Ensure that `who` can be added to one more bounty of the per-account `Index`.
+	fn ensure_index_capacity<Index>(who: &T::AccountId) -> DispatchResult
+		where Index: StorageMap<T::AccountId, Vec<BountyIndex>, Query = Vec<BountyIndex>>
+	{
+		ensure!(
+			Index::decode_len(who).unwrap_or(0) < T::MaxBountiesPerAccount::get() as usize,
+			Error::<T, I>::TooManyBounties
+		);
+		Ok(())
+	}
+
+	/// Add `bounty_id` to the bounties of `who` in the per-account `Index`, keeping it sorted.
+	///
+	/// The caller is responsible for checking `ensure_index_capacity` first.
+	fn index_bounty<Index>(who: &T::AccountId, bounty_id: BountyIndex)
+		where Index: StorageMap<T::AccountId, Vec<BountyIndex>, Query = Vec<BountyIndex>>
+	{
+		Index::mutate(who, |ids| if let Err(pos) = ids.binary_search(&bounty_id) {
+			ids.insert(pos, bounty_id);
+		});
+	}
+
+	/// Remove `bounty_id` from the bounties of `who` in the per-account `Index`.
+	fn unindex_bounty<Index>(who: &T::AccountId, bounty_id: BountyIndex)
+		where Index: StorageMap<T::AccountId, Vec<BountyIndex>, Query = Vec<BountyIndex>>
+	{
+		Index::mutate_exists(who, |maybe_ids| if let Some(ids) = maybe_ids {
+			if let Ok(pos) = ids.binary_search(&bounty_id) {
+				ids.remove(pos);
+			}
+			if ids.is_empty() {
+				*maybe_ids = None;
+			}
+		});
+	}
+
+	/// Reserve the bond of a new bounty proposal and store it, returning its index.
+	///
+	/// `data_len` is the number of bytes stored on chain to describe the bounty; the caller is
+	/// responsible for storing the description itself.
+	fn create_bounty(
+		proposer: T::AccountId,
+		data_len: u32,
+		value: BalanceOf<T>,
+	) -> Result<BountyIndex, DispatchError> {
+		ensure!(!Self::new_bounties_paused(), Error::<T, I>::Paused);
+		ensure!(value >= T::BountyValueMinimum::get(), Error::<T, I>::InvalidValue);
+
+		Self::ensure_index_capacity::<BountiesByProposer<T, I>>(&proposer)?;
+		let proposals = Self::open_proposal_count(&proposer);
+		ensure!(proposals < T::MaxProposalsPerAccount::get(), Error::<T, I>::TooManyProposals);
+
+		let index = Self::bounty_count();
+
+		// reserve deposit for new bounty
+		let bond = T::BountyDepositBase::get()
+			+ T::DataDepositPerByte::get() * data_len.into();
+		T::Currency::reserve(&proposer, bond)
+			.map_err(|_| Error::<T, I>::InsufficientProposersBalance)?;
+
+		BountyCount::<I>::put(index + 1);
+		OpenProposalCounts::<T, I>::insert(&proposer, proposals + 1);
+
+		let bounty = Bounty {
+			proposer,
+			finance: BountyFinance { value, bond, ..Default::default() },
+			status: BountyStatus::Proposed,
+		};
+
+		Bounties::<T, I>::insert(index, &bounty);
+		BountyProposalTerms::<T, I>::insert(index, BountyTerms {
+			payout_delay: T::BountyDepositPayoutDelay::get(),
+			rejection_slash: T::BountyRejectionSlash::get(),
+		});
+		Self::index_bounty::<BountiesByProposer<T, I>>(&bounty.proposer, index);
+
+		Self::deposit_event(RawEvent::BountyProposed(index));
+
+		Ok(index)
+	}
+
+	/// Fund the approved bounties of `funding_source` out of `budget_remaining`, and return the
+	/// weight consumed.
+	///
+	/// At most `MaxApprovalsPerBlock` approvals are considered, in order of approval; the rest
+	/// are carried over to the next spend period. Of those, bounties of higher priority are funded
+	/// first, then smaller ones before larger ones, then in order of approval. A bounty that does
+	/// not fit in the remaining budget is skipped, so that smaller bounties after it may still be
+	/// funded.
+	fn spend_funds_from(
+		funding_source: T::FundingSource,
+		budget_remaining: &mut BalanceOf<T>,
+		imbalance: &mut PositiveImbalanceOf<T>,
+		missed_any: &mut bool
+	) -> Weight {
+		BountyApprovals::<I>::mutate(|v| {
+			let mut considered = 0u32;
+			let mut other_sources = 0u64;
+			let mut paused = 0u64;
+
+			// The approvals that are done with, whether funded or gone.
+			let mut done = Vec::new();
+			let mut bounties = Vec::new();
+			for (position, &index) in v.iter().enumerate() {
+				// bounties of other funding sources are left to the `SpendFunds` of their pot.
+				if Self::bounty_funding_source(index) != funding_source {
+					other_sources += 1;
+					continue
+				}
+				// paused bounties wait for a spend period after they are unpaused.
+				if Self::bounty_paused(index) {
+					paused += 1;
+					continue
+				}
+				if considered == T::MaxApprovalsPerBlock::get() {
+					// leave the rest for the next spend period, and the funds for them unburnt.
+					*missed_any = true;
+					break
+				}
+				considered += 1;
+				// Should always be `Some`, but shouldn't panic if not or we're screwed.
+				match Self::bounties(index) {
+					Some(bounty) => {
+						let priority = Reverse(Self::bounty_priority(index));
+						let increase = Self::bounty_value_increase(index);
+						let amount = increase.unwrap_or(bounty.finance.value);
+						bounties.push((priority, amount, position, index, bounty, increase.is_some()));
+					},
+					None => done.push(index),
+				}
+			}
+			bounties.sort_by_key(|(priority, amount, position, _, _, _)| (*priority, *amount, *position));
+
+			let mut funded = 0u32;
+			for (_, amount, _, index, mut bounty, increase) in bounties {
+				if amount <= *budget_remaining {
+					*budget_remaining -= amount;
+
+					if increase {
+						// the bounty is funded already, only the increase of its value is not.
+						imbalance.subsume(T::Currency::deposit_creating(&Self::bounty_account_id(index), amount));
+						BountyValueIncreases::<T, I>::remove(index);
+						done.push(index);
+						Self::deposit_event(RawEvent::BountyValueIncreaseFunded(index, amount));
+						continue
+					}
+
+					// a curator proposed before funding has to be proposed again.
+					if let Some(curator) = bounty.status.curator() {
+						Self::unindex_bounty::<BountiesByCurator<T, I>>(curator, index);
+					}
+					bounty.status.transition(BountyStatus::Funded);
+
+					// return their deposit.
+					let _ = T::Currency::unreserve(&bounty.proposer, bounty.finance.bond);
+
+					// fund the bounty account
+					imbalance.subsume(T::Currency::deposit_creating(&Self::bounty_account_id(index), bounty.finance.value));
+
+					T::ProposerNotifier::on_funded(&bounty.proposer, index);
+					Bounties::<T, I>::insert(index, bounty);
+					BountyPriorities::<I>::remove(index);
+					done.push(index);
+					funded += 1;
+					Self::deposit_event(RawEvent::BountyBecameActive(index));
+				} else {
+					*missed_any = true;
+					Self::deposit_event(RawEvent::BountyFundingSkipped(index));
+				}
+			}
+
+			done.sort_unstable();
+			v.retain(|index| done.binary_search(index).is_err());
+			if funded > 0 {
+				TotalBountiesFunded::<I>::mutate(|total| *total = total.saturating_add(funded));
+				ActiveBountyCount::<I>::mutate(|count| *count = count.saturating_add(funded));
+			}
+
+			<T as Config<I>>::WeightInfo::spend_funds(considered)
+				.saturating_add(T::DbWeight::get().reads(other_sources.saturating_add(paused)))
+		})
+	}
+}
+
+impl<T: Config<I>, I: Instance> pallet_tips::TipFundingSource<T::AccountId, T::Hash, BalanceOf<T>> for Module<T, I> {
+	fn co_funding(reason: &T::Hash) -> Option<(T::AccountId, BalanceOf<T>)> {
+		let (bounty_id, max_per_tip) = Self::tip_co_funding(reason)?;
+		if Self::bounty_paused(bounty_id) {
+			return None
+		}
+		match Self::bounties(bounty_id)?.status {
+			BountyStatus::Active { .. } => {
+				let bounty_account = Self::bounty_account_id(bounty_id);
+				// contributions are kept for the bounty itself.
+				let available = T::Currency::free_balance(&bounty_account)
+					.saturating_sub(Self::bounty_contribution_total(bounty_id));
+				Some((bounty_account, max_per_tip.min(available)))
+			},
+			_ => None,
+		}
+	}
+}
+
+impl<T: Config<I>, I: Instance> pallet_treasury::SpendFunds<T> for Module<T, I> {
+	fn spend_funds(
+		budget_remaining: &mut BalanceOf<T>,
+		imbalance: &mut PositiveImbalanceOf<T>,
+		total_weight: &mut Weight,
+		missed_any: &mut bool
+	) {
+		*total_weight += Self::spend_funds_from(
+			T::FundingSource::default(),
+			budget_remaining,
+			imbalance,
+			missed_any,
+		);
+	}
+}
+
+/// Funds the approved bounties of the funding source `Source` of the bounties instance `I` from
+/// the treasury instance `TI`.
+///
+/// Use it as the `SpendFunds` of each treasury instance, other than the one the bounties instance
+/// is coupled to, that its bounties may be funded from. The treasury instance must share the
+/// currency of that treasury.
+pub struct SpendFundsFrom<T, TI, Source, I = DefaultInstance>(PhantomData<(T, TI, Source, I)>);
+
+impl<T, TI, Source, I> pallet_treasury::SpendFunds<T, TI> for SpendFundsFrom<T, TI, Source, I> where
+	T: Config<I> + pallet_treasury::Config<TI, Currency = <T as pallet_treasury::Config>::Currency>,
+	I: Instance,
+	Source: Get<<T as Config<I>>::FundingSource>,
+{
+	fn spend_funds(
+		budget_remaining: &mut BalanceOf<T>,
+		imbalance: &mut PositiveImbalanceOf<T>,
+		total_weight: &mut Weight,
+		missed_any: &mut bool
+	) {
+		*total_weight += Module::<T, I>::spend_funds_from(
+			Source::get(),
+			budget_remaining,
+			imbalance,
+			missed_any,
+		);
+	}
+}
+
+
+/// Refunds the weight fee of the curator maintenance calls of a bounty, i.e.
+/// `extend_bounty_expiry` and `award_bounty`, when they are signed by the curator of the bounty
+/// and succeed. Curators already have their deposit locked up while they look after a bounty.
+///
+/// Wraps the signed extension `S` that charges the fee, usually `ChargeTransactionPayment`, and
+/// encodes just like it. The fee is still withdrawn up front, so that failing calls are paid for,
+/// and `S` is told after dispatch that the call used no weight. The base and length fees are
+/// still paid, so that a long remark is not free to include, and any tip is kept.
+#[derive(Encode, Decode, Clone, Eq, PartialEq)]
+pub struct CuratorFeeExemption<T: Config<I> + Send + Sync, S, I: Instance = DefaultInstance>(pub S, PhantomData<(T, I)>);
+
+impl<T: Config<I> + Send + Sync, S, I: Instance> From<S> for CuratorFeeExemption<T, S, I> {
+	fn from(extension: S) -> Self {
+		Self(extension, PhantomData)
+	}
+}
+
+impl<T: Config<I> + Send + Sync, S: sp_std::fmt::Debug, I: Instance> sp_std::fmt::Debug for CuratorFeeExemption<T, S, I> {
+	fn fmt(&self, f: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		write!(f, "CuratorFeeExemption<{:?}>", self.0)
+	}
+}
+
+impl<T: Config<I> + Send + Sync, S, I: Instance> CuratorFeeExemption<T, S, I> where
+	<T as frame_system::Config>::Call: IsSubType<Call<T, I>>,
+{
+	/// Whether `call` is a maintenance call of a bounty that `who` is the active curator of.
+	fn is_curator_call(who: &T::AccountId, call: &<T as frame_system::Config>::Call) -> bool {
+		let bounty_id = match call.is_sub_type() {
+			Some(Call::extend_bounty_expiry(bounty_id, _)) | Some(Call::award_bounty(bounty_id, _, _)) => *bounty_id,
+			_ => return false,
+		};
+		matches!(
+			Module::<T, I>::bounties(bounty_id).map(|bounty| bounty.status),
+			Some(BountyStatus::Active { curator, .. }) if curator == *who
+		)
+	}
+}
+
+impl<T: Config<I> + Send + Sync, S, I: Instance + Clone + Eq + Send + Sync> SignedExtension for CuratorFeeExemption<T, S, I> where
+	S: SignedExtension<AccountId = T::AccountId, Call = <T as frame_system::Config>::Call>,
+	<T as frame_system::Config>::Call: IsSubType<Call<T, I>> + Dispatchable<PostInfo = PostDispatchInfo>,
+{
+	const IDENTIFIER: &'static str = S::IDENTIFIER;
+	type AccountId = T::AccountId;
+	type Call = <T as frame_system::Config>::Call;
+	type AdditionalSigned = S::AdditionalSigned;
+	/// The `Pre` of `S`, and whether the fee is refunded.
+	type Pre = (S::Pre, bool);
+
+	fn additional_signed(&self) -> Result<Self::AdditionalSigned, TransactionValidityError> {
+		self.0.additional_signed()
+	}
+
+	fn validate(
+		&self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		info: &DispatchInfoOf<Self::Call>,
+		len: usize,
+	) -> TransactionValidity {
+		self.0.validate(who, call, info, len)
+	}
+
+	fn pre_dispatch(
+		self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		info: &DispatchInfoOf<Self::Call>,
+		len: usize,
+	) -> Result<Self::Pre, TransactionValidityError> {
+		// decided before dispatch, as `award_bounty` moves the bounty on.
+		let exempt = Self::is_curator_call(who, call);
+		Ok((self.0.pre_dispatch(who, call, info, len)?, exempt))
+	}
+
+	fn post_dispatch(
+		pre: Self::Pre,
+		info: &DispatchInfoOf<Self::Call>,
+		post_info: &PostDispatchInfoOf<Self::Call>,
+		len: usize,
+		result: &DispatchResult,
+	) -> Result<(), TransactionValidityError> {
+		let (pre, exempt) = pre;
+		let mut post_info = *post_info;
+		if exempt && result.is_ok() {
+			// only `S` is told: the block still accounts for the weight used.
+			post_info.actual_weight = Some(0);
+		}
+		S::post_dispatch(pre, info, &post_info, len, result)
 	}
 }
@@ -24,14 +24,14 @@ use super::*;
 use std::cell::RefCell;
 
 use frame_support::{
-	assert_noop, assert_ok, parameter_types, weights::Weight, traits::OnInitialize
+	assert_noop, assert_ok, parameter_types, weights::{Weight, GetDispatchInfo, Pays}, traits::{OnInitialize, OnRuntimeUpgrade}
 };
 
 use sp_core::H256;
 use sp_runtime::{
 	Perbill, ModuleId,
 	testing::Header,
-	traits::{BlakeTwo256, IdentityLookup, BadOrigin},
+	traits::{BlakeTwo256, IdentityLookup, BadOrigin, Hash as HashT},
 };
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
@@ -46,7 +46,9 @@ frame_support::construct_runtime!(
 		System: frame_system::{Module, Call, Config, Storage, Event<T>},
 		Balances: pallet_balances::{Module, Call, Storage, Config<T>, Event<T>},
 		Bounties: pallet_bounties::{Module, Call, Storage, Event<T>},
+		Bounties1: pallet_bounties::<Instance1>::{Module, Call, Storage, Event<T>},
 		Treasury: pallet_treasury::{Module, Call, Storage, Config, Event<T>},
+		Scheduler: pallet_scheduler::{Module, Call, Storage, Event<T>},
 	}
 );
 
@@ -118,25 +120,254 @@ impl pallet_treasury::Config for Test {
 	type Burn = Burn;
 	type BurnDestination = ();  // Just gets burned.
 	type WeightInfo = ();
-	type SpendFunds = Bounties;
+	type SpendFunds = (Bounties, Bounties1);
 }
 parameter_types! {
 	pub const BountyDepositBase: u64 = 80;
-	pub const BountyDepositPayoutDelay: u64 = 3;
 	pub const BountyUpdatePeriod: u32 = 20;
+	pub const ClaimTimeout: u64 = 10;
 	pub const BountyCuratorDeposit: Permill = Permill::from_percent(50);
 	pub const BountyValueMinimum: u64 = 1;
 	pub const MaximumReasonLength: u32 = 16384;
+	pub const MaxTags: u32 = 3;
+	pub const MaxTagLen: u32 = 8;
+	pub const MaxTipReasons: u32 = 2;
+	pub static MaxApprovals: u32 = 100;
+	pub static MaxApprovalsPerBlock: u32 = 100;
+	pub static MaxBountiesPerAccount: u32 = 100;
+	pub static MaxProposalsPerAccount: u32 = 100;
+	pub static MaxTotalActiveBountyValue: u64 = u64::MAX;
 }
+parameter_types! {
+	pub static CuratorDepositMin: Option<u64> = Some(1);
+	pub static CuratorDepositMax: Option<u64> = Some(1000);
+	pub static BountyRejectionSlash: Permill = Permill::one();
+	pub static BountyDepositPayoutDelay: u64 = 3;
+	pub static RequireSelfAwardApproval: bool = false;
+	pub static MaintenanceTip: u64 = 0;
+}
+parameter_types! {
+	pub const AppealPeriod: u64 = 5;
+	pub const MaxWorkSubmissions: u32 = 2;
+	pub const WorkSubmissionDeposit: u64 = 1;
+	pub const MaxCuratorApplicants: u32 = 2;
+	pub const CuratorApplicationDeposit: u64 = 1;
+	pub const DisputeDeposit: u64 = 5;
+	pub const BountyAccountTag: &'static str = "bt";
+	pub const DescriptionPurgeReward: u64 = 2;
+	pub const MaxCuratorSponsors: u32 = 2;
+}
+pub struct TestIdentity;
+impl IdentityProvider<u128, H256> for TestIdentity {
+	fn identity_of(who: &u128) -> Option<H256> {
+		// Only the usual curator has an identity.
+		if *who == 4 { Some(H256::repeat_byte(4)) } else { None }
+	}
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub enum ProposerNews {
+	Approved,
+	Rejected,
+	Funded,
+}
+parameter_types! {
+	pub static Notified: Vec<(u128, BountyIndex, ProposerNews)> = vec![];
+}
+pub struct TestNotifier;
+impl TestNotifier {
+	fn notify(proposer: &u128, bounty_id: BountyIndex, news: ProposerNews) {
+		let mut notified = Notified::get();
+		notified.push((*proposer, bounty_id, news));
+		Notified::set(notified);
+	}
+}
+impl ProposerNotifier<u128> for TestNotifier {
+	fn on_approved(proposer: &u128, bounty_id: BountyIndex) {
+		Self::notify(proposer, bounty_id, ProposerNews::Approved);
+	}
+	fn on_rejected(proposer: &u128, bounty_id: BountyIndex) {
+		Self::notify(proposer, bounty_id, ProposerNews::Rejected);
+	}
+	fn on_funded(proposer: &u128, bounty_id: BountyIndex) {
+		Self::notify(proposer, bounty_id, ProposerNews::Funded);
+	}
+}
+
+parameter_types! {
+	pub static UnverifiedCurators: Vec<u128> = vec![];
+}
+pub struct TestCuratorFilter;
+impl Filter<u128> for TestCuratorFilter {
+	fn filter(who: &u128) -> bool {
+		!UnverifiedCurators::get().contains(who)
+	}
+}
+
+/// The chain whose accounts the test paymaster can pay, and the local account it pays them through.
+pub const REMOTE_CHAIN: u32 = 2000;
+pub const REMOTE_CHAIN_ACCOUNT: u128 = 200;
+parameter_types! {
+	pub static RemotePayouts: Vec<((u32, u128), u64)> = vec![];
+}
+pub struct TestPaymaster;
+impl BountyPaymaster<u128, u64> for TestPaymaster {
+	type Beneficiary = (u32, u128);
+
+	fn can_pay(to: &(u32, u128)) -> bool {
+		to.0 == REMOTE_CHAIN
+	}
+
+	fn pay(from: &u128, to: &(u32, u128), amount: u64) -> DispatchResult {
+		Balances::transfer(Origin::signed(*from), REMOTE_CHAIN_ACCOUNT, amount).map_err(|e| e.error)?;
+		let mut payouts = RemotePayouts::get();
+		payouts.push((*to, amount));
+		RemotePayouts::set(payouts);
+		Ok(())
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn benchmark_beneficiary() -> Option<(u32, u128)> {
+		Some((REMOTE_CHAIN, 0))
+	}
+}
+
+/// The account of the second pot bounties can be funded from in tests.
+pub const FUND: u128 = 100;
+
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub enum FundingSource {
+	Treasury,
+	Fund,
+}
+impl Default for FundingSource {
+	fn default() -> Self {
+		FundingSource::Treasury
+	}
+}
+impl BountyFundingSource<Test> for FundingSource {
+	fn account_id(&self) -> u128 {
+		match self {
+			FundingSource::Treasury => Treasury::account_id(),
+			FundingSource::Fund => FUND,
+		}
+	}
+}
+parameter_types! {
+	pub const FundSource: FundingSource = FundingSource::Fund;
+}
+
 impl Config for Test {
 	type Event = Event;
 	type BountyDepositBase = BountyDepositBase;
 	type BountyDepositPayoutDelay = BountyDepositPayoutDelay;
 	type BountyUpdatePeriod = BountyUpdatePeriod;
+	type ClaimTimeout = ClaimTimeout;
+	type BountyCuratorDeposit = BountyCuratorDeposit;
+	type BountyRejectionSlash = BountyRejectionSlash;
+	type AppealPeriod = AppealPeriod;
+	type AppealOrigin = frame_system::EnsureRoot<u128>;
+	type RequireSelfAwardApproval = RequireSelfAwardApproval;
+	type CuratorDepositMin = CuratorDepositMin;
+	type CuratorDepositMax = CuratorDepositMax;
+	type BountyValueMinimum = BountyValueMinimum;
+	type DataDepositPerByte = DataDepositPerByte;
+	type MaximumReasonLength = MaximumReasonLength;
+	type MaxTags = MaxTags;
+	type MaxTagLen = MaxTagLen;
+	type MaxTipReasons = MaxTipReasons;
+	type MaxApprovals = MaxApprovals;
+	type MaxApprovalsPerBlock = MaxApprovalsPerBlock;
+	type MaxBountiesPerAccount = MaxBountiesPerAccount;
+	type MaxProposalsPerAccount = MaxProposalsPerAccount;
+	type MaxTotalActiveBountyValue = MaxTotalActiveBountyValue;
+	type MaxWorkSubmissions = MaxWorkSubmissions;
+	type WorkSubmissionDeposit = WorkSubmissionDeposit;
+	type MaxCuratorApplicants = MaxCuratorApplicants;
+	type CuratorApplicationDeposit = CuratorApplicationDeposit;
+	type FastTrackOrigin = frame_system::EnsureRoot<u128>;
+	type DisputeDeposit = DisputeDeposit;
+	type BountyAccountTag = BountyAccountTag;
+	type DisputeResolutionOrigin = frame_system::EnsureRoot<u128>;
+	type DescriptionPurgeReward = DescriptionPurgeReward;
+	type MaintenanceTip = MaintenanceTip;
+	type IdentityProvider = TestIdentity;
+	type ProposerNotifier = TestNotifier;
+	type CuratorFilter = TestCuratorFilter;
+	type FundingSource = FundingSource;
+	type BountyCall = Call;
+	type Scheduler = Scheduler;
+	type PalletsOrigin = OriginCaller;
+	type Paymaster = TestPaymaster;
+	type VetoOrigin = frame_system::EnsureRoot<u128>;
+	type MaxCuratorSponsors = MaxCuratorSponsors;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub const Bounties1DepositBase: u64 = 10;
+	pub const Bounties1AccountTag: &'static str = "b1";
+}
+// A second bounty track, with its own deposit and bounty accounts.
+impl Config<Instance1> for Test {
+	type Event = Event;
+	type BountyDepositBase = Bounties1DepositBase;
+	type BountyDepositPayoutDelay = BountyDepositPayoutDelay;
+	type BountyUpdatePeriod = BountyUpdatePeriod;
+	type ClaimTimeout = ClaimTimeout;
 	type BountyCuratorDeposit = BountyCuratorDeposit;
+	type BountyRejectionSlash = BountyRejectionSlash;
+	type AppealPeriod = AppealPeriod;
+	type AppealOrigin = frame_system::EnsureRoot<u128>;
+	type RequireSelfAwardApproval = RequireSelfAwardApproval;
+	type CuratorDepositMin = CuratorDepositMin;
+	type CuratorDepositMax = CuratorDepositMax;
 	type BountyValueMinimum = BountyValueMinimum;
 	type DataDepositPerByte = DataDepositPerByte;
 	type MaximumReasonLength = MaximumReasonLength;
+	type MaxTags = MaxTags;
+	type MaxTagLen = MaxTagLen;
+	type MaxTipReasons = MaxTipReasons;
+	type MaxApprovals = MaxApprovals;
+	type MaxApprovalsPerBlock = MaxApprovalsPerBlock;
+	type MaxBountiesPerAccount = MaxBountiesPerAccount;
+	type MaxProposalsPerAccount = MaxProposalsPerAccount;
+	type MaxTotalActiveBountyValue = MaxTotalActiveBountyValue;
+	type MaxWorkSubmissions = MaxWorkSubmissions;
+	type WorkSubmissionDeposit = WorkSubmissionDeposit;
+	type MaxCuratorApplicants = MaxCuratorApplicants;
+	type CuratorApplicationDeposit = CuratorApplicationDeposit;
+	type FastTrackOrigin = frame_system::EnsureRoot<u128>;
+	type DisputeDeposit = DisputeDeposit;
+	type BountyAccountTag = Bounties1AccountTag;
+	type DisputeResolutionOrigin = frame_system::EnsureRoot<u128>;
+	type DescriptionPurgeReward = DescriptionPurgeReward;
+	type MaintenanceTip = MaintenanceTip;
+	type IdentityProvider = ();
+	type ProposerNotifier = ();
+	type CuratorFilter = ();
+	type FundingSource = ();
+	type BountyCall = Call;
+	type Scheduler = Scheduler;
+	type PalletsOrigin = OriginCaller;
+	type Paymaster = ();
+	type VetoOrigin = frame_system::EnsureRoot<u128>;
+	type MaxCuratorSponsors = MaxCuratorSponsors;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub const MaximumSchedulerWeight: Weight = Weight::MAX;
+	pub const MaxScheduledPerBlock: u32 = 50;
+}
+impl pallet_scheduler::Config for Test {
+	type Event = Event;
+	type Origin = Origin;
+	type PalletsOrigin = OriginCaller;
+	type Call = Call;
+	type MaximumWeight = MaximumSchedulerWeight;
+	type ScheduleOrigin = frame_system::EnsureRoot<u128>;
+	type MaxScheduledPerBlock = MaxScheduledPerBlock;
 	type WeightInfo = ();
 }
 
@@ -152,7 +383,7 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
 	t.into()
 }
 
-fn last_event() -> RawEvent<u64, u128> {
+fn last_event() -> RawEvent<u64, u128, H256, u64, (u32, u128), DefaultInstance> {
 	System::events().into_iter().map(|r| r.event)
 		.filter_map(|e| {
 			if let Event::pallet_bounties(inner) = e { Some(inner) } else { None }
@@ -161,6 +392,59 @@ fn last_event() -> RawEvent<u64, u128> {
 		.unwrap()
 }
 
+parameter_types! {
+	pub static ChargedFee: Option<(Pays, Weight, usize)> = None;
+}
+
+/// Stands in for the signed extension that charges the fee, recording what it would charge for.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, Debug)]
+struct RecordChargedFee;
+
+impl SignedExtension for RecordChargedFee {
+	const IDENTIFIER: &'static str = "RecordChargedFee";
+	type AccountId = u128;
+	type Call = Call;
+	type AdditionalSigned = ();
+	type Pre = ();
+
+	fn additional_signed(&self) -> Result<(), TransactionValidityError> { Ok(()) }
+
+	fn post_dispatch(
+		_pre: (),
+		info: &DispatchInfoOf<Call>,
+		post_info: &PostDispatchInfoOf<Call>,
+		len: usize,
+		_result: &DispatchResult,
+	) -> Result<(), TransactionValidityError> {
+		ChargedFee::set(Some((post_info.pays_fee, post_info.calc_actual_weight(info), len)));
+		Ok(())
+	}
+}
+
+/// Apply `call` signed by `who` with `CuratorFeeExemption`, returning the weight it paid for.
+///
+/// Checks that the base and length fees are paid either way.
+fn apply_with_fee_exemption(who: u128, call: pallet_bounties::Call<Test>) -> Weight {
+	let call = Call::Bounties(call);
+	let info = call.get_dispatch_info();
+	let len = call.encode().len();
+	let extension = CuratorFeeExemption::<Test, _>::from(RecordChargedFee);
+	let pre = extension.pre_dispatch(&who, &call, &info, len).unwrap();
+	let result = call.dispatch(Origin::signed(who));
+	let post_info = result.unwrap_or_else(|e| e.post_info);
+	assert_ok!(CuratorFeeExemption::<Test, RecordChargedFee>::post_dispatch(
+		pre,
+		&info,
+		&post_info,
+		len,
+		&result.map(|_| ()).map_err(|e| e.error),
+	));
+	let (pays_fee, weight, charged_len) = ChargedFee::get().unwrap();
+	assert_eq!(pays_fee, Pays::Yes);
+	assert_eq!(charged_len, len);
+	weight
+}
+
 #[test]
 fn genesis_config_works() {
 	new_test_ext().execute_with(|| {
@@ -394,10 +678,12 @@ fn propose_bounty_works() {
 
 		assert_eq!(Bounties::bounties(0).unwrap(), Bounty {
 			proposer: 0,
-			fee: 0,
-			curator_deposit: 0,
-			value: 10,
-			bond: deposit,
+			finance: BountyFinance {
+				value: 10,
+				fee: 0,
+				curator_deposit: 0,
+				bond: deposit,
+			},
 			status: BountyStatus::Proposed,
 		});
 
@@ -417,18 +703,88 @@ fn propose_bounty_validation_works() {
 
 		assert_noop!(
 			Bounties::propose_bounty(Origin::signed(1), 0, [0; 17_000].to_vec()),
-			Error::<Test>::ReasonTooBig
+			Error::<Test, DefaultInstance>::ReasonTooBig
+		);
+
+		assert_noop!(
+			Bounties::propose_bounty(Origin::signed(1), 10, Vec::new()),
+			Error::<Test, DefaultInstance>::ReasonEmpty
 		);
 
 		assert_noop!(
 			Bounties::propose_bounty(Origin::signed(1), 10, b"12345678901234567890".to_vec()),
-			Error::<Test>::InsufficientProposersBalance
+			Error::<Test, DefaultInstance>::InsufficientProposersBalance
 		);
 
 		assert_noop!(
 			Bounties::propose_bounty(Origin::signed(1), 0, b"12345678901234567890".to_vec()),
-			Error::<Test>::InvalidValue
+			Error::<Test, DefaultInstance>::InvalidValue
+		);
+	});
+}
+
+#[test]
+fn propose_bounty_with_hash_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&0, 200);
+
+		let description = b"1234567890".to_vec();
+		let description_hash = BlakeTwo256::hash(&description[..]);
+		assert_ok!(Bounties::propose_bounty_with_hash(Origin::signed(0), 10, description_hash));
+
+		assert_eq!(last_event(), RawEvent::BountyProposed(0));
+
+		// Only the hash is paid for.
+		let deposit: u64 = 80 + 32;
+		assert_eq!(Balances::reserved_balance(0), deposit);
+		assert_eq!(Bounties::bounties(0).unwrap().finance.bond, deposit);
+
+		assert_eq!(Bounties::bounty_description_hash(0), Some(description_hash));
+		assert_eq!(Bounties::bounty_descriptions(0), None);
+	});
+}
+
+#[test]
+fn note_bounty_description_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&0, 200);
+
+		assert_ok!(Bounties::propose_bounty(Origin::signed(0), 10, b"12345".to_vec()));
+		assert_noop!(
+			Bounties::note_bounty_description(Origin::signed(1), 0, b"12345".to_vec()),
+			Error::<Test, DefaultInstance>::NoDescriptionHash
+		);
+
+		let description = b"1234567890".to_vec();
+		let description_hash = BlakeTwo256::hash(&description[..]);
+		assert_ok!(Bounties::propose_bounty_with_hash(Origin::signed(0), 10, description_hash));
+
+		assert_noop!(
+			Bounties::note_bounty_description(Origin::signed(1), 1, b"123456789".to_vec()),
+			Error::<Test, DefaultInstance>::DescriptionHashMismatch
+		);
+
+		assert_ok!(Bounties::note_bounty_description(Origin::signed(1), 1, description.clone()));
+		assert_eq!(last_event(), RawEvent::BountyDescriptionNoted(1, 1));
+
+		assert_eq!(Bounties::bounty_descriptions(1), Some(description.clone()));
+		assert_eq!(Balances::reserved_balance(1), 10);
+
+		assert_noop!(
+			Bounties::note_bounty_description(Origin::signed(1), 1, description),
+			Error::<Test, DefaultInstance>::DescriptionAlreadyNoted
 		);
+
+		// Removing the bounty returns the deposit of the noter.
+		assert_ok!(Bounties::close_bounty(Origin::root(), 1, None));
+
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Bounties::bounty_descriptions(1), None);
+		assert_eq!(Bounties::bounty_description_hash(1), None);
+		assert_eq!(Bounties::bounty_description_deposit(1), None);
 	});
 }
 
@@ -437,15 +793,15 @@ fn close_bounty_works() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
 		Balances::make_free_balance_be(&Treasury::account_id(), 101);
-		assert_noop!(Bounties::close_bounty(Origin::root(), 0), Error::<Test>::InvalidIndex);
+		assert_noop!(Bounties::close_bounty(Origin::root(), 0, None), Error::<Test, DefaultInstance>::InvalidIndex);
 
 		assert_ok!(Bounties::propose_bounty(Origin::signed(0), 10, b"12345".to_vec()));
 
-		assert_ok!(Bounties::close_bounty(Origin::root(), 0));
+		assert_ok!(Bounties::close_bounty(Origin::root(), 0, None));
 
 		let deposit: u64 = 80 + 5;
 
-		assert_eq!(last_event(), RawEvent::BountyRejected(0, deposit));
+		assert_eq!(last_event(), RawEvent::BountyRejected(0, deposit, 0, None));
 
 		assert_eq!(Balances::reserved_balance(0), 0);
 		assert_eq!(Balances::free_balance(0), 100 - deposit);
@@ -457,30 +813,149 @@ fn close_bounty_works() {
 	});
 }
 
+#[test]
+fn bounties_are_settled_under_the_terms_they_were_proposed_with() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&0, 500);
+		Balances::make_free_balance_be(&4, 10);
+
+		assert_ok!(Bounties::propose_bounty(Origin::signed(0), 50, b"12345".to_vec()));
+		assert_ok!(Bounties::propose_bounty(Origin::signed(0), 50, b"12345".to_vec()));
+		assert_eq!(Bounties::bounty_terms(0), Some(BountyTerms {
+			payout_delay: 3,
+			rejection_slash: Permill::one(),
+		}));
+
+		// a runtime upgrade changes the rules.
+		BountyRejectionSlash::set(Permill::zero());
+		BountyDepositPayoutDelay::set(10);
+		assert_ok!(Bounties::propose_bounty(Origin::signed(0), 50, b"12345".to_vec()));
+
+		assert_ok!(Bounties::close_bounty(Origin::root(), 1, None));
+		assert_eq!(last_event(), RawEvent::BountyRejected(1, 85, 0, None));
+		assert_ok!(Bounties::close_bounty(Origin::root(), 2, None));
+		assert_eq!(last_event(), RawEvent::BountyRejected(2, 0, 85, None));
+		assert_eq!(Bounties::bounty_terms(2), None);
+
+		assert_ok!(Bounties::approve_bounty(Origin::root(), 0, FundingSource::Treasury));
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+		assert_ok!(Bounties::propose_curator(Origin::root(), 0, 4, 4));
+		assert_ok!(Bounties::accept_curator(Origin::signed(4), 0, None));
+		assert_ok!(Bounties::award_bounty(Origin::signed(4), 0, 3, None));
+		assert_eq!(Bounties::bounties(0).unwrap().status, BountyStatus::PendingPayout {
+			curator: 4,
+			beneficiary: 3,
+			unlock_at: 5,
+		});
+	});
+}
+
+#[test]
+fn close_bounty_refunds_unslashed_bond() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		BountyRejectionSlash::set(Permill::from_percent(20));
+
+		assert_ok!(Bounties::propose_bounty(Origin::signed(0), 10, b"12345".to_vec()));
+		assert_ok!(Bounties::close_bounty(Origin::root(), 0, None));
+
+		// 20% of the 85 deposit is slashed, the rest is returned.
+		assert_eq!(last_event(), RawEvent::BountyRejected(0, 17, 68, None));
+		assert_eq!(Balances::reserved_balance(0), 0);
+		assert_eq!(Balances::free_balance(0), 100 - 17);
+	});
+}
+
+#[test]
+fn set_bounty_tags_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&0, 300);
+		assert_ok!(Bounties::propose_bounty(Origin::signed(0), 50, b"12345".to_vec()));
+		assert_ok!(Bounties::propose_bounty(Origin::signed(0), 50, b"12345".to_vec()));
+		let bond = 80 + 5;
+
+		assert_noop!(
+			Bounties::set_bounty_tags(Origin::signed(1), 0, vec![b"docs".to_vec()]),
+			BadOrigin
+		);
+		assert_noop!(
+			Bounties::set_bounty_tags(Origin::signed(0), 0, vec![b"a".to_vec(); 4]),
+			Error::<Test, DefaultInstance>::TooManyTags
+		);
+		assert_noop!(
+			Bounties::set_bounty_tags(Origin::signed(0), 0, vec![b"documents".to_vec()]),
+			Error::<Test, DefaultInstance>::TagTooLong
+		);
+
+		assert_ok!(Bounties::set_bounty_tags(Origin::signed(0), 0, vec![b"infra".to_vec(), b"docs".to_vec()]));
+		assert_ok!(Bounties::set_bounty_tags(Origin::signed(0), 1, vec![b"docs".to_vec()]));
+		assert_eq!(last_event(), RawEvent::BountyTagsSet(1));
+		assert_eq!(Bounties::bounty_tags(0), vec![b"infra".to_vec(), b"docs".to_vec()]);
+		assert_eq!(Bounties::bounties(0).unwrap().finance.bond, bond + 9);
+		assert_eq!(Balances::reserved_balance(0), 2 * bond + 9 + 4);
+
+		let with_tag = |tag: &[u8]| Bounties::bounties_with_tag(tag).into_iter()
+			.map(|(index, _)| index)
+			.collect::<Vec<_>>();
+		assert_eq!(with_tag(b"docs"), vec![0, 1]);
+		assert_eq!(with_tag(b"infra"), vec![0]);
+
+		// Replacing the tags adjusts the deposit.
+		assert_ok!(Bounties::set_bounty_tags(Origin::signed(0), 0, vec![b"design".to_vec()]));
+		assert_eq!(Bounties::bounties(0).unwrap().finance.bond, bond + 6);
+		assert_eq!(Balances::reserved_balance(0), 2 * bond + 6 + 4);
+		assert_eq!(with_tag(b"docs"), vec![1]);
+
+		assert_ok!(Bounties::set_bounty_tags(Origin::signed(0), 1, vec![]));
+		assert!(!BountyTags::<DefaultInstance>::contains_key(1));
+		assert_eq!(Balances::reserved_balance(0), 2 * bond + 6);
+
+		// The tags deposit is returned along with the bond, and the tags kept while the bounty is.
+		assert_ok!(Bounties::approve_bounty(Origin::root(), 0, FundingSource::Treasury));
+		assert_noop!(
+			Bounties::set_bounty_tags(Origin::signed(0), 0, vec![]),
+			Error::<Test, DefaultInstance>::UnexpectedStatus
+		);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+		assert_eq!(Balances::reserved_balance(0), bond);
+		assert_eq!(with_tag(b"design"), vec![0]);
+
+		assert_ok!(Bounties::close_bounty(Origin::root(), 0, None));
+		assert!(!BountyTags::<DefaultInstance>::contains_key(0));
+	});
+}
+
 #[test]
 fn approve_bounty_works() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
 		Balances::make_free_balance_be(&Treasury::account_id(), 101);
-		assert_noop!(Bounties::approve_bounty(Origin::root(), 0), Error::<Test>::InvalidIndex);
+		assert_noop!(Bounties::approve_bounty(Origin::root(), 0, FundingSource::Treasury), Error::<Test, DefaultInstance>::InvalidIndex);
 
 		assert_ok!(Bounties::propose_bounty(Origin::signed(0), 50, b"12345".to_vec()));
 
-		assert_ok!(Bounties::approve_bounty(Origin::root(), 0));
+		assert_ok!(Bounties::approve_bounty(Origin::root(), 0, FundingSource::Treasury));
 
 		let deposit: u64 = 80 + 5;
 
 		assert_eq!(Bounties::bounties(0).unwrap(), Bounty {
 			proposer: 0,
-			fee: 0,
-			value: 50,
-			curator_deposit: 0,
-			bond: deposit,
+			finance: BountyFinance {
+				value: 50,
+				fee: 0,
+				curator_deposit: 0,
+				bond: deposit,
+			},
 			status: BountyStatus::Approved,
 		});
 		assert_eq!(Bounties::bounty_approvals(), vec![0]);
 
-		assert_noop!(Bounties::close_bounty(Origin::root(), 0), Error::<Test>::UnexpectedStatus);
+		assert_noop!(Bounties::close_bounty(Origin::root(), 0, None), Error::<Test, DefaultInstance>::UnexpectedStatus);
 
 		// deposit not returned yet
 		assert_eq!(Balances::reserved_balance(0), deposit);
@@ -494,10 +969,12 @@ fn approve_bounty_works() {
 
 		assert_eq!(Bounties::bounties(0).unwrap(), Bounty {
 			proposer: 0,
-			fee: 0,
-			curator_deposit: 0,
-			value: 50,
-			bond: deposit,
+			finance: BountyFinance {
+				value: 50,
+				fee: 0,
+				curator_deposit: 0,
+				bond: deposit,
+			},
 			status: BountyStatus::Funded,
 		});
 
@@ -507,394 +984,2736 @@ fn approve_bounty_works() {
 }
 
 #[test]
-fn assign_curator_works() {
+fn approve_bounty_records_description_hash() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
 		Balances::make_free_balance_be(&Treasury::account_id(), 101);
-
-		assert_noop!(Bounties::propose_curator(Origin::root(), 0, 4, 4), Error::<Test>::InvalidIndex);
+		Balances::make_free_balance_be(&0, 300);
 
 		assert_ok!(Bounties::propose_bounty(Origin::signed(0), 50, b"12345".to_vec()));
+		assert_ok!(Bounties::approve_bounty(Origin::root(), 0, FundingSource::Treasury));
 
-		assert_ok!(Bounties::approve_bounty(Origin::root(), 0));
-
-		System::set_block_number(2);
-		<Treasury as OnInitialize<u64>>::on_initialize(2);
+		let description_hash = BlakeTwo256::hash(b"12345");
+		assert_eq!(last_event(), RawEvent::BountyApproved(0, description_hash));
+		assert_eq!(Bounties::bounty_approved_description_hash(0), Some(description_hash));
 
-		assert_noop!(Bounties::propose_curator(Origin::root(), 0, 4, 50), Error::<Test>::InvalidFee);
-
-		assert_ok!(Bounties::propose_curator(Origin::root(), 0, 4, 4));
+		// A bounty proposed by hash is approved with that hash, even without a preimage.
+		let description_hash = BlakeTwo256::hash(b"1234567890");
+		assert_ok!(Bounties::propose_bounty_with_hash(Origin::signed(0), 50, description_hash));
+		assert_ok!(Bounties::approve_bounty(Origin::root(), 1, FundingSource::Treasury));
 
-		assert_eq!(Bounties::bounties(0).unwrap(), Bounty {
-			proposer: 0,
-			fee: 4,
-			curator_deposit: 0,
-			value: 50,
-			bond: 85,
-			status: BountyStatus::CuratorProposed {
-				curator: 4,
-			},
-		});
+		assert_eq!(last_event(), RawEvent::BountyApproved(1, description_hash));
+		assert_eq!(Bounties::bounty_approved_description_hash(1), Some(description_hash));
+	});
+}
 
-		assert_noop!(Bounties::accept_curator(Origin::signed(1), 0), Error::<Test>::RequireCurator);
-		assert_noop!(Bounties::accept_curator(Origin::signed(4), 0), pallet_balances::Error::<Test, _>::InsufficientBalance);
+#[test]
+fn proposer_is_notified_of_approval_rejection_and_funding() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&0, 300);
+		Notified::set(vec![]);
 
-		Balances::make_free_balance_be(&4, 10);
+		assert_ok!(Bounties::propose_bounty(Origin::signed(0), 50, b"12345".to_vec()));
+		assert_ok!(Bounties::propose_bounty(Origin::signed(1), 50, b"12345".to_vec()));
+		assert!(Notified::get().is_empty());
 
-		assert_ok!(Bounties::accept_curator(Origin::signed(4), 0));
+		assert_ok!(Bounties::approve_bounty(Origin::root(), 0, FundingSource::Treasury));
+		assert_ok!(Bounties::close_bounty(Origin::root(), 1, None));
+		assert_eq!(Notified::get(), vec![
+			(0, 0, ProposerNews::Approved),
+			(1, 1, ProposerNews::Rejected),
+		]);
 
-		assert_eq!(Bounties::bounties(0).unwrap(), Bounty {
-			proposer: 0,
-			fee: 4,
-			curator_deposit: 2,
-			value: 50,
-			bond: 85,
-			status: BountyStatus::Active {
-				curator: 4,
-				update_due: 22,
-			},
-		});
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+		assert_eq!(Notified::get().last(), Some(&(0, 0, ProposerNews::Funded)));
 
-		assert_eq!(Balances::free_balance(&4), 8);
-		assert_eq!(Balances::reserved_balance(&4), 2);
+		// Bounties of an instance without a notifier don't notify anyone.
+		assert_ok!(Bounties1::propose_bounty(Origin::signed(0), 50, b"12345".to_vec()));
+		assert_ok!(Bounties1::approve_bounty(Origin::root(), 0, ()));
+		assert_eq!(Notified::get().len(), 3);
 	});
 }
 
 #[test]
-fn unassign_curator_works() {
+fn approve_bounty_respects_max_approvals() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
 		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&0, 300);
+		MaxApprovals::set(1);
+
+		assert_ok!(Bounties::propose_bounty(Origin::signed(0), 50, b"12345".to_vec()));
 		assert_ok!(Bounties::propose_bounty(Origin::signed(0), 50, b"12345".to_vec()));
 
-		assert_ok!(Bounties::approve_bounty(Origin::root(), 0));
+		assert_ok!(Bounties::approve_bounty(Origin::root(), 0, FundingSource::Treasury));
+		assert_noop!(Bounties::approve_bounty(Origin::root(), 1, FundingSource::Treasury), Error::<Test, DefaultInstance>::TooManyApprovals);
 
-		System::set_block_number(2);
+		// Funding the approved bounty frees up a slot.
 		<Treasury as OnInitialize<u64>>::on_initialize(2);
+		assert_ok!(Bounties::approve_bounty(Origin::root(), 1, FundingSource::Treasury));
+		assert_eq!(Bounties::bounty_approvals(), vec![1]);
+	});
+}
 
-		assert_ok!(Bounties::propose_curator(Origin::root(), 0, 4, 4));
-
-		assert_noop!(Bounties::unassign_curator(Origin::signed(1), 0), BadOrigin);
-
-		assert_ok!(Bounties::unassign_curator(Origin::signed(4), 0));
-
-		assert_eq!(Bounties::bounties(0).unwrap(), Bounty {
-			proposer: 0,
-			fee: 4,
-			curator_deposit: 0,
-			value: 50,
-			bond: 85,
-			status: BountyStatus::Funded,
-		});
+#[test]
+fn approve_bounties_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&0, 500);
+		MaxApprovals::set(3);
 
-		assert_ok!(Bounties::propose_curator(Origin::root(), 0, 4, 4));
+		for _ in 0 .. 4 {
+			assert_ok!(Bounties::propose_bounty(Origin::signed(0), 10, b"12345".to_vec()));
+		}
 
-		Balances::make_free_balance_be(&4, 10);
+		assert_noop!(Bounties::approve_bounties(Origin::signed(0), vec![0], FundingSource::Treasury), BadOrigin);
+		assert_noop!(
+			Bounties::approve_bounties(Origin::root(), vec![2, 0, 2], FundingSource::Treasury),
+			Error::<Test, DefaultInstance>::DuplicateIndex
+		);
+		assert_noop!(
+			Bounties::approve_bounties(Origin::root(), vec![2, 4], FundingSource::Treasury),
+			Error::<Test, DefaultInstance>::InvalidIndex
+		);
+		assert_noop!(
+			Bounties::approve_bounties(Origin::root(), vec![0, 1, 2, 3], FundingSource::Treasury),
+			Error::<Test, DefaultInstance>::TooManyApprovals
+		);
 
-		assert_ok!(Bounties::accept_curator(Origin::signed(4), 0));
+		assert_ok!(Bounties::approve_bounties(Origin::root(), vec![2, 0], FundingSource::Treasury));
+		assert_eq!(Bounties::bounty_approvals(), vec![2, 0]);
+		assert_eq!(Bounties::bounties(0).unwrap().status, BountyStatus::Approved);
+		assert_eq!(Bounties::bounties(1).unwrap().status, BountyStatus::Proposed);
+		assert_eq!(Bounties::bounties(2).unwrap().status, BountyStatus::Approved);
+		assert_eq!(last_event(), RawEvent::BountyApproved(0, BlakeTwo256::hash(b"12345")));
 
-		assert_ok!(Bounties::unassign_curator(Origin::root(), 0));
+		// Nothing is approved if any bounty cannot be.
+		assert_noop!(
+			Bounties::approve_bounties(Origin::root(), vec![1, 2], FundingSource::Treasury),
+			Error::<Test, DefaultInstance>::UnexpectedStatus
+		);
+		assert_noop!(
+			Bounties::approve_bounties(Origin::root(), vec![1, 3], FundingSource::Treasury),
+			Error::<Test, DefaultInstance>::TooManyApprovals
+		);
 
-		assert_eq!(Bounties::bounties(0).unwrap(), Bounty {
-			proposer: 0,
-			fee: 4,
-			curator_deposit: 0,
-			value: 50,
-			bond: 85,
-			status: BountyStatus::Funded,
-		});
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+		assert_eq!(Bounties::bounties(2).unwrap().status, BountyStatus::Funded);
 
-		assert_eq!(Balances::free_balance(&4), 8);
-		assert_eq!(Balances::reserved_balance(&4), 0); // slashed 2
+		assert_ok!(Bounties::approve_bounties(Origin::root(), vec![3, 1], FundingSource::Treasury));
+		assert_eq!(Bounties::bounty_approvals(), vec![3, 1]);
 	});
 }
 
-
 #[test]
-fn award_and_claim_bounty_works() {
+fn total_active_bounty_value_is_capped() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
 		Balances::make_free_balance_be(&Treasury::account_id(), 101);
-		Balances::make_free_balance_be(&4, 10);
-		assert_ok!(Bounties::propose_bounty(Origin::signed(0), 50, b"12345".to_vec()));
+		Balances::make_free_balance_be(&0, 500);
+		MaxTotalActiveBountyValue::set(25);
 
-		assert_ok!(Bounties::approve_bounty(Origin::root(), 0));
+		for _ in 0 .. 3 {
+			assert_ok!(Bounties::propose_bounty(Origin::signed(0), 10, b"12345".to_vec()));
+		}
 
-		System::set_block_number(2);
+		assert_ok!(Bounties::approve_bounty(Origin::root(), 0, FundingSource::Treasury));
+		assert_eq!(Bounties::total_active_bounty_value(), 10);
+		assert_noop!(
+			Bounties::approve_bounties(Origin::root(), vec![1, 2], FundingSource::Treasury),
+			Error::<Test, DefaultInstance>::TooMuchActiveBountyValue
+		);
+		assert_ok!(Bounties::approve_bounties(Origin::root(), vec![1], FundingSource::Treasury));
+		assert_eq!(Bounties::total_active_bounty_value(), 20);
+		assert_noop!(
+			Bounties::approve_bounty(Origin::root(), 2, FundingSource::Treasury),
+			Error::<Test, DefaultInstance>::TooMuchActiveBountyValue
+		);
+
+		// closing a bounty makes room for another.
 		<Treasury as OnInitialize<u64>>::on_initialize(2);
+		assert_ok!(Bounties::close_bounty(Origin::root(), 0, None));
+		assert_eq!(Bounties::total_active_bounty_value(), 10);
+		assert_ok!(Bounties::approve_bounty(Origin::root(), 2, FundingSource::Treasury));
+		assert_eq!(Bounties::total_active_bounty_value(), 20);
+	});
+}
 
-		assert_ok!(Bounties::propose_curator(Origin::root(), 0, 4, 4));
-		assert_ok!(Bounties::accept_curator(Origin::signed(4), 0));
+#[test]
+fn approved_bounties_are_funded_by_priority() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&0, 1000);
 
-		assert_eq!(Balances::free_balance(4), 8); // inital 10 - 2 deposit
+		assert_ok!(Bounties::propose_bounty(Origin::signed(0), 60, b"12345".to_vec()));
+		assert_ok!(Bounties::propose_bounty(Origin::signed(0), 30, b"12345".to_vec()));
+		assert_ok!(Bounties::propose_bounty(Origin::signed(0), 30, b"12345".to_vec()));
+		assert_ok!(Bounties::propose_bounty(Origin::signed(0), 20, b"12345".to_vec()));
 
-		assert_noop!(Bounties::award_bounty(Origin::signed(1), 0, 3), Error::<Test>::RequireCurator);
+		assert_noop!(Bounties::set_bounty_priority(Origin::root(), 2, 1), Error::<Test, DefaultInstance>::UnexpectedStatus);
+		assert_ok!(Bounties::approve_bounties(Origin::root(), vec![0, 1, 2], FundingSource::Treasury));
+		assert_noop!(Bounties::set_bounty_priority(Origin::signed(0), 2, 1), BadOrigin);
+		assert_ok!(Bounties::set_bounty_priority(Origin::root(), 2, 1));
+		assert_eq!(last_event(), RawEvent::BountyPrioritySet(2, 1));
 
-		assert_ok!(Bounties::award_bounty(Origin::signed(4), 0, 3));
+		// The budget of 100 funds the prioritised bounty, then the smaller of the others.
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+		assert_eq!(Bounties::bounties(0).unwrap().status, BountyStatus::Approved);
+		assert_eq!(Bounties::bounties(1).unwrap().status, BountyStatus::Funded);
+		assert_eq!(Bounties::bounties(2).unwrap().status, BountyStatus::Funded);
+		assert!(System::events().iter().any(|r| r.event == Event::pallet_bounties(RawEvent::BountyFundingSkipped(0))));
+		assert_eq!(Bounties::bounty_approvals(), vec![0]);
+		assert!(!BountyPriorities::<DefaultInstance>::contains_key(2));
 
-		assert_eq!(Bounties::bounties(0).unwrap(), Bounty {
-			proposer: 0,
-			fee: 4,
-			curator_deposit: 2,
-			value: 50,
-			bond: 85,
-			status: BountyStatus::PendingPayout {
-				curator: 4,
-				beneficiary: 3,
-				unlock_at: 5
-			},
-		});
-
-		assert_noop!(Bounties::claim_bounty(Origin::signed(1), 0), Error::<Test>::Premature);
+		// Of equal priority, the smaller bounty is funded first, however late it was approved.
+		Balances::make_free_balance_be(&Treasury::account_id(), 61);
+		assert_ok!(Bounties::approve_bounty(Origin::root(), 3, FundingSource::Treasury));
+		<Treasury as OnInitialize<u64>>::on_initialize(4);
+		assert_eq!(Bounties::bounties(3).unwrap().status, BountyStatus::Funded);
+		assert_eq!(Bounties::bounty_approvals(), vec![0]);
+		assert_eq!(last_event(), RawEvent::BountyFundingSkipped(0));
+	});
+}
 
-		System::set_block_number(5);
-		<Treasury as OnInitialize<u64>>::on_initialize(5);
+#[test]
+fn approvals_beyond_max_approvals_per_block_are_carried_over() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&0, 1000);
+		MaxApprovalsPerBlock::set(2);
 
-		assert_ok!(Balances::transfer(Origin::signed(0), Bounties::bounty_account_id(0), 10));
+		for _ in 0 .. 3 {
+			assert_ok!(Bounties::propose_bounty(Origin::signed(0), 10, b"12345".to_vec()));
+		}
+		assert_ok!(Bounties::approve_bounties(Origin::root(), vec![0, 1, 2], FundingSource::Treasury));
 
-		assert_ok!(Bounties::claim_bounty(Origin::signed(1), 0));
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+		assert_eq!(Bounties::bounties(0).unwrap().status, BountyStatus::Funded);
+		assert_eq!(Bounties::bounties(1).unwrap().status, BountyStatus::Funded);
+		assert_eq!(Bounties::bounties(2).unwrap().status, BountyStatus::Approved);
+		assert_eq!(Bounties::bounty_approvals(), vec![2]);
+		// nothing is burnt while approvals are left over.
+		assert_eq!(Treasury::pot(), 100 - 20);
 
-		assert_eq!(last_event(), RawEvent::BountyClaimed(0, 56, 3));
+		<Treasury as OnInitialize<u64>>::on_initialize(4);
+		assert_eq!(Bounties::bounties(2).unwrap().status, BountyStatus::Funded);
+		assert_eq!(Bounties::bounty_approvals(), Vec::<BountyIndex>::new());
+		assert_eq!(Treasury::pot(), (100 - 30) / 2);
+	});
+}
 
-		assert_eq!(Balances::free_balance(4), 14); // initial 10 + fee 4
+#[test]
+fn open_proposals_per_account_are_capped() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&0, 500);
+		MaxProposalsPerAccount::set(2);
 
-		assert_eq!(Balances::free_balance(3), 56);
-		assert_eq!(Balances::free_balance(Bounties::bounty_account_id(0)), 0);
+		assert_ok!(Bounties::propose_bounty(Origin::signed(0), 50, b"12345".to_vec()));
+		assert_ok!(Bounties::propose_bounty(Origin::signed(0), 50, b"12345".to_vec()));
+		assert_noop!(
+			Bounties::propose_bounty(Origin::signed(0), 50, b"12345".to_vec()),
+			Error::<Test, DefaultInstance>::TooManyProposals
+		);
+		assert_eq!(Bounties::open_proposal_count(0), 2);
 
-		assert_eq!(Bounties::bounties(0), None);
-		assert_eq!(Bounties::bounty_descriptions(0), None);
+		// approved and rejected proposals make room for new ones.
+		assert_ok!(Bounties::approve_bounty(Origin::root(), 0, FundingSource::Treasury));
+		assert_eq!(Bounties::open_proposal_count(0), 1);
+		assert_ok!(Bounties::propose_bounty(Origin::signed(0), 50, b"12345".to_vec()));
+		assert_ok!(Bounties::close_bounty(Origin::root(), 1, None));
+		assert_ok!(Bounties::close_bounty(Origin::root(), 2, None));
+		assert!(!OpenProposalCounts::<Test>::contains_key(0));
 	});
 }
 
 #[test]
-fn claim_handles_high_fee() {
+fn bounty_account_indices_work() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
 		Balances::make_free_balance_be(&Treasury::account_id(), 101);
-		Balances::make_free_balance_be(&4, 30);
-		assert_ok!(Bounties::propose_bounty(Origin::signed(0), 50, b"12345".to_vec()));
+		Balances::make_free_balance_be(&0, 300);
+		Balances::make_free_balance_be(&4, 10);
+		MaxBountiesPerAccount::set(2);
 
-		assert_ok!(Bounties::approve_bounty(Origin::root(), 0));
+		assert_ok!(Bounties::propose_bounty(Origin::signed(0), 50, b"12345".to_vec()));
+		assert_ok!(Bounties::propose_bounty(Origin::signed(0), 50, b"12345".to_vec()));
+		assert_noop!(
+			Bounties::propose_bounty(Origin::signed(0), 50, b"12345".to_vec()),
+			Error::<Test, DefaultInstance>::TooManyBounties
+		);
+		assert_eq!(Bounties::bounties_by_proposer(0), vec![0, 1]);
 
-		System::set_block_number(2);
+		assert_ok!(Bounties::approve_bounty(Origin::root(), 0, FundingSource::Treasury));
 		<Treasury as OnInitialize<u64>>::on_initialize(2);
 
-		assert_ok!(Bounties::propose_curator(Origin::root(), 0, 4, 49));
-		assert_ok!(Bounties::accept_curator(Origin::signed(4), 0));
+		assert_ok!(Bounties::propose_curator(Origin::root(), 1, 4, 4));
+		assert_ok!(Bounties::propose_curator(Origin::root(), 0, 4, 4));
+		assert_eq!(Bounties::bounties_by_curator(4), vec![0, 1]);
 
-		assert_ok!(Bounties::award_bounty(Origin::signed(4), 0, 3));
+		assert_ok!(Bounties::close_bounty(Origin::root(), 1, None));
+		assert_eq!(Bounties::bounties_by_proposer(0), vec![0]);
+		assert_eq!(Bounties::bounties_by_curator(4), vec![0]);
 
-		System::set_block_number(5);
-		<Treasury as OnInitialize<u64>>::on_initialize(5);
+		assert_ok!(Bounties::unassign_curator(Origin::root(), 0));
+		assert!(!BountiesByCurator::<Test>::contains_key(4));
 
-		// make fee > balance
-		let _ = Balances::slash(&Bounties::bounty_account_id(0), 10);
+		assert_ok!(Bounties::propose_curator(Origin::root(), 0, 4, 4));
+		assert_ok!(Bounties::accept_curator(Origin::signed(4), 0, None));
+		assert_ok!(Bounties::award_bounty(Origin::signed(4), 0, 3, None));
+		assert_eq!(Bounties::bounties_by_curator(4), vec![0]);
 
+		System::set_block_number(5);
 		assert_ok!(Bounties::claim_bounty(Origin::signed(1), 0));
+		assert!(!BountiesByProposer::<Test>::contains_key(0));
+		assert!(!BountiesByCurator::<Test>::contains_key(4));
+	});
+}
 
-		assert_eq!(last_event(), RawEvent::BountyClaimed(0, 0, 3));
+#[test]
+fn pause_new_bounties_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		assert_ok!(Bounties::propose_bounty(Origin::signed(0), 10, b"12345".to_vec()));
 
-		assert_eq!(Balances::free_balance(4), 70); // 30 + 50 - 10
-		assert_eq!(Balances::free_balance(3), 0);
-		assert_eq!(Balances::free_balance(Bounties::bounty_account_id(0)), 0);
+		assert_noop!(Bounties::pause_new_bounties(Origin::signed(0)), BadOrigin);
+		assert_ok!(Bounties::pause_new_bounties(Origin::root()));
+		assert_eq!(last_event(), RawEvent::NewBountiesPaused);
 
-		assert_eq!(Bounties::bounties(0), None);
-		assert_eq!(Bounties::bounty_descriptions(0), None);
+		assert_noop!(
+			Bounties::propose_bounty(Origin::signed(0), 10, b"12345".to_vec()),
+			Error::<Test, DefaultInstance>::Paused
+		);
+		assert_noop!(
+			Bounties::propose_bounty_with_hash(Origin::signed(0), 10, BlakeTwo256::hash(b"12345")),
+			Error::<Test, DefaultInstance>::Paused
+		);
+
+		// Existing bounties are unaffected.
+		assert_ok!(Bounties::approve_bounty(Origin::root(), 0, FundingSource::Treasury));
+
+		assert_noop!(Bounties::resume_new_bounties(Origin::signed(0)), BadOrigin);
+		assert_ok!(Bounties::resume_new_bounties(Origin::root()));
+		assert_eq!(last_event(), RawEvent::NewBountiesResumed);
+
+		assert_ok!(Bounties::propose_bounty(Origin::signed(1), 10, b"12345".to_vec()));
 	});
 }
 
 #[test]
-fn cancel_and_refund() {
+fn bounty_queries_work() {
 	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&0, 300);
+		assert_ok!(Bounties::propose_bounty(Origin::signed(0), 10, b"12345".to_vec()));
+		assert_ok!(Bounties::propose_bounty(Origin::signed(0), 20, b"12345".to_vec()));
+		assert_ok!(Bounties::propose_bounty(Origin::signed(0), 30, b"12345".to_vec()));
+		assert_ok!(Bounties::approve_bounty(Origin::root(), 1, FundingSource::Treasury));
+
+		let indices = |bounties: Vec<(BountyIndex, Bounty<u128, u64, u64>)>| {
+			bounties.into_iter().map(|(index, _)| index).collect::<Vec<_>>()
+		};
+		assert_eq!(indices(Bounties::all_bounties()), vec![0, 1, 2]);
+		assert_eq!(indices(Bounties::bounties_by_status(BountyStatusKind::Proposed)), vec![0, 2]);
+		assert_eq!(indices(Bounties::bounties_by_status(BountyStatusKind::Approved)), vec![1]);
+		assert!(Bounties::bounties_by_status(BountyStatusKind::Active).is_empty());
+	});
+}
 
+#[test]
+fn bounties_are_funded_from_their_funding_source() {
+	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&0, 300);
+		assert_ok!(Bounties::propose_bounty(Origin::signed(0), 50, b"12345".to_vec()));
+		assert_ok!(Bounties::propose_bounty(Origin::signed(0), 50, b"12345".to_vec()));
+
+		assert_ok!(Bounties::approve_bounty(Origin::root(), 0, FundingSource::Fund));
+		assert_ok!(Bounties::approve_bounty(Origin::root(), 1, FundingSource::Treasury));
+		assert_eq!(Bounties::bounty_funding_source(0), FundingSource::Fund);
+
+		// The treasury only funds its own bounties.
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+		assert_eq!(Bounties::bounties(1).unwrap().status, BountyStatus::Funded);
+		assert_eq!(Bounties::bounties(0).unwrap().status, BountyStatus::Approved);
+		assert_eq!(Bounties::bounty_approvals(), vec![0]);
+
+		let mut budget_remaining = 40;
+		let mut total_weight = 0;
+		let mut missed_any = false;
+		let spend = |budget_remaining: &mut u64, total_weight: &mut Weight, missed_any: &mut bool| {
+			<SpendFundsFrom<Test, pallet_treasury::DefaultInstance, FundSource> as
+				pallet_treasury::SpendFunds<Test>>::spend_funds(
+					budget_remaining,
+					&mut PositiveImbalanceOf::<Test>::zero(),
+					total_weight,
+					missed_any,
+				)
+		};
+		spend(&mut budget_remaining, &mut total_weight, &mut missed_any);
+		assert!(missed_any);
+		assert_eq!(Bounties::bounties(0).unwrap().status, BountyStatus::Approved);
+
+		budget_remaining = 100;
+		spend(&mut budget_remaining, &mut total_weight, &mut missed_any);
+		assert_eq!(budget_remaining, 50);
+		assert_eq!(Bounties::bounties(0).unwrap().status, BountyStatus::Funded);
+		assert!(Bounties::bounty_approvals().is_empty());
+
+		// The remaining funds of a cancelled bounty go back to its funding source.
+		assert_ok!(Bounties::close_bounty(Origin::root(), 0, None));
+		assert_eq!(Balances::free_balance(FUND), 50);
+		assert_eq!(Bounties::bounty_funding_source(0), FundingSource::Treasury);
+	});
+}
 
+#[test]
+fn assign_curator_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
 		Balances::make_free_balance_be(&Treasury::account_id(), 101);
 
+		assert_noop!(Bounties::propose_curator(Origin::root(), 0, 4, 4), Error::<Test, DefaultInstance>::InvalidIndex);
+
 		assert_ok!(Bounties::propose_bounty(Origin::signed(0), 50, b"12345".to_vec()));
 
-		assert_ok!(Bounties::approve_bounty(Origin::root(), 0));
+		assert_ok!(Bounties::approve_bounty(Origin::root(), 0, FundingSource::Treasury));
 
 		System::set_block_number(2);
 		<Treasury as OnInitialize<u64>>::on_initialize(2);
 
-		assert_ok!(Balances::transfer(Origin::signed(0), Bounties::bounty_account_id(0), 10));
+		assert_noop!(Bounties::propose_curator(Origin::root(), 0, 4, 50), Error::<Test, DefaultInstance>::InvalidFee);
+
+		assert_ok!(Bounties::propose_curator(Origin::root(), 0, 4, 4));
+		assert_eq!(last_event(), RawEvent::CuratorProposed(0, 4, 4));
 
 		assert_eq!(Bounties::bounties(0).unwrap(), Bounty {
 			proposer: 0,
-			fee: 0,
-			curator_deposit: 0,
-			value: 50,
-			bond: 85,
-			status: BountyStatus::Funded,
+			finance: BountyFinance {
+				value: 50,
+				fee: 4,
+				curator_deposit: 0,
+				bond: 85,
+			},
+			status: BountyStatus::CuratorProposed {
+				curator: 4,
+			},
 		});
 
-		assert_eq!(Balances::free_balance(Bounties::bounty_account_id(0)), 60);
+		assert_noop!(Bounties::accept_curator(Origin::signed(1), 0, None), Error::<Test, DefaultInstance>::RequireCurator);
+		assert_noop!(Bounties::accept_curator(Origin::signed(4), 0, None), pallet_balances::Error::<Test, _>::InsufficientBalance);
 
-		assert_noop!(Bounties::close_bounty(Origin::signed(0), 0), BadOrigin);
+		Balances::make_free_balance_be(&4, 10);
 
-		assert_ok!(Bounties::close_bounty(Origin::root(), 0));
+		assert_noop!(Bounties::accept_curator(Origin::signed(4), 0, Some(1)), Error::<Test, DefaultInstance>::DepositTooHigh);
+		assert_ok!(Bounties::accept_curator(Origin::signed(4), 0, Some(2)));
 
-		assert_eq!(Treasury::pot(), 85); // - 25 + 10
+		assert_eq!(Bounties::bounties(0).unwrap(), Bounty {
+			proposer: 0,
+			finance: BountyFinance {
+				value: 50,
+				fee: 4,
+				curator_deposit: 2,
+				bond: 85,
+			},
+			status: BountyStatus::Active {
+				curator: 4,
+				update_due: 22,
+			},
+		});
 
+		assert_eq!(Balances::free_balance(&4), 8);
+		assert_eq!(Balances::reserved_balance(&4), 2);
 	});
+}
+
+#[test]
+fn curator_deposit_is_clamped_between_min_and_max() {
+	new_test_ext().execute_with(|| {
+		// `BountyCuratorDeposit` is 50% of the fee.
+		assert_eq!(Bounties::calculate_curator_deposit(&4), 2);
 
+		CuratorDepositMin::set(Some(3));
+		assert_eq!(Bounties::calculate_curator_deposit(&4), 3);
+		assert_eq!(Bounties::calculate_curator_deposit(&10), 5);
+
+		CuratorDepositMax::set(Some(4));
+		assert_eq!(Bounties::calculate_curator_deposit(&10), 4);
+
+		CuratorDepositMin::set(None);
+		CuratorDepositMax::set(None);
+		assert_eq!(Bounties::calculate_curator_deposit(&2), 1);
+		assert_eq!(Bounties::calculate_curator_deposit(&100), 50);
+	});
 }
 
 #[test]
-fn award_and_cancel() {
+fn accept_curator_reserves_clamped_deposit() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
 		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&4, 10);
 		assert_ok!(Bounties::propose_bounty(Origin::signed(0), 50, b"12345".to_vec()));
-
-		assert_ok!(Bounties::approve_bounty(Origin::root(), 0));
+		assert_ok!(Bounties::approve_bounty(Origin::root(), 0, FundingSource::Treasury));
 
 		System::set_block_number(2);
 		<Treasury as OnInitialize<u64>>::on_initialize(2);
 
-		assert_ok!(Bounties::propose_curator(Origin::root(), 0, 0, 10));
-		assert_ok!(Bounties::accept_curator(Origin::signed(0), 0));
-
-		assert_eq!(Balances::free_balance(0), 95);
-		assert_eq!(Balances::reserved_balance(0), 5);
-
-		assert_ok!(Bounties::award_bounty(Origin::signed(0), 0, 3));
-
-		// Cannot close bounty directly when payout is happening...
-		assert_noop!(Bounties::close_bounty(Origin::root(), 0), Error::<Test>::PendingPayout);
-
-		// Instead unassign the curator to slash them and then close.
-		assert_ok!(Bounties::unassign_curator(Origin::root(), 0));
-		assert_ok!(Bounties::close_bounty(Origin::root(), 0));
+		CuratorDepositMax::set(Some(3));
+		assert_ok!(Bounties::propose_curator(Origin::root(), 0, 4, 40));
+		assert_ok!(Bounties::accept_curator(Origin::signed(4), 0, None));
 
-		assert_eq!(last_event(), RawEvent::BountyCanceled(0));
-
-		assert_eq!(Balances::free_balance(Bounties::bounty_account_id(0)), 0);
-
-		// Slashed.
-		assert_eq!(Balances::free_balance(0), 95);
-		assert_eq!(Balances::reserved_balance(0), 0);
-
-		assert_eq!(Bounties::bounties(0), None);
-		assert_eq!(Bounties::bounty_descriptions(0), None);
+		assert_eq!(Bounties::bounties(0).unwrap().finance.curator_deposit, 3);
+		assert_eq!(Balances::reserved_balance(4), 3);
 	});
 }
 
 #[test]
-fn expire_and_unassign() {
+fn unassign_curator_works() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
 		Balances::make_free_balance_be(&Treasury::account_id(), 101);
 		assert_ok!(Bounties::propose_bounty(Origin::signed(0), 50, b"12345".to_vec()));
 
-		assert_ok!(Bounties::approve_bounty(Origin::root(), 0));
+		assert_ok!(Bounties::approve_bounty(Origin::root(), 0, FundingSource::Treasury));
 
 		System::set_block_number(2);
 		<Treasury as OnInitialize<u64>>::on_initialize(2);
 
-		assert_ok!(Bounties::propose_curator(Origin::root(), 0, 1, 10));
-		assert_ok!(Bounties::accept_curator(Origin::signed(1), 0));
+		assert_ok!(Bounties::propose_curator(Origin::root(), 0, 4, 4));
 
-		assert_eq!(Balances::free_balance(1), 93);
-		assert_eq!(Balances::reserved_balance(1), 5);
+		assert_noop!(Bounties::unassign_curator(Origin::signed(1), 0), BadOrigin);
 
-		System::set_block_number(22);
-		<Treasury as OnInitialize<u64>>::on_initialize(22);
+		assert_ok!(Bounties::unassign_curator(Origin::signed(4), 0));
+		assert_eq!(last_event(), RawEvent::CuratorUnassigned(0, 4, false));
+
+		assert_eq!(Bounties::bounties(0).unwrap(), Bounty {
+			proposer: 0,
+			finance: BountyFinance {
+				value: 50,
+				fee: 4,
+				curator_deposit: 0,
+				bond: 85,
+			},
+			status: BountyStatus::Funded,
+		});
 
-		assert_noop!(Bounties::unassign_curator(Origin::signed(0), 0), Error::<Test>::Premature);
+		assert_ok!(Bounties::propose_curator(Origin::root(), 0, 4, 4));
 
-		System::set_block_number(23);
-		<Treasury as OnInitialize<u64>>::on_initialize(23);
+		Balances::make_free_balance_be(&4, 10);
 
-		assert_ok!(Bounties::unassign_curator(Origin::signed(0), 0));
+		assert_ok!(Bounties::accept_curator(Origin::signed(4), 0, None));
+
+		assert_ok!(Bounties::unassign_curator(Origin::root(), 0));
+		assert_eq!(last_event(), RawEvent::CuratorUnassigned(0, 4, true));
 
 		assert_eq!(Bounties::bounties(0).unwrap(), Bounty {
 			proposer: 0,
-			fee: 10,
-			curator_deposit: 0,
-			value: 50,
-			bond: 85,
+			finance: BountyFinance {
+				value: 50,
+				fee: 4,
+				curator_deposit: 0,
+				bond: 85,
+			},
 			status: BountyStatus::Funded,
 		});
 
-		assert_eq!(Balances::free_balance(1), 93);
-		assert_eq!(Balances::reserved_balance(1), 0); // slashed
-
+		assert_eq!(Balances::free_balance(&4), 8);
+		assert_eq!(Balances::reserved_balance(&4), 0); // slashed 2
 	});
 }
 
 #[test]
-fn extend_expiry() {
+fn curator_slash_appeal_works() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
 		Balances::make_free_balance_be(&Treasury::account_id(), 101);
 		Balances::make_free_balance_be(&4, 10);
 		assert_ok!(Bounties::propose_bounty(Origin::signed(0), 50, b"12345".to_vec()));
+		assert_ok!(Bounties::approve_bounty(Origin::root(), 0, FundingSource::Treasury));
+
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+
+		assert_ok!(Bounties::propose_curator(Origin::root(), 0, 4, 4));
+		assert_ok!(Bounties::accept_curator(Origin::signed(4), 0, None));
+		assert_ok!(Bounties::unassign_curator(Origin::root(), 0));
+
+		// the slashed deposit is held rather than passed on.
+		assert_eq!(Balances::free_balance(4), 8);
+		assert_eq!(Balances::reserved_balance(4), 0);
+		assert_eq!(Balances::free_balance(Bounties::curator_slash_account_id()), 2);
+		assert_eq!(Bounties::curator_slash_appeal(0, 4), Some((2, 7)));
+
+		System::set_block_number(7);
+		assert_noop!(Bounties::settle_curator_slash(Origin::signed(0), 0, 4), Error::<Test, DefaultInstance>::Premature);
+		assert_noop!(Bounties::restore_curator_slash(Origin::signed(0), 0, 4), BadOrigin);
 
-		assert_ok!(Bounties::approve_bounty(Origin::root(), 0));
+		assert_ok!(Bounties::restore_curator_slash(Origin::root(), 0, 4));
+		assert_eq!(last_event(), RawEvent::CuratorSlashRestored(0, 4, 2));
+		assert_eq!(Balances::free_balance(4), 10);
+		assert_eq!(Balances::free_balance(Bounties::curator_slash_account_id()), 0);
+		assert_eq!(Bounties::curator_slash_appeal(0, 4), None);
+		assert_noop!(Bounties::settle_curator_slash(Origin::signed(0), 0, 4), Error::<Test, DefaultInstance>::NoPendingSlash);
+
+		assert_ok!(Bounties::propose_curator(Origin::root(), 0, 4, 4));
+		assert_ok!(Bounties::accept_curator(Origin::signed(4), 0, None));
+		assert_ok!(Bounties::unassign_curator(Origin::root(), 0));
+		assert_eq!(Bounties::curator_slash_appeal(0, 4), Some((2, 12)));
+
+		System::set_block_number(13);
+		assert_noop!(Bounties::restore_curator_slash(Origin::root(), 0, 4), Error::<Test, DefaultInstance>::AppealPeriodOver);
+
+		let issuance = Balances::total_issuance();
+		assert_ok!(Bounties::settle_curator_slash(Origin::signed(0), 0, 4));
+		assert_eq!(last_event(), RawEvent::CuratorSlashEnacted(0, 4, 2));
+		assert_eq!(Balances::free_balance(4), 8);
+		assert_eq!(Balances::free_balance(Bounties::curator_slash_account_id()), 0);
+		assert_eq!(Balances::total_issuance(), issuance - 2);
+		assert_eq!(Bounties::curator_slash_appeal(0, 4), None);
+	});
+}
+
+
+#[test]
+fn award_and_claim_bounty_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&4, 10);
+		assert_ok!(Bounties::propose_bounty(Origin::signed(0), 50, b"12345".to_vec()));
 
-		assert_noop!(Bounties::extend_bounty_expiry(Origin::signed(1), 0, Vec::new()), Error::<Test>::UnexpectedStatus);
+		assert_ok!(Bounties::approve_bounty(Origin::root(), 0, FundingSource::Treasury));
 
 		System::set_block_number(2);
 		<Treasury as OnInitialize<u64>>::on_initialize(2);
 
-		assert_ok!(Bounties::propose_curator(Origin::root(), 0, 4, 10));
-		assert_ok!(Bounties::accept_curator(Origin::signed(4), 0));
+		assert_ok!(Bounties::propose_curator(Origin::root(), 0, 4, 4));
+		assert_ok!(Bounties::accept_curator(Origin::signed(4), 0, None));
+		assert_eq!(last_event(), RawEvent::CuratorAccepted(0, 4, Some(H256::repeat_byte(4))));
 
-		assert_eq!(Balances::free_balance(4), 5);
-		assert_eq!(Balances::reserved_balance(4), 5);
+		assert_eq!(Balances::free_balance(4), 8); // inital 10 - 2 deposit
 
-		System::set_block_number(10);
-		<Treasury as OnInitialize<u64>>::on_initialize(10);
+		assert_noop!(Bounties::award_bounty(Origin::signed(1), 0, 3, None), Error::<Test, DefaultInstance>::RequireCurator);
 
-		assert_noop!(Bounties::extend_bounty_expiry(Origin::signed(0), 0, Vec::new()), Error::<Test>::RequireCurator);
-		assert_ok!(Bounties::extend_bounty_expiry(Origin::signed(4), 0, Vec::new()));
+		assert_ok!(Bounties::award_bounty(Origin::signed(4), 0, 3, None));
+		assert_eq!(last_event(), RawEvent::BountyAwarded(0, 3, None, None));
 
 		assert_eq!(Bounties::bounties(0).unwrap(), Bounty {
 			proposer: 0,
-			fee: 10,
-			curator_deposit: 5,
-			value: 50,
-			bond: 85,
-			status: BountyStatus::Active { curator: 4, update_due: 30 },
+			finance: BountyFinance {
+				value: 50,
+				fee: 4,
+				curator_deposit: 2,
+				bond: 85,
+			},
+			status: BountyStatus::PendingPayout {
+				curator: 4,
+				beneficiary: 3,
+				unlock_at: 5
+			},
 		});
 
-		assert_ok!(Bounties::extend_bounty_expiry(Origin::signed(4), 0, Vec::new()));
+		assert_noop!(Bounties::claim_bounty(Origin::signed(1), 0), Error::<Test, DefaultInstance>::Premature);
 
-		assert_eq!(Bounties::bounties(0).unwrap(), Bounty {
-			proposer: 0,
-			fee: 10,
-			curator_deposit: 5,
-			value: 50,
-			bond: 85,
-			status: BountyStatus::Active { curator: 4, update_due: 30 }, // still the same
-		});
+		System::set_block_number(5);
+		<Treasury as OnInitialize<u64>>::on_initialize(5);
 
-		System::set_block_number(25);
-		<Treasury as OnInitialize<u64>>::on_initialize(25);
+		assert_ok!(Balances::transfer(Origin::signed(0), Bounties::bounty_account_id(0), 10));
 
-		assert_noop!(Bounties::unassign_curator(Origin::signed(0), 0), Error::<Test>::Premature);
-		assert_ok!(Bounties::unassign_curator(Origin::signed(4), 0));
+		assert_ok!(Bounties::claim_bounty(Origin::signed(1), 0));
 
-		assert_eq!(Balances::free_balance(4), 10); // not slashed
-		assert_eq!(Balances::reserved_balance(4), 0);
+		assert_eq!(last_event(), RawEvent::BountyClaimed(0, 56, 3, 4, 2));
+
+		assert_eq!(Balances::free_balance(4), 14); // initial 10 + fee 4
+
+		assert_eq!(Balances::free_balance(3), 56);
+		assert_eq!(Balances::free_balance(Bounties::bounty_account_id(0)), 0);
+
+		assert_eq!(Bounties::bounties(0), None);
+		assert_eq!(Bounties::bounty_descriptions(0), None);
 	});
 }
 
 #[test]
-fn genesis_funding_works() {
-	let mut t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
-	let initial_funding = 100;
-	pallet_balances::GenesisConfig::<Test>{
-		// Total issuance will be 200 with treasury account initialized with 100.
-		balances: vec![(0, 100), (Treasury::account_id(), initial_funding)],
-	}.assimilate_storage(&mut t).unwrap();
-	pallet_treasury::GenesisConfig::default().assimilate_storage::<Test, _>(&mut t).unwrap();
-	let mut t: sp_io::TestExternalities = t.into();
+fn award_and_close_bounty_record_remark_hashes() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&0, 300);
+		Balances::make_free_balance_be(&4, 10);
+		assert_ok!(Bounties::propose_bounty(Origin::signed(0), 50, b"12345".to_vec()));
+		assert_ok!(Bounties::propose_bounty(Origin::signed(0), 20, b"67890".to_vec()));
+		assert_ok!(Bounties::propose_bounty(Origin::signed(0), 20, b"abcde".to_vec()));
+		assert_ok!(Bounties::approve_bounty(Origin::root(), 0, FundingSource::Treasury));
+		assert_ok!(Bounties::approve_bounty(Origin::root(), 1, FundingSource::Treasury));
 
-	t.execute_with(|| {
-		assert_eq!(Balances::free_balance(Treasury::account_id()), initial_funding);
-		assert_eq!(Treasury::pot(), initial_funding - Balances::minimum_balance());
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+
+		assert_ok!(Bounties::propose_curator(Origin::root(), 0, 4, 4));
+		assert_ok!(Bounties::accept_curator(Origin::signed(4), 0, None));
+
+		let too_long = vec![0; MaximumReasonLength::get() as usize + 1];
+		assert_noop!(
+			Bounties::award_bounty(Origin::signed(4), 0, 3, Some(too_long.clone())),
+			Error::<Test, DefaultInstance>::ReasonTooBig,
+		);
+		assert_noop!(
+			Bounties::close_bounty(Origin::root(), 1, Some(too_long)),
+			Error::<Test, DefaultInstance>::ReasonTooBig,
+		);
+
+		assert_ok!(Bounties::award_bounty(Origin::signed(4), 0, 3, Some(b"see PR 42".to_vec())));
+		let remark_hash = BlakeTwo256::hash(b"see PR 42");
+		assert_eq!(last_event(), RawEvent::BountyAwarded(0, 3, None, Some(remark_hash)));
+
+		assert_ok!(Bounties::close_bounty(Origin::root(), 1, Some(b"duplicate".to_vec())));
+		let remark_hash = BlakeTwo256::hash(b"duplicate");
+		assert_eq!(last_event(), RawEvent::BountyCanceled(1, Some(remark_hash)));
+
+		let bond = Bounties::bounties(2).unwrap().finance.bond;
+		assert_ok!(Bounties::close_bounty(Origin::root(), 2, Some(b"out of scope".to_vec())));
+		let remark_hash = BlakeTwo256::hash(b"out of scope");
+		assert_eq!(last_event(), RawEvent::BountyRejected(2, bond, 0, Some(remark_hash)));
+	});
+}
+
+#[test]
+fn award_bounty_to_location_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&4, 10);
+		RemotePayouts::set(vec![]);
+		assert_ok!(Bounties::propose_bounty(Origin::signed(0), 50, b"12345".to_vec()));
+		assert_ok!(Bounties::approve_bounty(Origin::root(), 0, FundingSource::Treasury));
+
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+
+		assert_ok!(Bounties::propose_curator(Origin::root(), 0, 4, 4));
+		assert_ok!(Bounties::accept_curator(Origin::signed(4), 0, None));
+
+		assert_noop!(
+			Bounties::award_bounty_to_location(Origin::signed(4), 0, (1000, 3), None),
+			Error::<Test, DefaultInstance>::UnsupportedBeneficiary,
+		);
+		assert_noop!(
+			Bounties::award_bounty_to_location(Origin::signed(1), 0, (REMOTE_CHAIN, 3), None),
+			Error::<Test, DefaultInstance>::RequireCurator,
+		);
+
+		// turning the award down forgets the location.
+		assert_ok!(Bounties::award_bounty_to_location(Origin::signed(4), 0, (REMOTE_CHAIN, 5), None));
+		assert_eq!(Bounties::bounty_beneficiary_location(0), Some((REMOTE_CHAIN, 5)));
+		assert_ok!(Bounties::reject_award(Origin::signed(4), 0));
+		assert_eq!(Bounties::bounty_beneficiary_location(0), None);
+
+		System::set_block_number(3);
+		assert_ok!(Bounties::award_bounty_to_location(Origin::signed(4), 0, (REMOTE_CHAIN, 3), None));
+		assert_eq!(last_event(), RawEvent::BountyAwardedToLocation(0, (REMOTE_CHAIN, 3), None));
+
+		System::set_block_number(6);
+		assert_ok!(Bounties::claim_bounty(Origin::signed(4), 0));
+		assert_eq!(last_event(), RawEvent::BountyClaimed(0, 46, 4, 4, 2));
+		assert_eq!(RemotePayouts::get(), vec![((REMOTE_CHAIN, 3), 46)]);
+		assert_eq!(Balances::free_balance(REMOTE_CHAIN_ACCOUNT), 46);
+		assert_eq!(Balances::free_balance(4), 14);
+		assert_eq!(Bounties::bounty_beneficiary_location(0), None);
+		assert_eq!(Bounties::bounties(0), None);
+	});
+}
+
+#[test]
+fn reject_origin_can_claim_bounty_for_beneficiary() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&4, 10);
+		assert_ok!(Bounties::propose_bounty(Origin::signed(0), 50, b"12345".to_vec()));
+		assert_ok!(Bounties::approve_bounty(Origin::root(), 0, FundingSource::Treasury));
+
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+
+		assert_ok!(Bounties::propose_curator(Origin::root(), 0, 4, 4));
+		assert_ok!(Bounties::accept_curator(Origin::signed(4), 0, None));
+		assert_ok!(Bounties::award_bounty(Origin::signed(4), 0, 3, None));
+
+		assert_noop!(Bounties::claim_bounty(Origin::root(), 0), Error::<Test, DefaultInstance>::Premature);
+		assert_noop!(Bounties::claim_bounty(Origin::none(), 0), BadOrigin);
+
+		System::set_block_number(5);
+		assert_ok!(Bounties::claim_bounty(Origin::root(), 0));
+
+		assert_eq!(last_event(), RawEvent::BountyClaimed(0, 46, 3, 4, 2));
+		assert_eq!(Balances::free_balance(3), 46);
+		assert_eq!(Bounties::bounties(0), None);
+	});
+}
+
+#[test]
+fn self_award_requires_approval_when_enabled() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&4, 10);
+		assert_ok!(Bounties::propose_bounty(Origin::signed(0), 50, b"12345".to_vec()));
+		assert_ok!(Bounties::approve_bounty(Origin::root(), 0, FundingSource::Treasury));
+
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+
+		assert_ok!(Bounties::propose_curator(Origin::root(), 0, 4, 4));
+		assert_noop!(Bounties::approve_self_award(Origin::root(), 0), Error::<Test, DefaultInstance>::UnexpectedStatus);
+		assert_ok!(Bounties::accept_curator(Origin::signed(4), 0, None));
+
+		RequireSelfAwardApproval::set(true);
+		assert_noop!(Bounties::award_bounty(Origin::signed(4), 0, 4, None), Error::<Test, DefaultInstance>::SelfAwardRequiresApproval);
+
+		assert_noop!(Bounties::approve_self_award(Origin::signed(4), 0), BadOrigin);
+		assert_ok!(Bounties::approve_self_award(Origin::root(), 0));
+		assert_eq!(last_event(), RawEvent::SelfAwardApproved(0, 4));
+		assert_ok!(Bounties::award_bounty(Origin::signed(4), 0, 4, None));
+		assert_eq!(Bounties::self_award_approval(0), None);
+
+		// the approval is used up by the award.
+		assert_ok!(Bounties::reject_award(Origin::signed(4), 0));
+		assert_noop!(Bounties::award_bounty(Origin::signed(4), 0, 4, None), Error::<Test, DefaultInstance>::SelfAwardRequiresApproval);
+
+		// awards to others, or without the rule, need no approval.
+		RequireSelfAwardApproval::set(false);
+		assert_ok!(Bounties::award_bounty(Origin::signed(4), 0, 4, None));
+		assert_ok!(Bounties::reject_award(Origin::signed(4), 0));
+		RequireSelfAwardApproval::set(true);
+		assert_ok!(Bounties::award_bounty(Origin::signed(4), 0, 3, None));
+	});
+}
+
+#[test]
+fn reject_award_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&4, 10);
+		assert_ok!(Bounties::propose_bounty(Origin::signed(0), 50, b"12345".to_vec()));
+		assert_ok!(Bounties::approve_bounty(Origin::root(), 0, FundingSource::Treasury));
+
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+
+		assert_ok!(Bounties::propose_curator(Origin::root(), 0, 4, 4));
+		assert_ok!(Bounties::accept_curator(Origin::signed(4), 0, None));
+		assert_noop!(Bounties::reject_award(Origin::signed(3), 0), Error::<Test, DefaultInstance>::UnexpectedStatus);
+		assert_ok!(Bounties::award_bounty(Origin::signed(4), 0, 3, None));
+
+		System::set_block_number(3);
+		assert_noop!(Bounties::reject_award(Origin::signed(4), 0), BadOrigin);
+		assert_ok!(Bounties::reject_award(Origin::signed(3), 0));
+		assert_eq!(last_event(), RawEvent::BountyAwardRejected(0, 3));
+		assert_eq!(Bounties::bounties(0).unwrap().status, BountyStatus::Active {
+			curator: 4,
+			update_due: 23,
+		});
+
+		// the curator awards it again, this time to the right account.
+		assert_ok!(Bounties::award_bounty(Origin::signed(4), 0, 5, None));
+		System::set_block_number(6);
+		assert_ok!(Bounties::claim_bounty(Origin::signed(1), 0));
+		assert_eq!(Balances::free_balance(3), 0);
+		assert_eq!(Balances::free_balance(5), 46);
+	});
+}
+
+#[test]
+fn bounty_payout_delay_can_be_set() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&4, 10);
+		assert_ok!(Bounties::propose_bounty(Origin::signed(0), 50, b"12345".to_vec()));
+
+		assert_noop!(Bounties::set_bounty_payout_delay(Origin::root(), 1, Some(10)), Error::<Test, DefaultInstance>::InvalidIndex);
+		assert_noop!(Bounties::set_bounty_payout_delay(Origin::signed(0), 0, Some(10)), BadOrigin);
+		assert_ok!(Bounties::set_bounty_payout_delay(Origin::root(), 0, Some(10)));
+		assert_eq!(last_event(), RawEvent::BountyPayoutDelaySet(0, Some(10)));
+		assert_eq!(Bounties::bounty_payout_delay(0), Some(10));
+
+		assert_ok!(Bounties::approve_bounty(Origin::root(), 0, FundingSource::Treasury));
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+		assert_ok!(Bounties::propose_curator(Origin::root(), 0, 4, 4));
+		assert_ok!(Bounties::accept_curator(Origin::signed(4), 0, None));
+
+		// the bounty delay is used instead of `BountyDepositPayoutDelay`.
+		assert_ok!(Bounties::award_bounty(Origin::signed(4), 0, 3, None));
+		assert_eq!(Bounties::bounties(0).unwrap().status, BountyStatus::PendingPayout {
+			curator: 4,
+			beneficiary: 3,
+			unlock_at: 12,
+		});
+		assert_noop!(Bounties::set_bounty_payout_delay(Origin::root(), 0, None), Error::<Test, DefaultInstance>::UnexpectedStatus);
+
+		System::set_block_number(11);
+		assert_noop!(Bounties::claim_bounty(Origin::signed(1), 0), Error::<Test, DefaultInstance>::Premature);
+		System::set_block_number(12);
+		assert_ok!(Bounties::claim_bounty(Origin::signed(1), 0));
+		assert!(!BountyPayoutDelays::<Test>::contains_key(0));
+	});
+}
+
+#[test]
+fn paused_bounty_is_frozen() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&4, 10);
+		assert_ok!(Bounties::propose_bounty(Origin::signed(0), 50, b"12345".to_vec()));
+
+		assert_noop!(Bounties::pause_bounty(Origin::root(), 1), Error::<Test, DefaultInstance>::InvalidIndex);
+		assert_noop!(Bounties::pause_bounty(Origin::signed(0), 0), BadOrigin);
+		assert_ok!(Bounties::pause_bounty(Origin::root(), 0));
+		assert_eq!(last_event(), RawEvent::BountyPaused(0));
+		assert_noop!(
+			Bounties::approve_bounty(Origin::root(), 0, FundingSource::Treasury),
+			Error::<Test, DefaultInstance>::BountyPaused
+		);
+		assert_noop!(Bounties::close_bounty(Origin::root(), 0, None), Error::<Test, DefaultInstance>::BountyPaused);
+
+		assert_noop!(Bounties::unpause_bounty(Origin::signed(0), 0), BadOrigin);
+		assert_ok!(Bounties::unpause_bounty(Origin::root(), 0));
+		assert_eq!(last_event(), RawEvent::BountyUnpaused(0));
+		assert_noop!(Bounties::unpause_bounty(Origin::root(), 0), Error::<Test, DefaultInstance>::UnexpectedStatus);
+		assert_ok!(Bounties::approve_bounty(Origin::root(), 0, FundingSource::Treasury));
+
+		// a paused bounty is not funded, and stays approved.
+		assert_ok!(Bounties::pause_bounty(Origin::root(), 0));
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+		assert_eq!(Bounties::bounties(0).unwrap().status, BountyStatus::Approved);
+		assert_eq!(Bounties::bounty_approvals(), vec![0]);
+
+		assert_ok!(Bounties::unpause_bounty(Origin::root(), 0));
+		System::set_block_number(4);
+		<Treasury as OnInitialize<u64>>::on_initialize(4);
+		assert_eq!(Bounties::bounties(0).unwrap().status, BountyStatus::Funded);
+		assert_ok!(Bounties::propose_curator(Origin::root(), 0, 4, 4));
+		assert_ok!(Bounties::accept_curator(Origin::signed(4), 0, None));
+		assert_ok!(Bounties::award_bounty(Origin::signed(4), 0, 3, None));
+
+		// the payout cannot be claimed while paused, and nothing is slashed.
+		assert_ok!(Bounties::pause_bounty(Origin::root(), 0));
+		System::set_block_number(10);
+		assert_noop!(Bounties::claim_bounty(Origin::signed(1), 0), Error::<Test, DefaultInstance>::BountyPaused);
+		assert_eq!(Balances::reserved_balance(4), 2);
+
+		assert_ok!(Bounties::unpause_bounty(Origin::root(), 0));
+		assert_ok!(Bounties::claim_bounty(Origin::signed(1), 0));
+		assert_eq!(Balances::free_balance(3), 46);
+	});
+}
+
+#[test]
+fn curator_fee_rate_is_paid_on_bounty_balance() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&4, 10);
+		assert_ok!(Bounties::propose_bounty(Origin::signed(0), 50, b"12345".to_vec()));
+		assert_ok!(Bounties::approve_bounty(Origin::root(), 0, FundingSource::Treasury));
+
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+
+		assert_noop!(
+			Bounties::propose_curator_with_rate(Origin::root(), 0, 4, Permill::one()),
+			Error::<Test, DefaultInstance>::InvalidFee
+		);
+		assert_ok!(Bounties::propose_curator_with_rate(Origin::root(), 0, 4, Permill::from_percent(10)));
+		assert_eq!(last_event(), RawEvent::CuratorProposedWithRate(0, 4, Permill::from_percent(10)));
+		assert_eq!(Bounties::bounties(0).unwrap().finance.fee, 5);
+
+		// an absolute fee replaces the rate.
+		assert_ok!(Bounties::unassign_curator(Origin::root(), 0));
+		assert_ok!(Bounties::propose_curator(Origin::root(), 0, 4, 4));
+		assert_eq!(Bounties::bounty_curator_fee_rate(0), None);
+		assert_ok!(Bounties::unassign_curator(Origin::root(), 0));
+		assert_ok!(Bounties::propose_curator_with_rate(Origin::root(), 0, 4, Permill::from_percent(10)));
+		assert_ok!(Bounties::accept_curator(Origin::signed(4), 0, None));
+		assert_ok!(Bounties::award_bounty(Origin::signed(4), 0, 3, None));
+
+		// the bounty account was topped up before the payout.
+		Balances::make_free_balance_be(&Bounties::bounty_account_id(0), 60);
+		System::set_block_number(5);
+		assert_ok!(Bounties::claim_bounty(Origin::signed(1), 0));
+		assert_eq!(Balances::free_balance(3), 54);
+		assert_eq!(Balances::free_balance(4), 16);
+		assert_eq!(Bounties::bounty_curator_fee_rate(0), None);
+	});
+}
+
+#[test]
+fn update_award_beneficiary_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&4, 10);
+		assert_ok!(Bounties::propose_bounty(Origin::signed(0), 50, b"12345".to_vec()));
+		assert_ok!(Bounties::approve_bounty(Origin::root(), 0, FundingSource::Treasury));
+
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+
+		assert_ok!(Bounties::propose_curator(Origin::root(), 0, 4, 4));
+		assert_ok!(Bounties::accept_curator(Origin::signed(4), 0, None));
+		assert_noop!(Bounties::update_award_beneficiary(Origin::signed(4), 0, 5), Error::<Test, DefaultInstance>::UnexpectedStatus);
+		assert_ok!(Bounties::award_bounty(Origin::signed(4), 0, 3, None));
+
+		assert_noop!(Bounties::update_award_beneficiary(Origin::signed(3), 0, 5), Error::<Test, DefaultInstance>::RequireCurator);
+		assert_ok!(Bounties::update_award_beneficiary(Origin::signed(4), 0, 5));
+		assert_eq!(last_event(), RawEvent::BountyBeneficiaryUpdated(0, 3, 5));
+		assert_eq!(Bounties::bounties(0).unwrap().status, BountyStatus::PendingPayout {
+			curator: 4,
+			beneficiary: 5,
+			unlock_at: 5,
+		});
+
+		System::set_block_number(5);
+		assert_noop!(Bounties::update_award_beneficiary(Origin::signed(4), 0, 3), Error::<Test, DefaultInstance>::PayoutUnlocked);
+		assert_ok!(Bounties::claim_bounty(Origin::signed(1), 0));
+		assert_eq!(Balances::free_balance(3), 0);
+		assert_eq!(Balances::free_balance(5), 46);
+	});
+}
+
+#[test]
+fn tip_co_funding_works() {
+	new_test_ext().execute_with(|| {
+		use pallet_tips::TipFundingSource;
+
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&4, 10);
+		assert_ok!(Bounties::propose_bounty(Origin::signed(0), 50, b"12345".to_vec()));
+		assert_ok!(Bounties::approve_bounty(Origin::root(), 0, FundingSource::Treasury));
+
+		let reason = BlakeTwo256::hash(b"awesome.dot");
+		let other = BlakeTwo256::hash(b"great.dot");
+		assert_noop!(
+			Bounties::set_tip_co_funding(Origin::signed(4), 0, vec![reason], 10),
+			Error::<Test, DefaultInstance>::UnexpectedStatus
+		);
+
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+		assert_ok!(Bounties::propose_curator(Origin::root(), 0, 4, 4));
+		assert_ok!(Bounties::accept_curator(Origin::signed(4), 0, None));
+
+		assert_noop!(
+			Bounties::set_tip_co_funding(Origin::signed(1), 0, vec![reason], 10),
+			Error::<Test, DefaultInstance>::RequireCurator
+		);
+		assert_noop!(
+			Bounties::set_tip_co_funding(Origin::signed(4), 0, vec![reason, other, H256::zero()], 10),
+			Error::<Test, DefaultInstance>::TooManyTipReasons
+		);
+		assert_ok!(Bounties::set_tip_co_funding(Origin::signed(4), 0, vec![reason, reason], 10));
+		assert_eq!(last_event(), RawEvent::BountyTipCoFundingSet(0));
+		assert_eq!(Bounties::bounty_tip_reasons(0), vec![reason]);
+
+		let bounty_account = Bounties::bounty_account_id(0);
+		assert_eq!(<Bounties as TipFundingSource<_, _, _>>::co_funding(&reason), Some((bounty_account, 10)));
+		assert_eq!(<Bounties as TipFundingSource<_, _, _>>::co_funding(&other), None);
+
+		// contributions are not available for tips.
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Bounties::contribute_to_bounty(Origin::signed(1), 0, 40));
+		assert_ok!(Bounties::set_tip_co_funding(Origin::signed(4), 0, vec![other], 60));
+		assert_eq!(Bounties::tip_co_funding(reason), None);
+		assert_eq!(<Bounties as TipFundingSource<_, _, _>>::co_funding(&other), Some((bounty_account, 50)));
+
+		// only active bounties co-fund tips.
+		assert_ok!(Bounties::award_bounty(Origin::signed(4), 0, 3, None));
+		assert_eq!(<Bounties as TipFundingSource<_, _, _>>::co_funding(&other), None);
+
+		System::set_block_number(5);
+		assert_ok!(Bounties::claim_bounty(Origin::signed(1), 0));
+		assert_eq!(Bounties::tip_co_funding(other), None);
+		assert_eq!(Bounties::bounty_tip_reasons(0), Vec::<H256>::new());
+	});
+}
+
+#[test]
+fn sweep_unclaimed_bounty_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&4, 10);
+		assert_ok!(Bounties::propose_bounty(Origin::signed(0), 50, b"12345".to_vec()));
+		assert_ok!(Bounties::approve_bounty(Origin::root(), 0, FundingSource::Treasury));
+
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+
+		assert_ok!(Bounties::propose_curator(Origin::root(), 0, 4, 4));
+		assert_ok!(Bounties::accept_curator(Origin::signed(4), 0, None));
+		Balances::make_free_balance_be(&1, 10);
+		assert_ok!(Bounties::contribute_to_bounty(Origin::signed(1), 0, 5));
+
+		assert_noop!(Bounties::sweep_unclaimed_bounty(Origin::signed(1), 0), Error::<Test, DefaultInstance>::UnexpectedStatus);
+		assert_ok!(Bounties::award_bounty(Origin::signed(4), 0, 3, None));
+
+		// unlocked at 5, claimable until 15.
+		System::set_block_number(15);
+		assert_noop!(Bounties::sweep_unclaimed_bounty(Origin::signed(1), 0), Error::<Test, DefaultInstance>::Premature);
+
+		System::set_block_number(16);
+		let pot = Treasury::pot();
+		assert_ok!(Bounties::sweep_unclaimed_bounty(Origin::signed(1), 0));
+		assert_eq!(last_event(), RawEvent::BountyUnclaimedSwept(0, 4, 46));
+
+		assert_eq!(Balances::free_balance(3), 0);
+		assert_eq!(Balances::free_balance(4), 14);
+		assert_eq!(Balances::reserved_balance(4), 0);
+		assert_eq!(Treasury::pot(), pot + 46);
+		assert_eq!(Bounties::bounties(0), None);
+		assert!(!BountiesByCurator::<Test>::contains_key(4));
+
+		// the contribution is left to be refunded.
+		assert_eq!(Balances::free_balance(Bounties::bounty_account_id(0)), 5);
+		assert_ok!(Bounties::refund_contribution(Origin::signed(1), 0));
+		assert_eq!(Balances::free_balance(1), 10);
+	});
+}
+
+#[test]
+fn maintenance_tip_is_paid_for_sweeps_but_not_own_claims() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 151);
+		Balances::make_free_balance_be(&0, 300);
+		Balances::make_free_balance_be(&4, 10);
+		MaintenanceTip::set(2);
+		for bounty_id in 0..3 {
+			assert_ok!(Bounties::propose_bounty(Origin::signed(0), 50, b"12345".to_vec()));
+			assert_ok!(Bounties::approve_bounty(Origin::root(), bounty_id, FundingSource::Treasury));
+		}
+
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+
+		for bounty_id in 0..3 {
+			assert_ok!(Bounties::propose_curator(Origin::root(), bounty_id, 4, 4));
+			assert_ok!(Bounties::accept_curator(Origin::signed(4), bounty_id, None));
+			assert_ok!(Bounties::award_bounty(Origin::signed(4), bounty_id, 3, None));
+		}
+
+		System::set_block_number(5);
+		// neither the beneficiary nor the council is tipped.
+		assert_ok!(Bounties::claim_bounty(Origin::signed(3), 0));
+		assert_eq!(last_event(), RawEvent::BountyClaimed(0, 46, 3, 4, 2));
+		assert_ok!(Bounties::claim_bounty(Origin::root(), 1));
+		assert_eq!(last_event(), RawEvent::BountyClaimed(1, 46, 3, 4, 2));
+
+		System::set_block_number(16);
+		let pot = Treasury::pot();
+		assert_ok!(Bounties::sweep_unclaimed_bounty(Origin::signed(1), 2));
+		assert!(System::events().iter().any(|r| r.event == Event::pallet_bounties(RawEvent::MaintenanceTipPaid(2, 1, 2))));
+		assert_eq!(last_event(), RawEvent::BountyUnclaimedSwept(2, 4, 44));
+		assert_eq!(Balances::free_balance(1), 100);
+		assert_eq!(Treasury::pot(), pot + 44);
+	});
+}
+
+#[test]
+fn claim_is_tipped_for_callers_other_than_the_beneficiary() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&4, 10);
+		MaintenanceTip::set(2);
+		assert_ok!(Bounties::propose_bounty(Origin::signed(0), 50, b"12345".to_vec()));
+		assert_ok!(Bounties::approve_bounty(Origin::root(), 0, FundingSource::Treasury));
+
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+
+		assert_ok!(Bounties::propose_curator(Origin::root(), 0, 4, 4));
+		assert_ok!(Bounties::accept_curator(Origin::signed(4), 0, None));
+		assert_ok!(Bounties::award_bounty(Origin::signed(4), 0, 3, None));
+
+		System::set_block_number(5);
+		assert_ok!(Bounties::claim_bounty(Origin::signed(1), 0));
+		assert!(System::events().iter().any(|r| r.event == Event::pallet_bounties(RawEvent::MaintenanceTipPaid(0, 1, 2))));
+		assert_eq!(last_event(), RawEvent::BountyClaimed(0, 44, 3, 4, 2));
+		assert_eq!(Balances::free_balance(1), 100);
+		assert_eq!(Balances::free_balance(3), 44);
+	});
+}
+
+#[test]
+fn claim_handles_high_fee() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&4, 30);
+		assert_ok!(Bounties::propose_bounty(Origin::signed(0), 50, b"12345".to_vec()));
+
+		assert_ok!(Bounties::approve_bounty(Origin::root(), 0, FundingSource::Treasury));
+
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+
+		assert_ok!(Bounties::propose_curator(Origin::root(), 0, 4, 49));
+		assert_ok!(Bounties::accept_curator(Origin::signed(4), 0, None));
+
+		assert_ok!(Bounties::award_bounty(Origin::signed(4), 0, 3, None));
+
+		System::set_block_number(5);
+		<Treasury as OnInitialize<u64>>::on_initialize(5);
+
+		// make fee > balance
+		let _ = Balances::slash(&Bounties::bounty_account_id(0), 10);
+
+		assert_ok!(Bounties::claim_bounty(Origin::signed(1), 0));
+
+		assert_eq!(last_event(), RawEvent::BountyClaimed(0, 0, 3, 40, 24));
+
+		assert_eq!(Balances::free_balance(4), 70); // 30 + 50 - 10
+		assert_eq!(Balances::free_balance(3), 0);
+		assert_eq!(Balances::free_balance(Bounties::bounty_account_id(0)), 0);
+
+		assert_eq!(Bounties::bounties(0), None);
+		assert_eq!(Bounties::bounty_descriptions(0), None);
+	});
+}
+
+#[test]
+fn cancel_and_refund() {
+	new_test_ext().execute_with(|| {
+
+		System::set_block_number(1);
+
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+
+		assert_ok!(Bounties::propose_bounty(Origin::signed(0), 50, b"12345".to_vec()));
+
+		assert_ok!(Bounties::approve_bounty(Origin::root(), 0, FundingSource::Treasury));
+
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+
+		assert_ok!(Balances::transfer(Origin::signed(0), Bounties::bounty_account_id(0), 10));
+
+		assert_eq!(Bounties::bounties(0).unwrap(), Bounty {
+			proposer: 0,
+			finance: BountyFinance {
+				value: 50,
+				fee: 0,
+				curator_deposit: 0,
+				bond: 85,
+			},
+			status: BountyStatus::Funded,
+		});
+
+		assert_eq!(Balances::free_balance(Bounties::bounty_account_id(0)), 60);
+
+		assert_noop!(Bounties::close_bounty(Origin::signed(0), 0, None), BadOrigin);
+
+		assert_ok!(Bounties::close_bounty(Origin::root(), 0, None));
+
+		assert_eq!(Treasury::pot(), 85); // - 25 + 10
+
+	});
+
+}
+
+#[test]
+fn award_and_cancel() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		assert_ok!(Bounties::propose_bounty(Origin::signed(0), 50, b"12345".to_vec()));
+
+		assert_ok!(Bounties::approve_bounty(Origin::root(), 0, FundingSource::Treasury));
+
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+
+		assert_ok!(Bounties::propose_curator(Origin::root(), 0, 0, 10));
+		assert_ok!(Bounties::accept_curator(Origin::signed(0), 0, None));
+
+		assert_eq!(Balances::free_balance(0), 95);
+		assert_eq!(Balances::reserved_balance(0), 5);
+
+		assert_ok!(Bounties::award_bounty(Origin::signed(0), 0, 3, None));
+
+		// Cannot close bounty directly when payout is happening...
+		assert_noop!(Bounties::close_bounty(Origin::root(), 0, None), Error::<Test, DefaultInstance>::PendingPayout);
+
+		// Instead unassign the curator to slash them and then close.
+		assert_ok!(Bounties::unassign_curator(Origin::root(), 0));
+		assert_ok!(Bounties::close_bounty(Origin::root(), 0, None));
+
+		assert_eq!(last_event(), RawEvent::BountyCanceled(0, None));
+
+		assert_eq!(Balances::free_balance(Bounties::bounty_account_id(0)), 0);
+
+		// Slashed.
+		assert_eq!(Balances::free_balance(0), 95);
+		assert_eq!(Balances::reserved_balance(0), 0);
+
+		assert_eq!(Bounties::bounties(0), None);
+		assert_eq!(Bounties::bounty_descriptions(0), None);
+	});
+}
+
+#[test]
+fn contribute_to_bounty_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&2, 50);
+		assert_ok!(Bounties::propose_bounty(Origin::signed(0), 50, b"12345".to_vec()));
+		assert_ok!(Bounties::approve_bounty(Origin::root(), 0, FundingSource::Treasury));
+
+		// Only funded or active bounties can be topped up.
+		assert_noop!(
+			Bounties::contribute_to_bounty(Origin::signed(1), 0, 10),
+			Error::<Test, DefaultInstance>::UnexpectedStatus
+		);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+		assert_noop!(
+			Bounties::contribute_to_bounty(Origin::signed(1), 0, 0),
+			Error::<Test, DefaultInstance>::InvalidValue
+		);
+
+		assert_ok!(Bounties::contribute_to_bounty(Origin::signed(1), 0, 10));
+		assert_ok!(Bounties::contribute_to_bounty(Origin::signed(2), 0, 5));
+		assert_ok!(Bounties::contribute_to_bounty(Origin::signed(1), 0, 10));
+		assert_eq!(last_event(), RawEvent::BountyContribution(0, 1, 10));
+		assert_eq!(Bounties::bounty_contribution(0, 1), 20);
+		assert_eq!(Bounties::bounty_contribution_total(0), 25);
+		assert_eq!(Balances::free_balance(Bounties::bounty_account_id(0)), 75);
+
+		assert_noop!(
+			Bounties::refund_contribution(Origin::signed(1), 0),
+			Error::<Test, DefaultInstance>::BountyNotCancelled
+		);
+
+		// Cancelling only sweeps the bounty value, contributions stay refundable.
+		assert_ok!(Bounties::close_bounty(Origin::root(), 0, None));
+		assert_eq!(Balances::free_balance(Bounties::bounty_account_id(0)), 25);
+
+		assert_ok!(Bounties::refund_contribution(Origin::signed(1), 0));
+		assert_eq!(last_event(), RawEvent::ContributionRefunded(0, 1, 20));
+		assert_eq!(Balances::free_balance(1), 98);
+		assert_noop!(Bounties::refund_contribution(Origin::signed(1), 0), Error::<Test, DefaultInstance>::NoContribution);
+
+		assert_ok!(Bounties::refund_contribution(Origin::signed(2), 0));
+		assert_eq!(Balances::free_balance(2), 50);
+		assert_eq!(Balances::free_balance(Bounties::bounty_account_id(0)), 0);
+		assert!(!BountyContributionTotals::<Test>::contains_key(0));
+	});
+}
+
+#[test]
+fn contributions_are_paid_out_with_the_bounty() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&4, 10);
+		assert_ok!(Bounties::propose_bounty(Origin::signed(0), 50, b"12345".to_vec()));
+		assert_ok!(Bounties::approve_bounty(Origin::root(), 0, FundingSource::Treasury));
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+
+		assert_ok!(Bounties::propose_curator(Origin::root(), 0, 4, 4));
+		assert_ok!(Bounties::accept_curator(Origin::signed(4), 0, None));
+		assert_ok!(Bounties::contribute_to_bounty(Origin::signed(1), 0, 10));
+		assert_ok!(Bounties::award_bounty(Origin::signed(4), 0, 3, None));
+
+		System::set_block_number(5);
+		assert_ok!(Bounties::claim_bounty(Origin::signed(1), 0));
+		assert_eq!(last_event(), RawEvent::BountyClaimed(0, 56, 3, 4, 2));
+		assert_eq!(Balances::free_balance(3), 56);
+
+		assert_eq!(Bounties::bounty_contribution(0, 1), 0);
+		assert!(!BountyContributionTotals::<Test>::contains_key(0));
+		assert_noop!(Bounties::refund_contribution(Origin::signed(1), 0), Error::<Test, DefaultInstance>::NoContribution);
+	});
+}
+
+#[test]
+fn expire_and_unassign() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		assert_ok!(Bounties::propose_bounty(Origin::signed(0), 50, b"12345".to_vec()));
+
+		assert_ok!(Bounties::approve_bounty(Origin::root(), 0, FundingSource::Treasury));
+
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+
+		assert_ok!(Bounties::propose_curator(Origin::root(), 0, 1, 10));
+		assert_ok!(Bounties::accept_curator(Origin::signed(1), 0, None));
+
+		assert_eq!(Balances::free_balance(1), 93);
+		assert_eq!(Balances::reserved_balance(1), 5);
+
+		System::set_block_number(22);
+		<Treasury as OnInitialize<u64>>::on_initialize(22);
+
+		assert_noop!(Bounties::unassign_curator(Origin::signed(0), 0), Error::<Test, DefaultInstance>::Premature);
+
+		System::set_block_number(23);
+		<Treasury as OnInitialize<u64>>::on_initialize(23);
+
+		assert_ok!(Bounties::unassign_curator(Origin::signed(0), 0));
+
+		assert_eq!(Bounties::bounties(0).unwrap(), Bounty {
+			proposer: 0,
+			finance: BountyFinance {
+				value: 50,
+				fee: 10,
+				curator_deposit: 0,
+				bond: 85,
+			},
+			status: BountyStatus::Funded,
+		});
+
+		assert_eq!(Balances::free_balance(1), 93);
+		assert_eq!(Balances::reserved_balance(1), 0); // slashed
+
+	});
+}
+
+#[test]
+fn extend_expiry() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&4, 10);
+		assert_ok!(Bounties::propose_bounty(Origin::signed(0), 50, b"12345".to_vec()));
+
+		assert_ok!(Bounties::approve_bounty(Origin::root(), 0, FundingSource::Treasury));
+
+		assert_noop!(Bounties::extend_bounty_expiry(Origin::signed(1), 0, Vec::new()), Error::<Test, DefaultInstance>::UnexpectedStatus);
+
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+
+		assert_ok!(Bounties::propose_curator(Origin::root(), 0, 4, 10));
+		assert_ok!(Bounties::accept_curator(Origin::signed(4), 0, None));
+
+		assert_eq!(Balances::free_balance(4), 5);
+		assert_eq!(Balances::reserved_balance(4), 5);
+
+		System::set_block_number(10);
+		<Treasury as OnInitialize<u64>>::on_initialize(10);
+
+		assert_noop!(Bounties::extend_bounty_expiry(Origin::signed(0), 0, Vec::new()), Error::<Test, DefaultInstance>::RequireCurator);
+		assert_ok!(Bounties::extend_bounty_expiry(Origin::signed(4), 0, Vec::new()));
+
+		assert_eq!(Bounties::bounties(0).unwrap(), Bounty {
+			proposer: 0,
+			finance: BountyFinance {
+				value: 50,
+				fee: 10,
+				curator_deposit: 5,
+				bond: 85,
+			},
+			status: BountyStatus::Active { curator: 4, update_due: 30 },
+		});
+
+		assert_ok!(Bounties::extend_bounty_expiry(Origin::signed(4), 0, Vec::new()));
+
+		assert_eq!(Bounties::bounties(0).unwrap(), Bounty {
+			proposer: 0,
+			finance: BountyFinance {
+				value: 50,
+				fee: 10,
+				curator_deposit: 5,
+				bond: 85,
+			},
+			status: BountyStatus::Active { curator: 4, update_due: 30 }, // still the same
+		});
+
+		System::set_block_number(25);
+		<Treasury as OnInitialize<u64>>::on_initialize(25);
+
+		assert_noop!(Bounties::unassign_curator(Origin::signed(0), 0), Error::<Test, DefaultInstance>::Premature);
+		assert_ok!(Bounties::unassign_curator(Origin::signed(4), 0));
+
+		assert_eq!(Balances::free_balance(4), 10); // not slashed
+		assert_eq!(Balances::reserved_balance(4), 0);
+	});
+}
+
+#[test]
+fn submit_work_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&4, 10);
+		assert_ok!(Bounties::propose_bounty(Origin::signed(0), 50, b"12345".to_vec()));
+		let work = BlakeTwo256::hash(b"work");
+
+		assert_noop!(Bounties::submit_work(Origin::signed(1), 1, work), Error::<Test, DefaultInstance>::InvalidIndex);
+		assert_noop!(Bounties::submit_work(Origin::signed(1), 0, work), Error::<Test, DefaultInstance>::UnexpectedStatus);
+
+		assert_ok!(Bounties::approve_bounty(Origin::root(), 0, FundingSource::Treasury));
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+		assert_ok!(Bounties::propose_curator(Origin::root(), 0, 4, 4));
+		assert_ok!(Bounties::accept_curator(Origin::signed(4), 0, None));
+
+		assert_ok!(Bounties::submit_work(Origin::signed(1), 0, work));
+		assert_eq!(last_event(), RawEvent::WorkSubmitted(0, 1, work));
+		System::set_block_number(3);
+		assert_ok!(Bounties::submit_work(Origin::signed(2), 0, BlakeTwo256::hash(b"more work")));
+		assert_eq!(Bounties::work_submissions(0), vec![
+			(1, work, 2),
+			(2, BlakeTwo256::hash(b"more work"), 3),
+		]);
+		assert_eq!(Balances::reserved_balance(1), 1);
+		assert_eq!(Balances::reserved_balance(2), 1);
+		assert_noop!(Bounties::submit_work(Origin::signed(3), 0, work), Error::<Test, DefaultInstance>::TooManySubmissions);
+
+		assert_ok!(Bounties::award_bounty(Origin::signed(4), 0, 1, None));
+		System::set_block_number(6);
+		assert_ok!(Bounties::claim_bounty(Origin::signed(1), 0));
+		assert!(Bounties::work_submissions(0).is_empty());
+		// the submission deposits are returned once the bounty is removed.
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::reserved_balance(2), 0);
+	});
+}
+
+#[test]
+fn curator_applications_work() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&4, 10);
+		Balances::make_free_balance_be(&5, 10);
+		assert_ok!(Bounties::propose_bounty(Origin::signed(0), 50, b"12345".to_vec()));
+
+		assert_noop!(Bounties::apply_for_curator(Origin::signed(4), 1, 4), Error::<Test, DefaultInstance>::InvalidIndex);
+		assert_noop!(Bounties::apply_for_curator(Origin::signed(4), 0, 4), Error::<Test, DefaultInstance>::UnexpectedStatus);
+
+		assert_ok!(Bounties::approve_bounty(Origin::root(), 0, FundingSource::Treasury));
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+
+		assert_noop!(Bounties::apply_for_curator(Origin::signed(4), 0, 50), Error::<Test, DefaultInstance>::InvalidFee);
+		assert_ok!(Bounties::apply_for_curator(Origin::signed(4), 0, 6));
+		assert_eq!(last_event(), RawEvent::CuratorApplied(0, 4, 6));
+		assert_ok!(Bounties::apply_for_curator(Origin::signed(5), 0, 8));
+		// applying again changes the bid.
+		assert_ok!(Bounties::apply_for_curator(Origin::signed(4), 0, 4));
+		assert_eq!(Bounties::curator_applications(0), vec![(4, 4), (5, 8)]);
+		// the deposit is only taken on the first application.
+		assert_eq!(Balances::reserved_balance(4), 1);
+		assert_eq!(Balances::reserved_balance(5), 1);
+		assert_noop!(Bounties::apply_for_curator(Origin::signed(6), 0, 4), Error::<Test, DefaultInstance>::TooManyApplicants);
+
+		assert_noop!(Bounties::select_curator(Origin::signed(0), 0, 4), BadOrigin);
+		assert_noop!(Bounties::select_curator(Origin::root(), 0, 6), Error::<Test, DefaultInstance>::NotApplicant);
+		assert_ok!(Bounties::select_curator(Origin::root(), 0, 4));
+		assert_eq!(last_event(), RawEvent::CuratorProposed(0, 4, 4));
+		assert_eq!(Bounties::bounties(0).unwrap().status, BountyStatus::CuratorProposed { curator: 4 });
+		assert_eq!(Bounties::bounties(0).unwrap().finance.fee, 4);
+		assert!(Bounties::curator_applications(0).is_empty());
+		assert_eq!(Balances::reserved_balance(4), 0);
+		assert_eq!(Balances::reserved_balance(5), 0);
+
+		assert_noop!(Bounties::apply_for_curator(Origin::signed(5), 0, 8), Error::<Test, DefaultInstance>::UnexpectedStatus);
+		assert_ok!(Bounties::accept_curator(Origin::signed(4), 0, None));
+	});
+}
+
+#[test]
+fn curator_application_deposits_are_returned_when_the_bounty_is_closed() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&4, 10);
+		assert_ok!(Bounties::propose_bounty(Origin::signed(0), 50, b"12345".to_vec()));
+		assert_ok!(Bounties::approve_bounty(Origin::root(), 0, FundingSource::Treasury));
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+
+		assert_ok!(Bounties::apply_for_curator(Origin::signed(4), 0, 4));
+		assert_eq!(Balances::reserved_balance(4), 1);
+		assert_ok!(Bounties::close_bounty(Origin::root(), 0, None));
+		assert_eq!(Balances::reserved_balance(4), 0);
+		assert_eq!(Bounties::curator_application_deposit(0, 4), None);
+	});
+}
+
+#[test]
+fn fast_tracked_bounty_has_shorter_periods() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&4, 10);
+		assert_ok!(Bounties::propose_bounty(Origin::signed(0), 50, b"12345".to_vec()));
+		assert_ok!(Bounties::approve_bounty(Origin::root(), 0, FundingSource::Treasury));
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+
+		assert_noop!(Bounties::fast_track_bounty(Origin::root(), 1), Error::<Test, DefaultInstance>::InvalidIndex);
+		assert_noop!(Bounties::fast_track_bounty(Origin::signed(0), 0), BadOrigin);
+		assert_ok!(Bounties::fast_track_bounty(Origin::root(), 0));
+		assert_eq!(last_event(), RawEvent::BountyFastTracked(0));
+
+		assert_ok!(Bounties::propose_curator(Origin::root(), 0, 4, 4));
+		assert_ok!(Bounties::accept_curator(Origin::signed(4), 0, None));
+		assert_eq!(Bounties::bounties(0).unwrap().status, BountyStatus::Active {
+			curator: 4,
+			// half of `BountyUpdatePeriod`.
+			update_due: 12,
+		});
+
+		assert_ok!(Bounties::award_bounty(Origin::signed(4), 0, 3, None));
+		assert_eq!(Bounties::bounties(0).unwrap().status, BountyStatus::PendingPayout {
+			curator: 4,
+			beneficiary: 3,
+			// half of `BountyDepositPayoutDelay`, rounded down.
+			unlock_at: 3,
+		});
+		assert_noop!(Bounties::fast_track_bounty(Origin::root(), 0), Error::<Test, DefaultInstance>::UnexpectedStatus);
+
+		System::set_block_number(3);
+		assert_ok!(Bounties::claim_bounty(Origin::signed(1), 0));
+		assert!(!Bounties::bounty_fast_tracked(0));
+	});
+}
+
+#[test]
+fn curator_fees_are_refunded() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&4, 10);
+		assert_ok!(Bounties::propose_bounty(Origin::signed(0), 50, b"12345".to_vec()));
+		assert_ok!(Bounties::approve_bounty(Origin::root(), 0, FundingSource::Treasury));
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+		assert_ok!(Bounties::propose_curator(Origin::root(), 0, 4, 4));
+		assert_ok!(Bounties::accept_curator(Origin::signed(4), 0, None));
+
+		let extend = || pallet_bounties::Call::extend_bounty_expiry(0, b"a long remark".to_vec());
+		let extend_weight = <Test as Config>::WeightInfo::extend_bounty_expiry();
+		assert_eq!(apply_with_fee_exemption(0, extend()), extend_weight);
+		assert_eq!(apply_with_fee_exemption(4, extend()), 0);
+
+		// failed calls still pay.
+		assert_ok!(Bounties::pause_bounty(Origin::root(), 0));
+		assert_eq!(apply_with_fee_exemption(4, extend()), extend_weight);
+		assert_ok!(Bounties::unpause_bounty(Origin::root(), 0));
+
+		assert_eq!(apply_with_fee_exemption(4, pallet_bounties::Call::award_bounty(0, 3, None)), 0);
+		assert_eq!(Bounties::bounties(0).unwrap().status, BountyStatus::PendingPayout {
+			curator: 4,
+			beneficiary: 3,
+			unlock_at: 5,
+		});
+
+		// other calls are not affected.
+		let reject_award = pallet_bounties::Call::reject_award(0);
+		let reject_award_weight = reject_award.get_dispatch_info().weight;
+		assert_eq!(apply_with_fee_exemption(4, reject_award), reject_award_weight);
+	});
+}
+
+#[test]
+fn genesis_funding_works() {
+	let mut t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	let initial_funding = 100;
+	pallet_balances::GenesisConfig::<Test>{
+		// Total issuance will be 200 with treasury account initialized with 100.
+		balances: vec![(0, 100), (Treasury::account_id(), initial_funding)],
+	}.assimilate_storage(&mut t).unwrap();
+	pallet_treasury::GenesisConfig::default().assimilate_storage::<Test, _>(&mut t).unwrap();
+	let mut t: sp_io::TestExternalities = t.into();
+
+	t.execute_with(|| {
+		assert_eq!(Balances::free_balance(Treasury::account_id()), initial_funding);
+		assert_eq!(Treasury::pot(), initial_funding - Balances::minimum_balance());
+	});
+}
+
+#[test]
+fn curator_records_and_bans_work() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&4, 10);
+		assert_ok!(Bounties::propose_bounty(Origin::signed(0), 50, b"12345".to_vec()));
+		assert_ok!(Bounties::propose_bounty(Origin::signed(1), 20, b"67890".to_vec()));
+		assert_ok!(Bounties::approve_bounty(Origin::root(), 0, FundingSource::Treasury));
+		assert_ok!(Bounties::approve_bounty(Origin::root(), 1, FundingSource::Treasury));
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+
+		assert_ok!(Bounties::propose_curator(Origin::root(), 0, 4, 4));
+		assert_ok!(Bounties::accept_curator(Origin::signed(4), 0, None));
+		assert_ok!(Bounties::award_bounty(Origin::signed(4), 0, 3, None));
+		System::set_block_number(5);
+		assert_ok!(Bounties::claim_bounty(Origin::signed(1), 0));
+		assert_eq!(Bounties::curator_record(4), CuratorRecord {
+			bounties_completed: 1,
+			times_slashed: 0,
+			fees_earned: 4,
+		});
+
+		assert_noop!(Bounties::ban_curator(Origin::signed(0), 4), BadOrigin);
+		assert_ok!(Bounties::ban_curator(Origin::root(), 4));
+		assert_eq!(last_event(), RawEvent::CuratorBanned(4));
+		assert_noop!(Bounties::propose_curator(Origin::root(), 1, 4, 2), Error::<Test, DefaultInstance>::BannedCurator);
+		assert_noop!(Bounties::apply_for_curator(Origin::signed(4), 1, 2), Error::<Test, DefaultInstance>::BannedCurator);
+
+		assert_ok!(Bounties::unban_curator(Origin::root(), 4));
+		assert_eq!(last_event(), RawEvent::CuratorUnbanned(4));
+		assert_ok!(Bounties::propose_curator(Origin::root(), 1, 4, 2));
+		assert_ok!(Bounties::accept_curator(Origin::signed(4), 1, None));
+		assert_ok!(Bounties::unassign_curator(Origin::root(), 1));
+		assert_eq!(Bounties::curator_record(4), CuratorRecord {
+			bounties_completed: 1,
+			times_slashed: 1,
+			fees_earned: 4,
+		});
+
+		// a slash restored on appeal does not count.
+		assert_ok!(Bounties::restore_curator_slash(Origin::root(), 1, 4));
+		assert_eq!(Bounties::curator_record(4).times_slashed, 0);
+	});
+}
+
+#[test]
+fn curator_calls_are_charged_for_the_path_taken() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&4, 10);
+		assert_ok!(Bounties::propose_bounty(Origin::signed(0), 50, b"12345".to_vec()));
+		assert_ok!(Bounties::approve_bounty(Origin::root(), 0, FundingSource::Treasury));
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+
+		let post_info = Bounties::apply_for_curator(Origin::signed(4), 0, 4).unwrap();
+		assert_eq!(post_info.actual_weight, Some(<() as WeightInfo>::apply_for_curator(0)));
+		let post_info = Bounties::select_curator(Origin::root(), 0, 4).unwrap();
+		assert_eq!(post_info.actual_weight, Some(<() as WeightInfo>::select_curator(1)));
+
+		let post_info = Bounties::unassign_curator(Origin::root(), 0).unwrap();
+		assert_eq!(post_info.actual_weight, Some(<() as WeightInfo>::unassign_curator_proposed()));
+
+		assert_ok!(Bounties::propose_curator(Origin::root(), 0, 4, 4));
+		assert_ok!(Bounties::accept_curator(Origin::signed(4), 0, None));
+		let reason = BlakeTwo256::hash(b"reason");
+		let post_info = Bounties::set_tip_co_funding(Origin::signed(4), 0, vec![reason], 10).unwrap();
+		assert_eq!(post_info.actual_weight, Some(<() as WeightInfo>::set_tip_co_funding(1)));
+
+		let post_info = Bounties::unassign_curator(Origin::root(), 0).unwrap();
+		assert_eq!(post_info.actual_weight, Some(<() as WeightInfo>::unassign_curator()));
+	});
+}
+
+#[test]
+fn curators_must_pass_the_curator_filter() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&4, 10);
+		UnverifiedCurators::set(vec![4]);
+		assert_ok!(Bounties::propose_bounty(Origin::signed(0), 50, b"12345".to_vec()));
+		assert_ok!(Bounties::approve_bounty(Origin::root(), 0, FundingSource::Treasury));
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+
+		assert_noop!(
+			Bounties::propose_curator(Origin::root(), 0, 4, 4),
+			Error::<Test, DefaultInstance>::CuratorNotAllowed,
+		);
+		assert_noop!(
+			Bounties::propose_curator_with_rate(Origin::root(), 0, 4, Permill::from_percent(10)),
+			Error::<Test, DefaultInstance>::CuratorNotAllowed,
+		);
+		assert_noop!(
+			Bounties::apply_for_curator(Origin::signed(4), 0, 4),
+			Error::<Test, DefaultInstance>::CuratorNotAllowed,
+		);
+
+		UnverifiedCurators::set(vec![]);
+		assert_ok!(Bounties::propose_curator(Origin::root(), 0, 4, 4));
+		assert_ok!(Bounties::accept_curator(Origin::signed(4), 0, None));
+	});
+}
+
+#[test]
+fn bounty_disputes_work() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&4, 10);
+		Balances::make_free_balance_be(&6, 20);
+		assert_ok!(Bounties::propose_bounty(Origin::signed(0), 50, b"12345".to_vec()));
+		assert_ok!(Bounties::propose_bounty(Origin::signed(1), 20, b"67890".to_vec()));
+		assert_ok!(Bounties::approve_bounty(Origin::root(), 0, FundingSource::Treasury));
+		assert_ok!(Bounties::approve_bounty(Origin::root(), 1, FundingSource::Treasury));
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+		for (bounty_id, fee) in [(0, 4), (1, 2)].iter().copied() {
+			assert_ok!(Bounties::propose_curator(Origin::root(), bounty_id, 4, fee));
+			assert_ok!(Bounties::accept_curator(Origin::signed(4), bounty_id, None));
+		}
+
+		let reason_hash = BlakeTwo256::hash(b"not done");
+		assert_noop!(Bounties::dispute_bounty(Origin::signed(6), 0, reason_hash), Error::<Test, DefaultInstance>::UnexpectedStatus);
+		assert_ok!(Bounties::award_bounty(Origin::signed(4), 0, 3, None));
+		assert_ok!(Bounties::award_bounty(Origin::signed(4), 1, 3, None));
+
+		assert_ok!(Bounties::dispute_bounty(Origin::signed(6), 0, reason_hash));
+		assert_eq!(last_event(), RawEvent::BountyDisputed(0, 6, reason_hash));
+		assert_eq!(Balances::reserved_balance(6), 5);
+		assert_noop!(Bounties::dispute_bounty(Origin::signed(6), 0, reason_hash), Error::<Test, DefaultInstance>::Disputed);
+		assert_noop!(Bounties::update_award_beneficiary(Origin::signed(4), 0, 5), Error::<Test, DefaultInstance>::Disputed);
+		assert_noop!(Bounties::reject_award(Origin::signed(3), 0), Error::<Test, DefaultInstance>::Disputed);
+		System::set_block_number(5);
+		assert_noop!(Bounties::claim_bounty(Origin::signed(1), 0), Error::<Test, DefaultInstance>::Disputed);
+
+		// the award stands, and the disputer loses their deposit.
+		assert_noop!(Bounties::resolve_dispute(Origin::signed(6), 0, DisputeVerdict::Release), BadOrigin);
+		assert_noop!(Bounties::resolve_dispute(Origin::root(), 1, DisputeVerdict::Release), Error::<Test, DefaultInstance>::NotDisputed);
+		assert_ok!(Bounties::resolve_dispute(Origin::root(), 0, DisputeVerdict::Release));
+		assert_eq!(last_event(), RawEvent::DisputeResolved(0, DisputeVerdict::Release));
+		assert_eq!(Balances::reserved_balance(6), 0);
+		assert_eq!(Balances::free_balance(6), 15);
+		assert_ok!(Bounties::claim_bounty(Origin::signed(1), 0));
+
+		// the bounty goes to another beneficiary, after a new payout delay.
+		assert_ok!(Bounties::dispute_bounty(Origin::signed(6), 1, reason_hash));
+		assert_ok!(Bounties::resolve_dispute(Origin::root(), 1, DisputeVerdict::ReAward(5)));
+		assert_eq!(Balances::free_balance(6), 15);
+		assert_eq!(Bounties::bounties(1).unwrap().status, BountyStatus::PendingPayout {
+			curator: 4,
+			beneficiary: 5,
+			unlock_at: 8,
+		});
+
+		// the curator is unassigned and slashed.
+		assert_ok!(Bounties::dispute_bounty(Origin::signed(6), 1, reason_hash));
+		assert_ok!(Bounties::resolve_dispute(Origin::root(), 1, DisputeVerdict::SlashCurator));
+		assert_eq!(Balances::free_balance(6), 15);
+		assert_eq!(Bounties::bounties(1).unwrap().status, BountyStatus::Funded);
+		assert_eq!(Bounties::curator_slash_appeal(1, 4), Some((1, 10)));
+		assert_eq!(Bounties::curator_record(4).times_slashed, 1);
+		assert!(Bounties::bounty_dispute(1).is_none());
+	});
+}
+
+#[test]
+fn unassigning_a_disputed_curator_drops_the_dispute() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&4, 10);
+		Balances::make_free_balance_be(&6, 20);
+		assert_ok!(Bounties::propose_bounty(Origin::signed(0), 50, b"12345".to_vec()));
+		assert_ok!(Bounties::approve_bounty(Origin::root(), 0, FundingSource::Treasury));
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+		assert_ok!(Bounties::propose_curator(Origin::root(), 0, 4, 4));
+		assert_ok!(Bounties::accept_curator(Origin::signed(4), 0, None));
+		assert_ok!(Bounties::award_bounty(Origin::signed(4), 0, 3, None));
+		assert_ok!(Bounties::dispute_bounty(Origin::signed(6), 0, BlakeTwo256::hash(b"not done")));
+
+		assert_ok!(Bounties::unassign_curator(Origin::root(), 0));
+		assert_eq!(Bounties::bounty_dispute(0), None);
+		assert_eq!(Balances::reserved_balance(6), 0);
+		assert_eq!(Balances::free_balance(6), 20);
+
+		// the next award is not held up by the old dispute.
+		Balances::make_free_balance_be(&5, 10);
+		assert_ok!(Bounties::propose_curator(Origin::root(), 0, 5, 4));
+		assert_ok!(Bounties::accept_curator(Origin::signed(5), 0, None));
+		assert_ok!(Bounties::award_bounty(Origin::signed(5), 0, 3, None));
+		System::set_block_number(5);
+		assert_ok!(Bounties::claim_bounty(Origin::signed(3), 0));
+		assert_eq!(Balances::free_balance(3), 46);
+		assert_ok!(Bounties::do_try_state());
+	});
+}
+
+#[test]
+fn closing_a_once_disputed_bounty_leaves_no_dispute() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&4, 10);
+		Balances::make_free_balance_be(&6, 20);
+		assert_ok!(Bounties::propose_bounty(Origin::signed(0), 50, b"12345".to_vec()));
+		assert_ok!(Bounties::approve_bounty(Origin::root(), 0, FundingSource::Treasury));
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+		assert_ok!(Bounties::propose_curator(Origin::root(), 0, 4, 4));
+		assert_ok!(Bounties::accept_curator(Origin::signed(4), 0, None));
+		assert_ok!(Bounties::award_bounty(Origin::signed(4), 0, 3, None));
+		assert_ok!(Bounties::dispute_bounty(Origin::signed(6), 0, BlakeTwo256::hash(b"not done")));
+
+		// a pending payout cannot be closed before the curator is unassigned.
+		assert_noop!(Bounties::close_bounty(Origin::root(), 0, None), Error::<Test, DefaultInstance>::PendingPayout);
+		assert_ok!(Bounties::unassign_curator(Origin::root(), 0));
+		assert_ok!(Bounties::close_bounty(Origin::root(), 0, None));
+		assert_eq!(Bounties::bounty_dispute(0), None);
+		assert_eq!(Balances::reserved_balance(6), 0);
+		assert_eq!(Balances::free_balance(6), 20);
+		assert_ok!(Bounties::do_try_state());
+	});
+}
+
+#[test]
+fn bounty_finance_payout_split_works() {
+	let finance = BountyFinance::<u64> { value: 50, fee: 4, curator_deposit: 2, bond: 85 };
+	assert_eq!(finance.payout_split(50, None), (4, 46));
+	assert_eq!(finance.payout_split(60, Some(Permill::from_percent(10))), (6, 54));
+	// the fee never exceeds the balance paid out.
+	assert_eq!(finance.payout_split(3, None), (3, 0));
+	assert_eq!(finance.payout_split(0, Some(Permill::from_percent(10))), (0, 0));
+}
+
+#[test]
+fn bounty_finance_deposit_due_works() {
+	let finance = BountyFinance::<u64> { value: 50, fee: 10, curator_deposit: 0, bond: 0 };
+	let half = Permill::from_percent(50);
+	assert_eq!(finance.deposit_due(half, None, None), 5);
+	assert_eq!(finance.deposit_due(half, Some(7), None), 7);
+	assert_eq!(finance.deposit_due(half, None, Some(3)), 3);
+	assert_eq!(finance.deposit_due(half, Some(1), Some(100)), 5);
+	assert_eq!(Bounties::calculate_curator_deposit(&10), finance.deposit_due(
+		BountyCuratorDeposit::get(),
+		CuratorDepositMin::get(),
+		CuratorDepositMax::get(),
+	));
+}
+
+#[test]
+fn bounty_finance_bond_split_works() {
+	let finance = BountyFinance::<u64> { value: 50, fee: 0, curator_deposit: 0, bond: 85 };
+	assert_eq!(finance.bond_split(Permill::one()), (85, 0));
+	assert_eq!(finance.bond_split(Permill::from_percent(20)), (17, 68));
+	assert_eq!(finance.bond_split(Permill::zero()), (0, 85));
+}
+
+#[test]
+fn bounty_finance_is_encoded_in_line() {
+	let bounty = Bounty::<u128, u64, u64> {
+		proposer: 0,
+		finance: BountyFinance { value: 50, fee: 4, curator_deposit: 2, bond: 85 },
+		status: BountyStatus::Funded,
+	};
+	let flat = (0u128, 50u64, 4u64, 2u64, 85u64, BountyStatus::<u128, u64>::Funded);
+	assert_eq!(bounty.encode(), flat.encode());
+}
+
+#[test]
+fn bounty_instances_are_independent() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+
+		assert_ok!(Bounties::propose_bounty(Origin::signed(0), 10, b"12345".to_vec()));
+		assert_ok!(Bounties1::propose_bounty(Origin::signed(1), 20, b"12345".to_vec()));
+
+		// each track counts its own bounties and takes its own deposit.
+		assert_eq!(Bounties::bounty_count(), 1);
+		assert_eq!(Bounties1::bounty_count(), 1);
+		assert_eq!(Balances::reserved_balance(0), 85);
+		assert_eq!(Balances::reserved_balance(1), 15);
+		assert_eq!(Bounties::bounties(0).unwrap().finance.value, 10);
+		assert_eq!(Bounties1::bounties(0).unwrap().finance.value, 20);
+		assert_eq!(
+			last_event(),
+			RawEvent::BountyProposed(0),
+		);
+		assert_eq!(
+			System::events().last().unwrap().event,
+			Event::pallet_bounties_Instance1(RawEvent::BountyProposed(0)),
+		);
+
+		// both are funded from the same treasury, into different accounts.
+		assert_ne!(Bounties::bounty_account_id(0), Bounties1::bounty_account_id(0));
+		assert_ne!(Bounties::curator_slash_account_id(), Bounties1::curator_slash_account_id());
+		assert_ok!(Bounties::approve_bounty(Origin::root(), 0, Default::default()));
+		assert_ok!(Bounties1::approve_bounty(Origin::root(), 0, ()));
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+
+		assert_eq!(Bounties::bounties(0).unwrap().status, BountyStatus::Funded);
+		assert_eq!(Bounties1::bounties(0).unwrap().status, BountyStatus::Funded);
+		assert_eq!(Balances::free_balance(Bounties::bounty_account_id(0)), 10);
+		assert_eq!(Balances::free_balance(Bounties1::bounty_account_id(0)), 20);
+
+		// closing a bounty of one track leaves the other alone.
+		assert_ok!(Bounties1::close_bounty(Origin::root(), 0, None));
+		assert_eq!(Bounties1::bounties(0), None);
+		assert_eq!(Bounties::bounties(0).unwrap().status, BountyStatus::Funded);
+	});
+}
+
+#[test]
+fn bounty_status_transition_matrix() {
+	use BountyStatusKind::*;
+	let kinds = [Proposed, Approved, Funded, CuratorProposed, Active, PendingPayout];
+	let allowed = [
+		(Proposed, Approved),
+		(Proposed, CuratorProposed),
+		(Approved, Funded),
+		(Approved, CuratorProposed),
+		(Funded, CuratorProposed),
+		(CuratorProposed, Active),
+		(CuratorProposed, Funded),
+		(Active, PendingPayout),
+		(Active, Funded),
+		(PendingPayout, Active),
+		(PendingPayout, PendingPayout),
+		(PendingPayout, Funded),
+	];
+	for &from in kinds.iter() {
+		for &to in kinds.iter() {
+			assert_eq!(
+				from.can_transition_to(to),
+				allowed.contains(&(from, to)),
+				"{:?} -> {:?}", from, to,
+			);
+		}
+	}
+	// nothing moves back to before funding.
+	assert!(kinds.iter().all(|kind| !kind.can_transition_to(Proposed)));
+}
+
+#[test]
+fn bounty_status_transition_works() {
+	let mut status = BountyStatus::<u128, u64>::Funded;
+	status.transition(BountyStatus::CuratorProposed { curator: 4 });
+	status.transition(BountyStatus::Active { curator: 4, update_due: 20 });
+	assert_eq!(status, BountyStatus::Active { curator: 4, update_due: 20 });
+}
+
+#[test]
+#[should_panic(expected = "illegal bounty status transition")]
+fn illegal_bounty_status_transition_panics_in_debug() {
+	let mut status = BountyStatus::<u128, u64>::Proposed;
+	status.transition(BountyStatus::PendingPayout { curator: 4, beneficiary: 3, unlock_at: 5 });
+}
+
+#[test]
+fn purge_orphaned_description_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+
+		assert_noop!(Bounties::purge_orphaned_description(Origin::signed(2), 7), Error::<Test, DefaultInstance>::NotOrphaned);
+
+		// descriptions of existing bounties are kept.
+		assert_ok!(Bounties::propose_bounty(Origin::signed(0), 10, b"12345".to_vec()));
+		assert_noop!(Bounties::purge_orphaned_description(Origin::signed(2), 0), Error::<Test, DefaultInstance>::NotOrphaned);
+
+		// an orphan, e.g. left behind by a removal that missed it.
+		BountyDescriptions::<DefaultInstance>::insert(7, b"orphan".to_vec());
+		BountyTags::<DefaultInstance>::insert(7, vec![b"rust".to_vec()]);
+		assert_ok!(Balances::reserve(&1, 3));
+		BountyDescriptionDeposits::<Test, DefaultInstance>::insert(7, (1, 3));
+
+		assert_ok!(Bounties::purge_orphaned_description(Origin::signed(2), 7));
+		assert_eq!(last_event(), RawEvent::OrphanedDescriptionPurged(7, 2, 2));
+		assert_eq!(Bounties::bounty_descriptions(7), None);
+		assert!(Bounties::bounty_tags(7).is_empty());
+		assert_eq!(Bounties::bounty_description_deposit(7), None);
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::free_balance(2), 3);
+		assert_eq!(Treasury::pot(), 98);
+
+		assert_noop!(Bounties::purge_orphaned_description(Origin::signed(2), 7), Error::<Test, DefaultInstance>::NotOrphaned);
+		assert_eq!(Bounties::bounty_descriptions(0), Some(b"12345".to_vec()));
+	});
+}
+
+#[test]
+fn sweep_bounty_account_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&2, 50);
+
+		assert_noop!(Bounties::sweep_bounty_account(Origin::signed(2), 0), Error::<Test, DefaultInstance>::InvalidIndex);
+
+		assert_ok!(Bounties::propose_bounty(Origin::signed(0), 50, b"12345".to_vec()));
+		assert_ok!(Bounties::approve_bounty(Origin::root(), 0, FundingSource::Treasury));
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+		assert_ok!(Bounties::contribute_to_bounty(Origin::signed(2), 0, 5));
+		assert_noop!(
+			Bounties::sweep_bounty_account(Origin::signed(2), 0),
+			Error::<Test, DefaultInstance>::BountyNotCancelled
+		);
+
+		assert_ok!(Bounties::close_bounty(Origin::root(), 0, None));
+		let pot = Treasury::pot();
+		assert_noop!(Bounties::sweep_bounty_account(Origin::signed(2), 0), Error::<Test, DefaultInstance>::NothingToSweep);
+
+		// a transfer made after the bounty was closed is swept, the contribution stays refundable.
+		assert_ok!(Balances::transfer(Origin::signed(1), Bounties::bounty_account_id(0), 3));
+		assert_ok!(Bounties::sweep_bounty_account(Origin::signed(2), 0));
+		assert_eq!(last_event(), RawEvent::BountyAccountSwept(0, 3));
+		assert_eq!(Treasury::pot(), pot + 3);
+		assert_eq!(Balances::free_balance(Bounties::bounty_account_id(0)), 5);
+
+		assert_ok!(Bounties::refund_contribution(Origin::signed(2), 0));
+		assert_eq!(Balances::free_balance(2), 50);
+
+		// with nothing left to refund, the account is emptied.
+		assert_ok!(Balances::transfer(Origin::signed(1), Bounties::bounty_account_id(0), 1));
+		assert_ok!(Bounties::sweep_bounty_account(Origin::signed(2), 0));
+		assert_eq!(last_event(), RawEvent::BountyAccountSwept(0, 1));
+		assert_eq!(Balances::free_balance(Bounties::bounty_account_id(0)), 0);
+		assert_eq!(Treasury::pot(), pot + 4);
+	});
+}
+
+fn scheduled_claim_at(bounty_id: BountyIndex) -> Option<u64> {
+	<Scheduler as ScheduleNamed<u64, Call, OriginCaller>>::next_dispatch_time(
+		Bounties::claim_task_id(bounty_id)
+	).ok()
+}
+
+fn run_scheduler_to(n: u64) {
+	while System::block_number() < n {
+		System::set_block_number(System::block_number() + 1);
+		<Scheduler as OnInitialize<u64>>::on_initialize(System::block_number());
+	}
+}
+
+#[test]
+fn awarded_bounty_is_claimed_automatically() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&4, 10);
+		assert_ok!(Bounties::propose_bounty(Origin::signed(0), 50, b"12345".to_vec()));
+		assert_ok!(Bounties::approve_bounty(Origin::root(), 0, FundingSource::Treasury));
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+		assert_ok!(Bounties::propose_curator(Origin::root(), 0, 4, 4));
+		assert_ok!(Bounties::accept_curator(Origin::signed(4), 0, None));
+
+		assert_ok!(Bounties::award_bounty(Origin::signed(4), 0, 3, None));
+		assert_eq!(scheduled_claim_at(0), Some(4));
+
+		run_scheduler_to(3);
+		assert!(Bounties::bounties(0).is_some());
+		run_scheduler_to(4);
+		assert_eq!(last_event(), RawEvent::BountyClaimed(0, 46, 3, 4, 2));
+		assert_eq!(Balances::free_balance(3), 46);
+		assert_eq!(Bounties::bounties(0), None);
+		assert_eq!(scheduled_claim_at(0), None);
+	});
+}
+
+#[test]
+fn scheduled_claims_follow_the_award() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&4, 10);
+		assert_ok!(Bounties::propose_bounty(Origin::signed(0), 50, b"12345".to_vec()));
+		assert_ok!(Bounties::approve_bounty(Origin::root(), 0, FundingSource::Treasury));
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+		assert_ok!(Bounties::propose_curator(Origin::root(), 0, 4, 4));
+		assert_ok!(Bounties::accept_curator(Origin::signed(4), 0, None));
+
+		// a rejected award is not claimed.
+		assert_ok!(Bounties::award_bounty(Origin::signed(4), 0, 3, None));
+		assert_ok!(Bounties::reject_award(Origin::signed(3), 0));
+		assert_eq!(scheduled_claim_at(0), None);
+
+		// a claim that falls due while the bounty is paused is scheduled again on unpausing.
+		assert_ok!(Bounties::award_bounty(Origin::signed(4), 0, 3, None));
+		assert_ok!(Bounties::pause_bounty(Origin::root(), 0));
+		run_scheduler_to(4);
+		assert!(Bounties::bounties(0).is_some());
+		assert_eq!(scheduled_claim_at(0), None);
+		assert_ok!(Bounties::unpause_bounty(Origin::root(), 0));
+		assert_eq!(scheduled_claim_at(0), Some(5));
+
+		// a claim made by hand drops the scheduled one.
+		assert_ok!(Bounties::claim_bounty(Origin::signed(1), 0));
+		assert_eq!(scheduled_claim_at(0), None);
+		assert_eq!(Balances::free_balance(3), 46);
+	});
+}
+
+#[test]
+fn bounty_metrics_are_kept() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&0, 300);
+		Balances::make_free_balance_be(&4, 10);
+		for _ in 0..3 {
+			assert_ok!(Bounties::propose_bounty(Origin::signed(0), 30, b"12345".to_vec()));
+		}
+		assert_ok!(Bounties::approve_bounty(Origin::root(), 0, FundingSource::Treasury));
+		assert_ok!(Bounties::approve_bounty(Origin::root(), 1, FundingSource::Treasury));
+		// a rejected proposal was never funded.
+		assert_ok!(Bounties::close_bounty(Origin::root(), 2, None));
+
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+		assert_eq!(Bounties::total_bounties_funded(), 2);
+		assert_eq!(Bounties::active_bounty_count(), 2);
+		assert_eq!(Bounties::total_value_paid_out(), 0);
+
+		assert_ok!(Bounties::propose_curator(Origin::root(), 0, 4, 4));
+		assert_ok!(Bounties::accept_curator(Origin::signed(4), 0, None));
+		assert_ok!(Bounties::award_bounty(Origin::signed(4), 0, 3, None));
+		System::set_block_number(5);
+		assert_ok!(Bounties::claim_bounty(Origin::signed(3), 0));
+		assert_eq!(Bounties::active_bounty_count(), 1);
+		assert_eq!(Bounties::total_value_paid_out(), 30);
+
+		assert_ok!(Bounties::close_bounty(Origin::root(), 1, None));
+		assert_eq!(Bounties::total_bounties_funded(), 2);
+		assert_eq!(Bounties::active_bounty_count(), 0);
+		assert_eq!(Bounties::total_value_paid_out(), 30);
+	});
+}
+
+#[test]
+fn veto_bounty_works_at_any_stage() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&0, 400);
+		Balances::make_free_balance_be(&4, 10);
+		Balances::make_free_balance_be(&6, 10);
+		for _ in 0..4 {
+			assert_ok!(Bounties::propose_bounty(Origin::signed(0), 20, b"12345".to_vec()));
+		}
+		assert_ok!(Bounties::approve_bounty(Origin::root(), 2, FundingSource::Treasury));
+		assert_ok!(Bounties::approve_bounty(Origin::root(), 3, FundingSource::Treasury));
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+		assert_ok!(Bounties::approve_bounty(Origin::root(), 1, FundingSource::Treasury));
+		let bond = Bounties::bounties(0).unwrap().finance.bond;
+		assert_eq!(Balances::reserved_balance(0), 2 * bond);
+
+		assert_noop!(
+			Bounties::veto_bounty(Origin::signed(0), 0, None),
+			BadOrigin,
+		);
+
+		// a proposal is dropped, and its bond returned in full.
+		assert_ok!(Bounties::veto_bounty(Origin::root(), 0, None));
+		assert_eq!(last_event(), RawEvent::BountyVetoed(0, 0, None));
+		assert_eq!(Balances::reserved_balance(0), bond);
+		assert_eq!(Bounties::open_proposal_count(0), 0);
+
+		// so is an approved bounty that is not funded yet.
+		assert_ok!(Bounties::veto_bounty(Origin::root(), 1, None));
+		assert_eq!(Balances::reserved_balance(0), 0);
+		assert_eq!(Balances::free_balance(0), 400);
+		assert!(Bounties::bounty_approvals().is_empty());
+		assert_eq!(Bounties::total_active_bounty_value(), 40);
+
+		// an active curator gets their deposit back.
+		assert_ok!(Bounties::propose_curator(Origin::root(), 2, 4, 4));
+		assert_ok!(Bounties::accept_curator(Origin::signed(4), 2, None));
+		let deposit = Balances::reserved_balance(4);
+		let pot = Treasury::pot();
+		assert_ok!(Bounties::veto_bounty(Origin::root(), 2, None));
+		assert_eq!(Balances::reserved_balance(4), 0);
+		assert_eq!(Treasury::pot(), pot + 20);
+		assert_eq!(Bounties::active_bounty_count(), 1);
+
+		// an awarded curator is slashed, even if the award is disputed and the bounty paused.
+		assert_ok!(Bounties::propose_curator(Origin::root(), 3, 4, 4));
+		assert_ok!(Bounties::accept_curator(Origin::signed(4), 3, None));
+		assert_ok!(Bounties::award_bounty(Origin::signed(4), 3, 3, None));
+		assert_noop!(
+			Bounties::close_bounty(Origin::root(), 3, None),
+			Error::<Test, DefaultInstance>::PendingPayout,
+		);
+		assert_ok!(Bounties::dispute_bounty(Origin::signed(6), 3, H256::repeat_byte(1)));
+		assert_ok!(Bounties::pause_bounty(Origin::root(), 3));
+		assert_ok!(Bounties::veto_bounty(Origin::root(), 3, Some(b"fraud".to_vec())));
+		assert_eq!(
+			last_event(),
+			RawEvent::BountyVetoed(3, deposit, Some(BlakeTwo256::hash(b"fraud"))),
+		);
+		assert_eq!(Balances::reserved_balance(4), 0);
+		assert_eq!(Balances::free_balance(4), 10 - deposit);
+		assert_eq!(Bounties::curator_slash_appeal(3, 4).map(|(held, _)| held), Some(deposit));
+		assert_eq!(Balances::reserved_balance(6), 0);
+		assert_eq!(Treasury::pot(), pot + 40);
+		assert_eq!(scheduled_claim_at(3), None);
+		assert!(!Bounties::bounty_paused(3));
+		assert_eq!(Bounties::bounty_count(), 4);
+		assert!(Bounties::all_bounties().is_empty());
+		assert_eq!(Bounties::total_active_bounty_value(), 0);
+		assert_eq!(Bounties::active_bounty_count(), 0);
+		assert_ok!(Bounties::do_try_state());
+	});
+}
+
+#[test]
+fn increase_bounty_value_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&4, 10);
+		assert_ok!(Bounties::propose_bounty(Origin::signed(0), 50, b"12345".to_vec()));
+		assert_noop!(
+			Bounties::increase_bounty_value(Origin::root(), 0, 10),
+			Error::<Test, DefaultInstance>::UnexpectedStatus,
+		);
+		assert_ok!(Bounties::approve_bounty(Origin::root(), 0, FundingSource::Treasury));
+		assert_noop!(
+			Bounties::increase_bounty_value(Origin::root(), 0, 10),
+			Error::<Test, DefaultInstance>::UnexpectedStatus,
+		);
+
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+		assert_ok!(Bounties::propose_curator(Origin::root(), 0, 4, 4));
+		assert_ok!(Bounties::accept_curator(Origin::signed(4), 0, None));
+
+		assert_noop!(Bounties::increase_bounty_value(Origin::signed(0), 0, 10), BadOrigin);
+		assert_noop!(
+			Bounties::increase_bounty_value(Origin::root(), 0, 0),
+			Error::<Test, DefaultInstance>::InvalidValue,
+		);
+		assert_ok!(Bounties::increase_bounty_value(Origin::root(), 0, 10));
+		assert_eq!(last_event(), RawEvent::BountyValueIncreased(0, 10, 60));
+		assert_ok!(Bounties::increase_bounty_value(Origin::root(), 0, 5));
+		assert_eq!(Bounties::bounties(0).unwrap().finance.value, 65);
+		assert_eq!(Bounties::total_active_bounty_value(), 65);
+		assert_eq!(Bounties::bounty_value_increase(0), Some(15));
+		assert_eq!(Bounties::bounty_approvals(), vec![0]);
+		assert_ok!(Bounties::do_try_state());
+
+		System::set_block_number(4);
+		<Treasury as OnInitialize<u64>>::on_initialize(4);
+		assert_eq!(Balances::free_balance(Bounties::bounty_account_id(0)), 65);
+		assert!(System::events().iter().any(|r|
+			r.event == Event::pallet_bounties(RawEvent::BountyValueIncreaseFunded(0, 15))
+		));
+		assert_eq!(Bounties::bounty_value_increase(0), None);
+		assert!(Bounties::bounty_approvals().is_empty());
+		// an increase is not a funding of the bounty.
+		assert_eq!(Bounties::total_bounties_funded(), 1);
+
+		// an increase still to be funded goes with the bounty.
+		assert_ok!(Bounties::increase_bounty_value(Origin::root(), 0, 5));
+		assert_ok!(Bounties::close_bounty(Origin::root(), 0, None));
+		assert_eq!(Bounties::bounty_value_increase(0), None);
+		assert!(Bounties::bounty_approvals().is_empty());
+		assert_eq!(Bounties::total_active_bounty_value(), 0);
+		assert_ok!(Bounties::do_try_state());
+	});
+}
+
+#[test]
+fn accept_curator_with_sponsor_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&5, 10);
+		assert_ok!(Bounties::propose_bounty(Origin::signed(0), 50, b"12345".to_vec()));
+		assert_noop!(
+			Bounties::sponsor_curator(Origin::signed(5), 0, 4, 2),
+			Error::<Test, DefaultInstance>::UnexpectedStatus,
+		);
+		assert_ok!(Bounties::approve_bounty(Origin::root(), 0, FundingSource::Treasury));
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+		assert_ok!(Bounties::propose_curator(Origin::root(), 0, 4, 4));
+
+		assert_noop!(
+			Bounties::accept_curator_with_sponsor(Origin::signed(4), 0, 5),
+			Error::<Test, DefaultInstance>::NoSponsorship,
+		);
+		assert_ok!(Bounties::sponsor_curator(Origin::signed(6), 0, 3, 10));
+		assert_noop!(
+			Bounties::accept_curator_with_sponsor(Origin::signed(4), 0, 6),
+			Error::<Test, DefaultInstance>::NoSponsorship,
+		);
+		assert_ok!(Bounties::withdraw_curator_sponsorship(Origin::signed(6), 0));
+		assert_eq!(last_event(), RawEvent::CuratorSponsorshipSet(0, 6, None));
+		assert_noop!(
+			Bounties::withdraw_curator_sponsorship(Origin::signed(6), 0),
+			Error::<Test, DefaultInstance>::NoSponsorship,
+		);
+
+		// only `MaxCuratorSponsors` accounts may sponsor a bounty at once.
+		assert_ok!(Bounties::sponsor_curator(Origin::signed(6), 0, 3, 10));
+		assert_ok!(Bounties::sponsor_curator(Origin::signed(7), 0, 3, 10));
+		assert_ok!(Bounties::sponsor_curator(Origin::signed(6), 0, 4, 10));
+		assert_eq!(Bounties::curator_sponsorship_count(0), 2);
+		assert_noop!(
+			Bounties::sponsor_curator(Origin::signed(5), 0, 4, 2),
+			Error::<Test, DefaultInstance>::TooManySponsors,
+		);
+		assert_ok!(Bounties::withdraw_curator_sponsorship(Origin::signed(6), 0));
+		assert_ok!(Bounties::withdraw_curator_sponsorship(Origin::signed(7), 0));
+		assert_eq!(Bounties::curator_sponsorship_count(0), 0);
+
+		assert_ok!(Bounties::sponsor_curator(Origin::signed(5), 0, 4, 1));
+		assert_noop!(
+			Bounties::accept_curator_with_sponsor(Origin::signed(4), 0, 5),
+			Error::<Test, DefaultInstance>::DepositTooHigh,
+		);
+		assert_ok!(Bounties::sponsor_curator(Origin::signed(5), 0, 4, 2));
+		assert_eq!(last_event(), RawEvent::CuratorSponsorshipSet(0, 5, Some((4, 2))));
+		assert_ok!(Bounties::accept_curator_with_sponsor(Origin::signed(4), 0, 5));
+		assert_eq!(last_event(), RawEvent::CuratorDepositSponsored(0, 5, 2));
+		assert_eq!(Balances::reserved_balance(5), 2);
+		assert_eq!(Balances::reserved_balance(4), 0);
+		assert_eq!(Bounties::bounty_curator_sponsor(0), Some(5));
+		assert_eq!(Bounties::curator_sponsorship(0, 5), None);
+
+		// a slash is taken from the sponsor, and restored to them on appeal.
+		assert_ok!(Bounties::unassign_curator(Origin::root(), 0));
+		assert_eq!(Balances::total_balance(&5), 8);
+		assert_eq!(Bounties::bounty_curator_sponsor(0), None);
+		assert_eq!(Bounties::slashed_curator_sponsor(0, 4), Some(5));
+		assert_ok!(Bounties::propose_curator(Origin::root(), 0, 4, 4));
+		assert_noop!(
+			Bounties::accept_curator(Origin::signed(4), 0, None),
+			Error::<Test, DefaultInstance>::HeldSlashOfOtherDepositor,
+		);
+		assert_ok!(Bounties::restore_curator_slash(Origin::root(), 0, 4));
+		assert_eq!(Balances::free_balance(5), 10);
+		assert_eq!(Bounties::slashed_curator_sponsor(0, 4), None);
+
+		// the deposit is returned to the sponsor once the bounty is claimed.
+		assert_ok!(Bounties::sponsor_curator(Origin::signed(5), 0, 4, 2));
+		assert_ok!(Bounties::accept_curator_with_sponsor(Origin::signed(4), 0, 5));
+		assert_ok!(Bounties::award_bounty(Origin::signed(4), 0, 3, None));
+		System::set_block_number(5);
+		assert_ok!(Bounties::claim_bounty(Origin::signed(3), 0));
+		assert_eq!(last_event(), RawEvent::BountyClaimed(0, 46, 3, 4, 2));
+		assert_eq!(Balances::free_balance(5), 10);
+		assert_eq!(Balances::reserved_balance(5), 0);
+		assert_eq!(Balances::free_balance(4), 4);
+		assert_eq!(Bounties::bounty_curator_sponsor(0), None);
+		assert_ok!(Bounties::do_try_state());
+	});
+}
+
+#[test]
+fn clone_bounty_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&0, 200);
+		Balances::make_free_balance_be(&1, 300);
+		assert_noop!(Bounties::clone_bounty(Origin::signed(1), 0), Error::<Test, DefaultInstance>::InvalidIndex);
+		assert_ok!(Bounties::propose_bounty(Origin::signed(0), 50, b"12345".to_vec()));
+		let bond = Bounties::bounties(0).unwrap().finance.bond;
+
+		assert_ok!(Bounties::clone_bounty(Origin::signed(1), 0));
+		assert_eq!(last_event(), RawEvent::BountyCloned(1, 0));
+		let clone = Bounties::bounties(1).unwrap();
+		assert_eq!(clone.proposer, 1);
+		assert_eq!(clone.status, BountyStatus::Proposed);
+		assert_eq!(clone.finance.value, 50);
+		assert_eq!(clone.finance.bond, bond);
+		assert_eq!(Balances::reserved_balance(1), bond);
+		assert_eq!(Bounties::bounty_descriptions(1), Some(b"12345".to_vec()));
+
+		// the outline of a bounty is only kept once it is gone if a deposit was made for it.
+		assert_ok!(Bounties::close_bounty(Origin::root(), 1, None));
+		assert_eq!(Bounties::bounty_outline(1), None);
+		assert_noop!(Bounties::clone_bounty(Origin::signed(1), 1), Error::<Test, DefaultInstance>::InvalidIndex);
+
+		// the value and the description hash, 40 bytes.
+		assert_ok!(Bounties::keep_bounty_outline(Origin::signed(1), 0));
+		assert_eq!(last_event(), RawEvent::BountyOutlineKept(0, 1, 40));
+		assert_eq!(Balances::reserved_balance(1), 40);
+		assert_noop!(
+			Bounties::keep_bounty_outline(Origin::signed(2), 0),
+			Error::<Test, DefaultInstance>::OutlineAlreadyKept,
+		);
+
+		// once the bounty is gone, only the hash of its description is left to copy.
+		assert_ok!(Bounties::close_bounty(Origin::root(), 0, None));
+		assert_eq!(Bounties::bounty_outline(0), Some((50, BlakeTwo256::hash(b"12345"))));
+		assert_ok!(Bounties::clone_bounty(Origin::signed(1), 0));
+		assert_eq!(last_event(), RawEvent::BountyCloned(2, 0));
+		assert_eq!(Bounties::bounties(2).unwrap().finance.value, 50);
+		assert_eq!(Bounties::bounty_descriptions(2), None);
+		assert_eq!(Bounties::bounty_description_hash(2), Some(BlakeTwo256::hash(b"12345")));
+		assert_ok!(Bounties::note_bounty_description(Origin::signed(1), 2, b"12345".to_vec()));
+		assert_ok!(Bounties::do_try_state());
+
+		// only the depositor can stop keeping it.
+		assert_noop!(
+			Bounties::forget_bounty_outline(Origin::signed(0), 0),
+			Error::<Test, DefaultInstance>::NoOutlineDeposit,
+		);
+		let reserved = Balances::reserved_balance(1);
+		assert_ok!(Bounties::forget_bounty_outline(Origin::signed(1), 0));
+		assert_eq!(last_event(), RawEvent::BountyOutlineForgotten(0));
+		assert_eq!(Balances::reserved_balance(1), reserved - 40);
+		assert_eq!(Bounties::bounty_outline(0), None);
+		assert_eq!(Bounties::bounty_outline_deposit(0), None);
+		assert_noop!(Bounties::clone_bounty(Origin::signed(1), 0), Error::<Test, DefaultInstance>::InvalidIndex);
+	});
+}
+
+#[test]
+fn closing_a_bounty_never_funded_leaves_metrics_alone() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&0, 300);
+		for _ in 0..3 {
+			assert_ok!(Bounties::propose_bounty(Origin::signed(0), 20, b"12345".to_vec()));
+		}
+		assert_ok!(Bounties::approve_bounty(Origin::root(), 0, FundingSource::Treasury));
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+		assert_eq!(Bounties::active_bounty_count(), 1);
+		assert_eq!(Bounties::total_active_bounty_value(), 20);
+		let bond = Bounties::bounties(1).unwrap().finance.bond;
+
+		// a curator proposed for a bounty that is not even approved.
+		assert_ok!(Bounties::propose_curator(Origin::root(), 1, 4, 4));
+		assert_ok!(Bounties::close_bounty(Origin::root(), 1, None));
+		assert_eq!(last_event(), RawEvent::BountyRejected(1, bond, 0, None));
+		assert!(Bounties::bounties_by_curator(4).is_empty());
+
+		// and one unassigned again.
+		assert_ok!(Bounties::propose_curator(Origin::root(), 2, 4, 4));
+		assert_ok!(Bounties::unassign_curator(Origin::root(), 2));
+		assert_ok!(Bounties::close_bounty(Origin::root(), 2, None));
+
+		assert_eq!(Balances::reserved_balance(0), 0);
+		assert_eq!(Bounties::open_proposal_count(0), 0);
+		assert_eq!(Bounties::active_bounty_count(), 1);
+		assert_eq!(Bounties::total_active_bounty_value(), 20);
+		assert_ok!(Bounties::do_try_state());
+	});
+}
+
+#[test]
+fn vetoing_a_bounty_never_funded_returns_the_bond() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&0, 300);
+		for _ in 0..3 {
+			assert_ok!(Bounties::propose_bounty(Origin::signed(0), 20, b"12345".to_vec()));
+		}
+		assert_ok!(Bounties::approve_bounty(Origin::root(), 0, FundingSource::Treasury));
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+		let free = Balances::free_balance(0);
+		let bond = Bounties::bounties(1).unwrap().finance.bond;
+
+		// a curator proposed for a bounty that is not even approved.
+		assert_ok!(Bounties::propose_curator(Origin::root(), 1, 4, 4));
+		assert_ok!(Bounties::veto_bounty(Origin::root(), 1, None));
+		assert_eq!(Balances::free_balance(0), free + bond);
+		assert!(Bounties::bounties_by_curator(4).is_empty());
+
+		// and one unassigned again.
+		assert_ok!(Bounties::propose_curator(Origin::root(), 2, 4, 4));
+		assert_ok!(Bounties::unassign_curator(Origin::root(), 2));
+		assert_ok!(Bounties::veto_bounty(Origin::root(), 2, None));
+		assert_eq!(Balances::free_balance(0), free + 2 * bond);
+
+		assert_eq!(Balances::reserved_balance(0), 0);
+		assert_eq!(Bounties::open_proposal_count(0), 0);
+		assert_eq!(Bounties::active_bounty_count(), 1);
+		assert_eq!(Bounties::total_active_bounty_value(), 20);
+		assert_ok!(Bounties::do_try_state());
+	});
+}
+
+#[test]
+fn funding_sources_are_recorded_for_bounties_approved_before_the_upgrade() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&0, 400);
+		Balances::make_free_balance_be(&4, 10);
+		for _ in 0..4 {
+			assert_ok!(Bounties::propose_bounty(Origin::signed(0), 20, b"12345".to_vec()));
+		}
+		assert_ok!(Bounties::approve_bounty(Origin::root(), 0, FundingSource::Treasury));
+		assert_ok!(Bounties::approve_bounty(Origin::root(), 1, FundingSource::Treasury));
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+		assert_ok!(Bounties::propose_curator(Origin::root(), 0, 4, 4));
+		assert_ok!(Bounties::accept_curator(Origin::signed(4), 0, None));
+		// approved, but not funded yet.
+		assert_ok!(Bounties::approve_bounty(Origin::root(), 2, FundingSource::Treasury));
+		assert_ok!(Bounties::propose_curator(Origin::root(), 2, 5, 4));
+		// never approved.
+		assert_ok!(Bounties::propose_curator(Origin::root(), 3, 5, 4));
+
+		// as left by a runtime from before funding sources were recorded.
+		for bounty_id in 0..4 {
+			BountyFundingSources::<Test, DefaultInstance>::remove(bounty_id);
+		}
+
+		<Bounties as OnRuntimeUpgrade>::on_runtime_upgrade();
+		for bounty_id in 0..3 {
+			assert!(BountyFundingSources::<Test, DefaultInstance>::contains_key(bounty_id));
+		}
+		assert!(!BountyFundingSources::<Test, DefaultInstance>::contains_key(3));
+		// only migrated once.
+		BountyFundingSources::<Test, DefaultInstance>::remove(2);
+		<Bounties as OnRuntimeUpgrade>::on_runtime_upgrade();
+		assert!(!BountyFundingSources::<Test, DefaultInstance>::contains_key(2));
+		BountyFundingSources::<Test, DefaultInstance>::insert(2, FundingSource::Treasury);
+
+		// the funded bounties are closed as such, leaving the proposer's bonds alone.
+		let reserved = Balances::reserved_balance(0);
+		assert_ok!(Bounties::close_bounty(Origin::root(), 1, None));
+		assert_eq!(last_event(), RawEvent::BountyCanceled(1, None));
+		assert_ok!(Bounties::veto_bounty(Origin::root(), 0, None));
+		assert_eq!(Balances::reserved_balance(0), reserved);
+		assert_eq!(Balances::free_balance(4), 10);
+		assert_eq!(Bounties::active_bounty_count(), 0);
+		assert_ok!(Bounties::do_try_state());
+	});
+}
+
+#[test]
+fn vetoing_a_bounty_funded_before_the_upgrade_closes_it_as_funded() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&0, 300);
+		Balances::make_free_balance_be(&4, 10);
+		for _ in 0..2 {
+			assert_ok!(Bounties::propose_bounty(Origin::signed(0), 20, b"12345".to_vec()));
+		}
+		assert_ok!(Bounties::approve_bounty(Origin::root(), 0, FundingSource::Treasury));
+		assert_ok!(Bounties::approve_bounty(Origin::root(), 1, FundingSource::Treasury));
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+		for bounty_id in 0..2 {
+			assert_ok!(Bounties::propose_curator(Origin::root(), bounty_id, 4, 4));
+			assert_ok!(Bounties::accept_curator(Origin::signed(4), bounty_id, None));
+		}
+		assert_ok!(Bounties::award_bounty(Origin::signed(4), 1, 3, None));
+		let deposit = Balances::reserved_balance(4) / 2;
+
+		// as left by a runtime from before funding sources were recorded, not migrated yet.
+		for bounty_id in 0..2 {
+			BountyFundingSources::<Test, DefaultInstance>::remove(bounty_id);
+		}
+
+		// the status of an active or awarded bounty tells it was funded.
+		let pot = Treasury::pot();
+		assert_ok!(Bounties::veto_bounty(Origin::root(), 0, None));
+		assert_eq!(Balances::reserved_balance(4), deposit);
+		assert_ok!(Bounties::veto_bounty(Origin::root(), 1, None));
+		assert_eq!(last_event(), RawEvent::BountyVetoed(1, deposit, None));
+		assert_eq!(Balances::reserved_balance(4), 0);
+		assert_eq!(Balances::reserved_balance(0), 0);
+		assert_eq!(Balances::free_balance(0), 300);
+		assert_eq!(Treasury::pot(), pot + 40);
+		assert_eq!(Balances::free_balance(Bounties::bounty_account_id(0)), 0);
+		assert_eq!(Balances::free_balance(Bounties::bounty_account_id(1)), 0);
+		assert_eq!(Bounties::active_bounty_count(), 0);
+		assert_eq!(Bounties::total_active_bounty_value(), 0);
+	});
+}
+
+#[test]
+fn try_state_holds_through_bounty_lifecycle() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&4, 10);
+		assert_ok!(Bounties::do_try_state());
+
+		assert_ok!(Bounties::propose_bounty(Origin::signed(0), 50, b"12345".to_vec()));
+		assert_ok!(Bounties::do_try_state());
+		assert_ok!(Bounties::approve_bounty(Origin::root(), 0, FundingSource::Treasury));
+		assert_ok!(Bounties::do_try_state());
+
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+		assert_ok!(Bounties::do_try_state());
+
+		assert_ok!(Bounties::propose_curator(Origin::root(), 0, 4, 4));
+		assert_ok!(Bounties::do_try_state());
+		assert_ok!(Bounties::accept_curator(Origin::signed(4), 0, None));
+		assert_ok!(Bounties::do_try_state());
+		assert_ok!(Bounties::award_bounty(Origin::signed(4), 0, 3, None));
+		assert_ok!(Bounties::do_try_state());
+
+		System::set_block_number(5);
+		assert_ok!(Bounties::claim_bounty(Origin::signed(1), 0));
+		assert_ok!(Bounties::do_try_state());
+
+		// a curator proposed before approval, then the bounty closed.
+		assert_ok!(Bounties::propose_bounty(Origin::signed(1), 10, b"12345".to_vec()));
+		assert_ok!(Bounties::propose_curator(Origin::root(), 1, 4, 1));
+		assert_ok!(Bounties::do_try_state());
+		assert_ok!(Bounties::close_bounty(Origin::root(), 1, None));
+		assert_ok!(Bounties::do_try_state());
+	});
+}
+
+#[test]
+fn try_state_detects_broken_invariants() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&4, 10);
+		assert_ok!(Bounties::propose_bounty(Origin::signed(0), 50, b"12345".to_vec()));
+		assert_ok!(Bounties::approve_bounty(Origin::root(), 0, FundingSource::Treasury));
+
+		BountyApprovals::<DefaultInstance>::put(vec![0, 1]);
+		assert_eq!(
+			Bounties::do_try_state(),
+			Err("approval of a bounty that is neither approved nor curator-proposed"),
+		);
+		BountyApprovals::<DefaultInstance>::kill();
+		assert_eq!(Bounties::do_try_state(), Err("approved bounty missing from approvals"));
+		BountyApprovals::<DefaultInstance>::put(vec![0]);
+
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+		assert_ok!(Bounties::propose_curator(Origin::root(), 0, 4, 4));
+		assert_ok!(Bounties::do_try_state());
+
+		BountiesByCurator::<Test, DefaultInstance>::remove(4);
+		assert_eq!(Bounties::do_try_state(), Err("bounty missing from the bounties of its curator"));
+		BountiesByCurator::<Test, DefaultInstance>::insert(4, vec![0, 1]);
+		assert_eq!(Bounties::do_try_state(), Err("bounty indexed under an account that does not curate it"));
+		BountiesByCurator::<Test, DefaultInstance>::insert(4, vec![0]);
+
+		assert_ok!(Bounties::accept_curator(Origin::signed(4), 0, None));
+		Balances::make_free_balance_be(&Bounties::bounty_account_id(0), 49);
+		assert_eq!(Bounties::do_try_state(), Err("funded bounty account holds less than the bounty value"));
+		Balances::make_free_balance_be(&Bounties::bounty_account_id(0), 50);
+		assert_ok!(Bounties::do_try_state());
 	});
 }
@@ -24,6 +24,7 @@ use frame_system::EventRecord;
 use frame_benchmarking::{benchmarks_instance, account, whitelisted_caller};
 use sp_runtime::traits::Bounded;
 use sp_std::mem::size_of;
+use frame_support::traits::UnfilteredDispatchable;
 
 use frame_system::Call as SystemCall;
 use frame_system::Module as System;
@@ -632,6 +633,206 @@ benchmarks_instance! {
 		assert_eq!(Collective::<T, _>::proposals().len(), (p - 1) as usize);
 		assert_last_event::<T, I>(RawEvent::Disapproved(last_hash).into());
 	}
+
+	ban_member {
+		let m in 2 .. T::MaxMembers::get();
+		let p in 1 .. T::MaxProposals::get();
+
+		// Construct `members`.
+		let mut members = vec![];
+		for i in 0 .. m - 1 {
+			let member = account("member", i, SEED);
+			members.push(member);
+		}
+		let caller: T::AccountId = account("caller", 0, SEED);
+		members.push(caller.clone());
+		Collective::<T, _>::set_members(
+			SystemOrigin::Root.into(),
+			members.clone(),
+			Some(caller.clone()),
+			T::MaxMembers::get(),
+		)?;
+
+		// The banned member votes on every proposal so that all of them have to be pruned.
+		let banned = members[0].clone();
+		// Set a high threshold for proposals passing so that they stay around.
+		let threshold = m.max(2);
+		// Length of the proposals should be irrelevant to `ban_member`.
+		let length = 100;
+		for i in 0 .. p {
+			// Proposals should be different so that different proposal hashes are generated
+			let proposal: T::Proposal = SystemCall::<T>::remark(vec![i as u8; length]).into();
+			Collective::<T, _>::propose(
+				SystemOrigin::Signed(caller.clone()).into(),
+				threshold,
+				Box::new(proposal.clone()),
+				MAX_BYTES,
+			)?;
+			let hash = T::Hashing::hash_of(&proposal);
+			Collective::<T, _>::vote(
+				SystemOrigin::Signed(banned.clone()).into(),
+				hash,
+				i,
+				true,
+			)?;
+		}
+		let until = T::BlockNumber::max_value();
+		let origin = T::BanOrigin::successful_origin();
+		let call = Call::<T, I>::ban_member(banned.clone(), until);
+
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert!(!Collective::<T, _>::members().contains(&banned));
+		assert_eq!(Collective::<T, _>::bans(&banned), Some(until));
+		assert_last_event::<T, I>(RawEvent::MemberBanned(banned, until).into());
+	}
+
+	on_initialize_vote_cleanup {
+		let p in 1 .. T::MaxProposals::get();
+
+		// Construct `members`.
+		let m = T::MaxMembers::get();
+		let mut members = vec![];
+		for i in 0 .. m {
+			let member: T::AccountId = account("member", i, SEED);
+			members.push(member);
+		}
+		let proposer = members[m as usize - 1].clone();
+		Collective::<T, _>::set_members(
+			SystemOrigin::Root.into(),
+			members.clone(),
+			None,
+			T::MaxMembers::get(),
+		)?;
+
+		// Every member but the proposer votes on every proposal, so that all of them have votes
+		// to remove.
+		let threshold = m;
+		let length = 100;
+		for i in 0 .. p {
+			let proposal: T::Proposal = SystemCall::<T>::remark(vec![i as u8; length]).into();
+			Collective::<T, _>::propose(
+				SystemOrigin::Signed(proposer.clone()).into(),
+				threshold,
+				Box::new(proposal.clone()),
+				MAX_BYTES,
+			)?;
+			let hash = T::Hashing::hash_of(&proposal);
+			for voter in members.iter().take(m as usize - 2) {
+				Collective::<T, _>::vote(SystemOrigin::Signed(voter.clone()).into(), hash, i, false)?;
+			}
+		}
+
+		// Stage the cleanup as a membership change leaving only the proposer would.
+		let mut outgoing = members[.. m as usize - 1].to_vec();
+		outgoing.sort();
+		OutgoingVoters::<T, I>::put(outgoing);
+		VoteCleanupQueue::<T, I>::put(Collective::<T, _>::proposals());
+	}: {
+		Collective::<T, _>::clean_up_votes(p);
+	}
+	verify {
+		assert!(Collective::<T, _>::vote_cleanup_queue().is_empty());
+		assert!(Collective::<T, _>::outgoing_voters().is_empty());
+	}
+
+	propose_with_origin {
+		let b in 1 .. MAX_BYTES;
+		let m in 2 .. T::MaxMembers::get();
+		let p in 1 .. T::MaxProposals::get();
+
+		let bytes_in_storage = b + size_of::<u32>() as u32;
+
+		// Construct `members`.
+		let mut members = vec![];
+		for i in 0 .. m - 1 {
+			let member = account("member", i, SEED);
+			members.push(member);
+		}
+		let caller: T::AccountId = whitelisted_caller();
+		members.push(caller.clone());
+		Collective::<T, _>::set_members(SystemOrigin::Root.into(), members, None, T::MaxMembers::get())?;
+
+		let threshold = m;
+		// Add previous proposals.
+		for i in 0 .. p - 1 {
+			// Proposals should be different so that different proposal hashes are generated
+			let proposal: T::Proposal = SystemCall::<T>::remark(vec![i as u8; b as usize]).into();
+			Collective::<T, _>::propose(
+				SystemOrigin::Signed(caller.clone()).into(),
+				threshold,
+				Box::new(proposal),
+				bytes_in_storage,
+			)?;
+		}
+
+		assert_eq!(Collective::<T, _>::proposals().len(), (p - 1) as usize);
+
+		// Root is checked against `RootCapableOrigin`, so it is the slowest origin to propose with
+		// where it is allowed.
+		let members_origin = RawOrigin::Members(threshold, m).into();
+		let motion_origin = if T::RootCapableOrigin::try_origin(members_origin).is_ok() {
+			MotionOrigin::Root
+		} else {
+			MotionOrigin::Collective
+		};
+		let proposal: T::Proposal = SystemCall::<T>::remark(vec![p as u8; b as usize]).into();
+
+	}: _(SystemOrigin::Signed(caller.clone()), threshold, Box::new(proposal.clone()), motion_origin, bytes_in_storage)
+	verify {
+		// New proposal is recorded, with its origin
+		assert_eq!(Collective::<T, _>::proposals().len(), p as usize);
+		let proposal_hash = T::Hashing::hash_of(&proposal);
+		assert_eq!(Collective::<T, _>::motion_origin(proposal_hash), Some(motion_origin));
+		assert_last_event::<T, I>(RawEvent::Proposed(caller, p - 1, proposal_hash, threshold).into());
+	}
+
+	propose_emergency {
+		let b in 1 .. MAX_BYTES;
+		let m in 2 .. T::MaxMembers::get();
+		let p in 1 .. T::MaxProposals::get();
+
+		let bytes_in_storage = b + size_of::<u32>() as u32;
+
+		// Construct `members`, with the caller as prime.
+		let mut members = vec![];
+		for i in 0 .. m - 1 {
+			let member = account("member", i, SEED);
+			members.push(member);
+		}
+		let caller: T::AccountId = whitelisted_caller();
+		members.push(caller.clone());
+		Collective::<T, _>::set_members(
+			SystemOrigin::Root.into(),
+			members,
+			Some(caller.clone()),
+			T::MaxMembers::get(),
+		)?;
+
+		let threshold = m;
+		// Add previous proposals.
+		for i in 0 .. p - 1 {
+			// Proposals should be different so that different proposal hashes are generated
+			let proposal: T::Proposal = SystemCall::<T>::remark(vec![i as u8; b as usize]).into();
+			Collective::<T, _>::propose(
+				SystemOrigin::Signed(caller.clone()).into(),
+				threshold,
+				Box::new(proposal),
+				bytes_in_storage,
+			)?;
+		}
+
+		assert_eq!(Collective::<T, _>::proposals().len(), (p - 1) as usize);
+
+		let proposal: T::Proposal = SystemCall::<T>::remark(vec![p as u8; b as usize]).into();
+
+	}: _(SystemOrigin::Signed(caller.clone()), threshold, Box::new(proposal.clone()), bytes_in_storage)
+	verify {
+		// New proposal is recorded
+		assert_eq!(Collective::<T, _>::proposals().len(), p as usize);
+		let proposal_hash = T::Hashing::hash_of(&proposal);
+		assert_last_event::<T, I>(RawEvent::EmergencyProposed(caller, p - 1, proposal_hash, threshold).into());
+	}
 }
 
 #[cfg(test)]
@@ -709,4 +910,32 @@ mod tests {
 			assert_ok!(test_benchmark_disapprove_proposal::<Test>());
 		});
 	}
+
+	#[test]
+	fn ban_member() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(test_benchmark_ban_member::<Test>());
+		});
+	}
+
+	#[test]
+	fn on_initialize_vote_cleanup() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(test_benchmark_on_initialize_vote_cleanup::<Test>());
+		});
+	}
+
+	#[test]
+	fn propose_with_origin() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(test_benchmark_propose_with_origin::<Test>());
+		});
+	}
+
+	#[test]
+	fn propose_emergency() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(test_benchmark_propose_emergency::<Test>());
+		});
+	}
 }
@@ -38,6 +38,29 @@
 //! abstentions and the proposal is executed if there are enough approvals counting the new votes.
 //!
 //! If there are not, or if no prime is set, then the motion is dropped without being executed.
+//!
+//! The prime member may also open an emergency motion through `propose_emergency`. It is voted on
+//! like any other motion but closes after the shorter `EmergencyMotionDuration`.
+//!
+//! A member may choose the origin a proposal is dispatched with through `propose_with_origin`:
+//! the usual `RawOrigin::Members` origin carrying the threshold of the motion, the plain origin of
+//! a single-member proposal, or Root if `RootCapableOrigin` accepts the motion's origin. This lets
+//! one instance serve proposals of differing privilege.
+//!
+//! The `BanOrigin` may remove a member through `ban_member`, dropping their votes on open motions.
+//! The banned account cannot be made a member again through `set_members` until the given block.
+//!
+//! The votes of outgoing members are removed from at most `MaxVoteCleanupsPerBlock` open motions
+//! when the membership changes, and from the rest in `on_initialize` of the following blocks, so
+//! that large committees with many motions can be rotated. Until then, `vote` and `close` ignore
+//! them.
+//!
+//! A sub-committee drawn from another collective instance, e.g. from the council, can share its
+//! membership source through `SharedMembership` and `SubCommittee`, which keep every member of the
+//! sub-committee a member of the parent instance.
+//!
+//! Other pallets can read the membership of an instance through `CollectiveInfo`, e.g. to gate
+//! features on membership of the council.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![recursion_limit = "128"]
@@ -66,7 +89,14 @@ mod benchmarking;
 pub mod weights;
 pub use weights::WeightInfo;
 
+#[cfg(any(test, feature = "test-utils"))]
+pub mod test_utils;
+
 /// Simple index type for proposal counting.
+///
+/// Each motion is given the next index when it is proposed, and indices are never reused: a
+/// proposal that is proposed again after its motion closed gets a new index. Once all indices are
+/// used up, no more motions can be proposed. Proposals executed right away get no index.
 pub type ProposalIndex = u32;
 
 /// A number of members.
@@ -121,9 +151,64 @@ impl DefaultVote for MoreThanMajorityThenPrimeDefaultVote {
 	}
 }
 
+/// Handler for the voting activity of a collective.
+///
+/// Allows companion pallets to mirror the motions of this collective (e.g. for reputation
+/// tracking or shadow referenda) without having to scan the emitted events.
+pub trait VoteHandler<AccountId, Hash> {
+	/// A member has cast (or changed) their vote on a motion. `yes_votes` and `no_votes` are the
+	/// tally of the motion after the vote was counted.
+	fn on_vote(
+		proposal_hash: &Hash,
+		who: &AccountId,
+		approve: bool,
+		yes_votes: MemberCount,
+		no_votes: MemberCount,
+	);
+
+	/// A motion has been closed with the given final tally. `approved` is whether it passed.
+	fn on_close(
+		proposal_hash: &Hash,
+		approved: bool,
+		yes_votes: MemberCount,
+		no_votes: MemberCount,
+	);
+}
+
+impl<AccountId, Hash> VoteHandler<AccountId, Hash> for () {
+	fn on_vote(_: &Hash, _: &AccountId, _: bool, _: MemberCount, _: MemberCount) {}
+	fn on_close(_: &Hash, _: bool, _: MemberCount, _: MemberCount) {}
+}
+
+/// Read access to the membership of a collective.
+///
+/// Lets other pallets gate features on membership of a collective instance, e.g. deposit-free
+/// reports for council members, without depending on its storage.
+pub trait CollectiveInfo<AccountId> {
+	/// The current members.
+	fn members() -> Vec<AccountId>;
+
+	/// The number of current members.
+	fn member_count() -> MemberCount;
+
+	/// The prime member, if any.
+	fn prime() -> Option<AccountId>;
+
+	/// Whether `who` is a current member.
+	fn is_member(who: &AccountId) -> bool;
+}
+
+/// A collective without members.
+impl<AccountId> CollectiveInfo<AccountId> for () {
+	fn members() -> Vec<AccountId> { Vec::new() }
+	fn member_count() -> MemberCount { 0 }
+	fn prime() -> Option<AccountId> { None }
+	fn is_member(_: &AccountId) -> bool { false }
+}
+
 pub trait Config<I: Instance=DefaultInstance>: frame_system::Config {
 	/// The outer origin type.
-	type Origin: From<RawOrigin<Self::AccountId, I>>;
+	type Origin: From<RawOrigin<Self::AccountId, I>> + From<frame_system::RawOrigin<Self::AccountId>>;
 
 	/// The outer call dispatch type.
 	type Proposal: Parameter
@@ -137,6 +222,9 @@ pub trait Config<I: Instance=DefaultInstance>: frame_system::Config {
 	/// The time-out for council motions.
 	type MotionDuration: Get<Self::BlockNumber>;
 
+	/// The time-out for emergency motions opened through `propose_emergency`.
+	type EmergencyMotionDuration: Get<Self::BlockNumber>;
+
 	/// Maximum number of proposals allowed to be active in parallel.
 	type MaxProposals: Get<ProposalIndex>;
 
@@ -150,6 +238,26 @@ pub trait Config<I: Instance=DefaultInstance>: frame_system::Config {
 	/// Default vote strategy of this collective.
 	type DefaultVote: DefaultVote;
 
+	/// Handler notified of every vote and close, e.g. to mirror the collective elsewhere.
+	type VoteHandler: VoteHandler<Self::AccountId, Self::Hash>;
+
+	/// Origin from which a member can be banned from the collective.
+	type BanOrigin: EnsureOrigin<<Self as frame_system::Config>::Origin>;
+
+	/// The origins of passed motions, i.e. `RawOrigin::Members(threshold, members)`, that may
+	/// have their proposal dispatched as Root through `propose_with_origin`.
+	type RootCapableOrigin: EnsureOrigin<<Self as Config<I>>::Origin>;
+
+	/// Whether `change_members_sorted` panics when the accounts it is given are not sorted and
+	/// deduplicated. Otherwise it logs an error and sorts and deduplicates the new members itself.
+	///
+	/// Meant for tests and test networks; `()` disables it.
+	type StrictMemberOrdering: Get<bool>;
+
+	/// The maximum number of open motions whose votes of outgoing members are removed when the
+	/// membership changes, and then in `on_initialize` of each block until all are done.
+	type MaxVoteCleanupsPerBlock: Get<u32>;
+
 	/// Weight information for extrinsics in this pallet.
 	type WeightInfo: WeightInfo;
 }
@@ -183,9 +291,36 @@ pub struct Votes<AccountId, BlockNumber> {
 	end: BlockNumber,
 }
 
+/// The origin a proposal is dispatched with once passed, as chosen through `propose_with_origin`.
+#[derive(PartialEq, Eq, Clone, Copy, Encode, Decode, RuntimeDebug)]
+pub enum MotionOrigin {
+	/// `RawOrigin::Members` with the threshold of the motion, as for `propose`.
+	Members,
+	/// `RawOrigin::Members(1, _)`, the plain origin of a proposal executed by a single member.
+	Collective,
+	/// Root, if `RootCapableOrigin` accepts the `RawOrigin::Members` origin of the motion.
+	Root,
+}
+
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug)]
+/// When a motion was proposed and closed, for tracking how long motions take.
+pub struct MotionLifetime<BlockNumber> {
+	/// The block at which the motion was proposed.
+	pub proposed_at: BlockNumber,
+	/// The block at which the motion was closed, approved or disapproved, if it was.
+	pub closed_at: Option<BlockNumber>,
+}
+
+impl<BlockNumber: sp_runtime::traits::AtLeast32BitUnsigned + Copy> MotionLifetime<BlockNumber> {
+	/// The number of blocks the motion was open for, if it is closed.
+	pub fn duration(&self) -> Option<BlockNumber> {
+		self.closed_at.map(|closed_at| closed_at.saturating_sub(self.proposed_at))
+	}
+}
+
 decl_storage! {
 	trait Store for Module<T: Config<I>, I: Instance=DefaultInstance> as Collective {
-		/// The hashes of the active proposals.
+		/// The hashes of the active proposals, in the order they were proposed.
 		pub Proposals get(fn proposals): Vec<T::Hash>;
 		/// Actual proposal for a given hash, if it's current.
 		pub ProposalOf get(fn proposal_of):
@@ -193,12 +328,28 @@ decl_storage! {
 		/// Votes on a given proposal, if it is ongoing.
 		pub Voting get(fn voting):
 			map hasher(identity) T::Hash => Option<Votes<T::AccountId, T::BlockNumber>>;
-		/// Proposals so far.
+		/// The origin each ongoing proposal is dispatched with once passed, if not
+		/// `MotionOrigin::Members`.
+		pub MotionOrigins get(fn motion_origin): map hasher(identity) T::Hash => Option<MotionOrigin>;
+		/// Proposals so far. The index of the next motion.
 		pub ProposalCount get(fn proposal_count): u32;
+		/// When each motion was proposed and closed, by proposal index. Kept after the motion is
+		/// closed. Proposals executed right away are not motions and have no entry.
+		pub MotionLifetimes get(fn motion_lifetime):
+			map hasher(twox_64_concat) ProposalIndex => Option<MotionLifetime<T::BlockNumber>>;
 		/// The current members of the collective. This is stored sorted (just by value).
 		pub Members get(fn members): Vec<T::AccountId>;
 		/// The prime member that helps determine the default vote behavior in case of absentations.
 		pub Prime get(fn prime): Option<T::AccountId>;
+		/// Accounts banned from the collective, with the block number until which they cannot be
+		/// made members again through `set_members`.
+		pub Bans get(fn bans): map hasher(twox_64_concat) T::AccountId => Option<T::BlockNumber>;
+		/// The former members whose votes are still to be removed from the motions in
+		/// `VoteCleanupQueue`. Sorted.
+		pub OutgoingVoters get(fn outgoing_voters): Vec<T::AccountId>;
+		/// The motions whose votes of `OutgoingVoters` are still to be removed, in the order they
+		/// are cleaned up.
+		pub VoteCleanupQueue get(fn vote_cleanup_queue): Vec<T::Hash>;
 	}
 	add_extra_genesis {
 		config(phantom): sp_std::marker::PhantomData<I>;
@@ -211,11 +362,16 @@ decl_event! {
 	pub enum Event<T, I=DefaultInstance> where
 		<T as frame_system::Config>::Hash,
 		<T as frame_system::Config>::AccountId,
+		<T as frame_system::Config>::BlockNumber,
 	{
 		/// A motion (given hash) has been proposed (by given account) with a threshold (given
 		/// `MemberCount`).
 		/// \[account, proposal_index, proposal_hash, threshold\]
 		Proposed(AccountId, ProposalIndex, Hash, MemberCount),
+		/// An emergency motion (given hash) has been proposed (by given account) with a threshold
+		/// (given `MemberCount`). It closes after `EmergencyMotionDuration`.
+		/// \[account, proposal_index, proposal_hash, threshold\]
+		EmergencyProposed(AccountId, ProposalIndex, Hash, MemberCount),
 		/// A motion (given hash) has been voted on by given account, leaving
 		/// a tally (yes votes and no votes given respectively as `MemberCount`).
 		/// \[account, proposal_hash, voted, yes, no\]
@@ -235,6 +391,14 @@ decl_event! {
 		/// A proposal was closed because its threshold was reached or after its duration was up.
 		/// \[proposal_hash, yes, no\]
 		Closed(Hash, MemberCount, MemberCount),
+		/// A member was removed from the collective and banned from rejoining until the given
+		/// block number.
+		/// \[account, until\]
+		MemberBanned(AccountId, BlockNumber),
+		/// The default vote was counted for the given number of members who did not vote on a
+		/// motion when it was closed.
+		/// \[proposal_hash, count, aye\]
+		DefaultVotesApplied(Hash, MemberCount, bool),
 	}
 }
 
@@ -260,6 +424,14 @@ decl_error! {
 		WrongProposalWeight,
 		/// The given length bound for the proposal was too low.
 		WrongProposalLength,
+		/// Account is not the prime member
+		NotPrime,
+		/// Account is banned from the collective
+		Banned,
+		/// The motion would not pass with an origin that may dispatch as Root
+		RootNotAllowed,
+		/// All proposal indices have been used up
+		ProposalIndicesExhausted,
 	}
 }
 
@@ -281,6 +453,11 @@ decl_module! {
 
 		fn deposit_event() = default;
 
+		fn on_initialize(_n: T::BlockNumber) -> Weight {
+			let cleaned = Self::clean_up_votes(T::MaxVoteCleanupsPerBlock::get());
+			T::WeightInfo::on_initialize_vote_cleanup(cleaned)
+		}
+
 		/// Set the collective's membership.
 		///
 		/// - `new_members`: The new member list. Be nice to the chain and provide it sorted.
@@ -290,6 +467,8 @@ decl_module! {
 		///
 		/// Requires root origin.
 		///
+		/// Fails if one of `new_members` is banned until a block after the current one.
+		///
 		/// NOTE: Does not enforce the expected `MaxMembers` limit on the amount of members, but
 		///       the weight estimations rely on it to estimate dispatchable weight.
 		///
@@ -298,9 +477,11 @@ decl_module! {
 		/// - `O(MP + N)` where:
 		///   - `M` old-members-count (code- and governance-bounded)
 		///   - `N` new-members-count (code- and governance-bounded)
-		///   - `P` proposals-count (code-bounded)
+		///   - `P` proposals-count (code-bounded), of which at most `MaxVoteCleanupsPerBlock` are
+		///     updated right away
 		/// - DB:
 		///   - 1 storage mutation (codec `O(M)` read, `O(N)` write) for reading and writing the members
+		///   - `N` storage reads (codec `O(1)`) for checking the bans of the new members
 		///   - 1 storage read (codec `O(P)`) for reading the proposals
 		///   - `P` storage mutations (codec `O(M)`) for updating the votes for each proposal
 		///   - 1 storage write (codec `O(1)`) for deleting the old `prime` and setting the new one
@@ -309,7 +490,7 @@ decl_module! {
 			T::WeightInfo::set_members(
 				*old_count, // M
 				new_members.len() as u32, // N
-				T::MaxProposals::get() // P
+				Module::<T, I>::max_vote_cleanups() // P
 			),
 			DispatchClass::Operational
 		)]
@@ -335,6 +516,12 @@ decl_module! {
 					old.len()
 				);
 			}
+			let now = frame_system::Module::<T>::block_number();
+			for who in new_members.iter() {
+				if let Some(until) = Bans::<T, I>::get(who) {
+					ensure!(until <= now, Error::<T, I>::Banned);
+				}
+			}
 			let mut new_members = new_members;
 			new_members.sort();
 			<Self as ChangeMembers<T::AccountId>>::set_members_sorted(&new_members, &old);
@@ -343,7 +530,62 @@ decl_module! {
 			Ok(Some(T::WeightInfo::set_members(
 				old.len() as u32, // M
 				new_members.len() as u32, // N
-				T::MaxProposals::get(), // P
+				Self::max_vote_cleanups(), // P
+			)).into())
+		}
+
+		/// Remove a member from the collective and ban them from being made a member again
+		/// through `set_members` until block `until`.
+		///
+		/// - `who`: The member to ban.
+		/// - `until`: The block number from which `who` may be made a member again.
+		///
+		/// The votes of `who` are removed from all open motions. The prime is kept unless it is
+		/// `who`.
+		///
+		/// Requires `BanOrigin`.
+		///
+		/// # <weight>
+		/// ## Weight
+		/// - `O(MP)` where:
+		///   - `M` members-count (code-bounded)
+		///   - `P` proposals-count (code-bounded), of which at most `MaxVoteCleanupsPerBlock` are
+		///     updated right away
+		/// - DB:
+		///   - 1 storage mutation (codec `O(M)`) for reading and writing the members
+		///   - 1 storage read (codec `O(P)`) for reading the proposals
+		///   - `P` storage mutations (codec `O(M)`) for updating the votes for each proposal
+		///   - 1 storage mutation (codec `O(1)`) for keeping or deleting the `prime`
+		///   - 1 storage write (codec `O(1)`) for storing the ban
+		/// # </weight>
+		#[weight = (
+			T::WeightInfo::ban_member(
+				T::MaxMembers::get(), // M
+				Module::<T, I>::max_vote_cleanups() // P
+			),
+			DispatchClass::Operational
+		)]
+		fn ban_member(origin, who: T::AccountId, until: T::BlockNumber) -> DispatchResultWithPostInfo {
+			T::BanOrigin::ensure_origin(origin)?;
+
+			let mut members = Self::members();
+			let old_count = members.len() as u32;
+			let pos = members.binary_search(&who).ok().ok_or(Error::<T, I>::NotMember)?;
+			members.remove(pos);
+
+			let prime = Self::prime().filter(|p| p != &who);
+			<Self as ChangeMembers<T::AccountId>>::change_members_sorted(
+				&[],
+				sp_std::slice::from_ref(&who),
+				&members,
+			);
+			Prime::<T, I>::set(prime);
+			Bans::<T, I>::insert(&who, until);
+
+			Self::deposit_event(RawEvent::MemberBanned(who, until));
+			Ok(Some(T::WeightInfo::ban_member(
+				old_count, // M
+				Self::max_vote_cleanups(), // P
 			)).into())
 		}
 
@@ -434,54 +676,114 @@ decl_module! {
 			#[compact] threshold: MemberCount,
 			proposal: Box<<T as Config<I>>::Proposal>,
 			#[compact] length_bound: u32
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::do_propose(who, threshold, proposal, length_bound, MotionOrigin::Members)
+		}
+
+		/// Add a new emergency proposal to be voted on.
+		///
+		/// Requires the sender to be the prime member.
+		///
+		/// The motion is voted on and closed like any other, but it may be closed once
+		/// `EmergencyMotionDuration` has passed rather than `MotionDuration`. It is always put up
+		/// for voting, whatever the `threshold`.
+		///
+		/// # <weight>
+		/// ## Weight
+		/// - `O(B + M + P)` where:
+		///   - `B` is `proposal` size in bytes (length-fee-bounded)
+		///   - `M` is members-count (code- and governance-bounded)
+		///   - `P` is proposals-count (code-bounded)
+		/// - DB:
+		///   - 1 storage read `is_member` (codec `O(M)`)
+		///   - 1 storage read `Prime` (codec `O(1)`)
+		///   - 1 storage read `ProposalOf::contains_key` (codec `O(1)`)
+		///   - 1 storage mutation `Proposals` (codec `O(P)`)
+		///   - 1 storage mutation `ProposalCount` (codec `O(1)`)
+		///   - 1 storage write `ProposalOf` (codec `O(B)`)
+		///   - 1 storage write `Voting` (codec `O(M)`)
+		///   - 1 event
+		/// # </weight>
+		#[weight = (
+			T::WeightInfo::propose_emergency(
+				*length_bound, // B
+				T::MaxMembers::get(), // M
+				T::MaxProposals::get(), // P
+			),
+			DispatchClass::Operational
+		)]
+		fn propose_emergency(origin,
+			#[compact] threshold: MemberCount,
+			proposal: Box<<T as Config<I>>::Proposal>,
+			#[compact] length_bound: u32
 		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
 			let members = Self::members();
 			ensure!(members.contains(&who), Error::<T, I>::NotMember);
+			ensure!(Self::prime().as_ref() == Some(&who), Error::<T, I>::NotPrime);
 
 			let proposal_len = proposal.using_encoded(|x| x.len());
 			ensure!(proposal_len <= length_bound as usize, Error::<T, I>::WrongProposalLength);
 			let proposal_hash = T::Hashing::hash_of(&proposal);
 			ensure!(!<ProposalOf<T, I>>::contains_key(proposal_hash), Error::<T, I>::DuplicateProposal);
 
-			if threshold < 2 {
-				let seats = Self::members().len() as MemberCount;
-				let result = proposal.dispatch(RawOrigin::Members(1, seats).into());
-				Self::deposit_event(
-					RawEvent::Executed(proposal_hash, result.map(|_| ()).map_err(|e| e.error))
-				);
+			let (index, active_proposals) = Self::do_propose_motion(
+				who.clone(),
+				threshold,
+				*proposal,
+				proposal_hash,
+				T::EmergencyMotionDuration::get(),
+			)?;
+			Self::deposit_event(RawEvent::EmergencyProposed(who, index, proposal_hash, threshold));
+
+			Ok(Some(T::WeightInfo::propose_emergency(
+				proposal_len as u32, // B
+				members.len() as u32, // M
+				active_proposals as u32, // P
+			)).into())
+		}
 
-				Ok(get_result_weight(result).map(|w| {
-					T::WeightInfo::propose_execute(
-						proposal_len as u32, // B
-						members.len() as u32, // M
-					).saturating_add(w) // P1
-				}).into())
+		/// Add a new proposal to either be voted on or executed directly, choosing the origin it
+		/// is dispatched with.
+		///
+		/// Requires the sender to be member.
+		///
+		/// Works like `propose`, except that `proposal` is dispatched with the origin that
+		/// `motion_origin` selects. `MotionOrigin::Root` is only allowed if `RootCapableOrigin`
+		/// accepts the origin of a motion passed by `threshold` members, both when proposing and
+		/// when the motion is approved.
+		///
+		/// # <weight>
+		/// ## Weight
+		/// - Same as `propose`, plus:
+		/// - `RootCapableOrigin` check of the origin of the motion
+		/// - DB:
+		///   - 1 storage write `MotionOrigins` (codec `O(1)`)
+		/// # </weight>
+		#[weight = (
+			if *threshold < 2 {
+				T::WeightInfo::propose_execute(
+					*length_bound, // B
+					T::MaxMembers::get(), // M
+				).saturating_add(proposal.get_dispatch_info().weight) // P1
 			} else {
-				let active_proposals =
-					<Proposals<T, I>>::try_mutate(|proposals| -> Result<usize, DispatchError> {
-						proposals.push(proposal_hash);
-						ensure!(
-							proposals.len() <= T::MaxProposals::get() as usize,
-							Error::<T, I>::TooManyProposals
-						);
-						Ok(proposals.len())
-					})?;
-				let index = Self::proposal_count();
-				<ProposalCount<I>>::mutate(|i| *i += 1);
-				<ProposalOf<T, I>>::insert(proposal_hash, *proposal);
-				let end = system::Module::<T>::block_number() + T::MotionDuration::get();
-				let votes = Votes { index, threshold, ayes: vec![who.clone()], nays: vec![], end };
-				<Voting<T, I>>::insert(proposal_hash, votes);
-
-				Self::deposit_event(RawEvent::Proposed(who, index, proposal_hash, threshold));
-
-				Ok(Some(T::WeightInfo::propose_proposed(
-					proposal_len as u32, // B
-					members.len() as u32, // M
-					active_proposals as u32, // P2
-				)).into())
-			}
+				T::WeightInfo::propose_with_origin(
+					*length_bound, // B
+					T::MaxMembers::get(), // M
+					T::MaxProposals::get(), // P2
+				)
+			},
+			DispatchClass::Operational
+		)]
+		fn propose_with_origin(origin,
+			#[compact] threshold: MemberCount,
+			proposal: Box<<T as Config<I>>::Proposal>,
+			motion_origin: MotionOrigin,
+			#[compact] length_bound: u32
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::do_propose(who, threshold, proposal, length_bound, motion_origin)
 		}
 
 		/// Add an aye or nay vote for the sender to the given proposal.
@@ -511,7 +813,7 @@ decl_module! {
 			let members = Self::members();
 			ensure!(members.contains(&who), Error::<T, I>::NotMember);
 
-			let mut voting = Self::voting(&proposal).ok_or(Error::<T, I>::ProposalMissing)?;
+			let mut voting = Self::current_voting(&proposal).ok_or(Error::<T, I>::ProposalMissing)?;
 			ensure!(voting.index == index, Error::<T, I>::WrongIndex);
 
 			let position_yes = voting.ayes.iter().position(|a| a == &who);
@@ -542,6 +844,7 @@ decl_module! {
 
 			let yes_votes = voting.ayes.len() as MemberCount;
 			let no_votes = voting.nays.len() as MemberCount;
+			T::VoteHandler::on_vote(&proposal, &who, approve, yes_votes, no_votes);
 			Self::deposit_event(RawEvent::Voted(who, proposal, approve, yes_votes, no_votes));
 
 			Voting::<T, I>::insert(&proposal, voting);
@@ -611,7 +914,7 @@ decl_module! {
 		) -> DispatchResultWithPostInfo {
 			let _ = ensure_signed(origin)?;
 
-			let voting = Self::voting(&proposal_hash).ok_or(Error::<T, I>::ProposalMissing)?;
+			let voting = Self::current_voting(&proposal_hash).ok_or(Error::<T, I>::ProposalMissing)?;
 			ensure!(voting.index == index, Error::<T, I>::WrongIndex);
 
 			let mut no_votes = voting.nays.len() as MemberCount;
@@ -626,6 +929,7 @@ decl_module! {
 					length_bound,
 					proposal_weight_bound,
 				)?;
+				T::VoteHandler::on_close(&proposal_hash, true, yes_votes, no_votes);
 				Self::deposit_event(RawEvent::Closed(proposal_hash, yes_votes, no_votes));
 				let (proposal_weight, proposal_count) =
 					Self::do_approve_proposal(seats, voting, proposal_hash, proposal);
@@ -636,6 +940,7 @@ decl_module! {
 				).into());
 
 			} else if disapproved {
+				T::VoteHandler::on_close(&proposal_hash, false, yes_votes, no_votes);
 				Self::deposit_event(RawEvent::Closed(proposal_hash, yes_votes, no_votes));
 				let proposal_count = Self::do_disapprove_proposal(proposal_hash);
 				return Ok((
@@ -665,6 +970,10 @@ decl_module! {
 					length_bound,
 					proposal_weight_bound,
 				)?;
+				T::VoteHandler::on_close(&proposal_hash, true, yes_votes, no_votes);
+				if abstentions > 0 {
+					Self::deposit_event(RawEvent::DefaultVotesApplied(proposal_hash, abstentions, default));
+				}
 				Self::deposit_event(RawEvent::Closed(proposal_hash, yes_votes, no_votes));
 				let (proposal_weight, proposal_count) =
 					Self::do_approve_proposal(seats, voting, proposal_hash, proposal);
@@ -674,6 +983,10 @@ decl_module! {
 					Pays::Yes,
 				).into());
 			} else {
+				T::VoteHandler::on_close(&proposal_hash, false, yes_votes, no_votes);
+				if abstentions > 0 {
+					Self::deposit_event(RawEvent::DefaultVotesApplied(proposal_hash, abstentions, default));
+				}
 				Self::deposit_event(RawEvent::Closed(proposal_hash, yes_votes, no_votes));
 				let proposal_count = Self::do_disapprove_proposal(proposal_hash);
 				return Ok((
@@ -713,6 +1026,169 @@ impl<T: Config<I>, I: Instance> Module<T, I> {
 		Self::members().contains(who)
 	}
 
+	/// Propose `proposal` on behalf of `who`, to be dispatched with `motion_origin` once passed.
+	fn do_propose(
+		who: T::AccountId,
+		threshold: MemberCount,
+		proposal: Box<<T as Config<I>>::Proposal>,
+		length_bound: u32,
+		motion_origin: MotionOrigin,
+	) -> DispatchResultWithPostInfo {
+		let members = Self::members();
+		ensure!(members.contains(&who), Error::<T, I>::NotMember);
+
+		let proposal_len = proposal.using_encoded(|x| x.len());
+		ensure!(proposal_len <= length_bound as usize, Error::<T, I>::WrongProposalLength);
+		let proposal_hash = T::Hashing::hash_of(&proposal);
+		ensure!(!<ProposalOf<T, I>>::contains_key(proposal_hash), Error::<T, I>::DuplicateProposal);
+
+		let seats = members.len() as MemberCount;
+		if threshold < 2 {
+			let origin = Self::dispatch_origin(motion_origin, 1, seats)?;
+			let result = proposal.dispatch(origin);
+			Self::deposit_event(
+				RawEvent::Executed(proposal_hash, result.map(|_| ()).map_err(|e| e.error))
+			);
+
+			Ok(get_result_weight(result).map(|w| {
+				T::WeightInfo::propose_execute(
+					proposal_len as u32, // B
+					members.len() as u32, // M
+				).saturating_add(w) // P1
+			}).into())
+		} else {
+			// fail early rather than at approval if the motion may never be dispatched as Root.
+			Self::dispatch_origin(motion_origin, threshold, seats)?;
+			let (index, active_proposals) = Self::do_propose_motion(
+				who.clone(),
+				threshold,
+				*proposal,
+				proposal_hash,
+				T::MotionDuration::get(),
+			)?;
+			Self::deposit_event(RawEvent::Proposed(who, index, proposal_hash, threshold));
+
+			if motion_origin == MotionOrigin::Members {
+				return Ok(Some(T::WeightInfo::propose_proposed(
+					proposal_len as u32, // B
+					members.len() as u32, // M
+					active_proposals as u32, // P2
+				)).into())
+			}
+			<MotionOrigins<T, I>>::insert(proposal_hash, motion_origin);
+			Ok(Some(T::WeightInfo::propose_with_origin(
+				proposal_len as u32, // B
+				members.len() as u32, // M
+				active_proposals as u32, // P2
+			)).into())
+		}
+	}
+
+	/// The origin to dispatch a proposal passed by `threshold` out of `seats` members with.
+	fn dispatch_origin(
+		motion_origin: MotionOrigin,
+		threshold: MemberCount,
+		seats: MemberCount,
+	) -> Result<<T as Config<I>>::Origin, DispatchError> {
+		let members_origin = RawOrigin::Members(threshold, seats).into();
+		match motion_origin {
+			MotionOrigin::Members => Ok(members_origin),
+			MotionOrigin::Collective => Ok(RawOrigin::Members(1, seats).into()),
+			MotionOrigin::Root => {
+				T::RootCapableOrigin::try_origin(members_origin)
+					.map_err(|_| Error::<T, I>::RootNotAllowed)?;
+				Ok(frame_system::RawOrigin::Root.into())
+			},
+		}
+	}
+
+	/// Store `proposal` as a new motion proposed by `who`, which closes after `duration`.
+	///
+	/// The proposer implicitly votes aye. Returns the index of the motion and the number of
+	/// active proposals.
+	fn do_propose_motion(
+		who: T::AccountId,
+		threshold: MemberCount,
+		proposal: <T as Config<I>>::Proposal,
+		proposal_hash: T::Hash,
+		duration: T::BlockNumber,
+	) -> Result<(ProposalIndex, usize), DispatchError> {
+		let index = Self::proposal_count();
+		let next_index = index.checked_add(1).ok_or(Error::<T, I>::ProposalIndicesExhausted)?;
+		let active_proposals =
+			<Proposals<T, I>>::try_mutate(|proposals| -> Result<usize, DispatchError> {
+				proposals.push(proposal_hash);
+				ensure!(
+					proposals.len() <= T::MaxProposals::get() as usize,
+					Error::<T, I>::TooManyProposals
+				);
+				Ok(proposals.len())
+			})?;
+		<ProposalCount<I>>::put(next_index);
+		<ProposalOf<T, I>>::insert(proposal_hash, proposal);
+		let now = system::Module::<T>::block_number();
+		let end = now + duration;
+		let votes = Votes { index, threshold, ayes: vec![who.clone()], nays: vec![], end };
+		<Voting<T, I>>::insert(proposal_hash, votes);
+		<MotionLifetimes<T, I>>::insert(index, MotionLifetime { proposed_at: now, closed_at: None });
+
+		// The proposer implicitly votes aye.
+		T::VoteHandler::on_vote(&proposal_hash, &who, true, 1, 0);
+
+		Ok((index, active_proposals))
+	}
+
+	/// The votes on the motion of the proposal `hash`, without those of former members that are
+	/// still to be removed.
+	fn current_voting(hash: &T::Hash) -> Option<Votes<T::AccountId, T::BlockNumber>> {
+		let mut voting = Self::voting(hash)?;
+		let outgoing = Self::outgoing_voters();
+		if !outgoing.is_empty() {
+			Self::remove_votes_of(&mut voting, &outgoing);
+		}
+		Some(voting)
+	}
+
+	/// Remove the votes of the sorted accounts `outgoing` from `votes`.
+	fn remove_votes_of(votes: &mut Votes<T::AccountId, T::BlockNumber>, outgoing: &[T::AccountId]) {
+		votes.ayes.retain(|a| outgoing.binary_search(a).is_err());
+		votes.nays.retain(|a| outgoing.binary_search(a).is_err());
+	}
+
+	/// Remove the votes of `OutgoingVoters` from at most `max` motions of `VoteCleanupQueue`,
+	/// returning the number of motions done. Once the queue is empty, both are cleared.
+	fn clean_up_votes(max: u32) -> u32 {
+		let mut queue = Self::vote_cleanup_queue();
+		if queue.is_empty() {
+			return 0
+		}
+		let outgoing = Self::outgoing_voters();
+		let batch = queue.len().min(max as usize);
+		for h in queue.drain(..batch) {
+			<Voting<T, I>>::mutate(h, |v| if let Some(votes) = v {
+				Self::remove_votes_of(votes, &outgoing);
+			});
+		}
+		if queue.is_empty() {
+			OutgoingVoters::<T, I>::kill();
+			VoteCleanupQueue::<T, I>::kill();
+		} else {
+			VoteCleanupQueue::<T, I>::put(queue);
+		}
+		batch as u32
+	}
+
+	/// The most motions whose votes are updated right away when the membership changes, for
+	/// weight estimation.
+	fn max_vote_cleanups() -> u32 {
+		T::MaxProposals::get().min(T::MaxVoteCleanupsPerBlock::get())
+	}
+
+	/// The index of the active motion of the proposal `hash`, if there is one.
+	pub fn proposal_index_of(hash: T::Hash) -> Option<ProposalIndex> {
+		Self::voting(hash).map(|votes| votes.index)
+	}
+
 	/// Ensure that the right proposal bounds were passed and get the proposal from storage.
 	///
 	/// Checks the length in storage via `storage::read` which adds an extra `size_of::<u32>() == 4`
@@ -756,8 +1232,11 @@ impl<T: Config<I>, I: Instance> Module<T, I> {
 		Self::deposit_event(RawEvent::Approved(proposal_hash));
 
 		let dispatch_weight = proposal.get_dispatch_info().weight;
-		let origin = RawOrigin::Members(voting.threshold, seats).into();
-		let result = proposal.dispatch(origin);
+		let motion_origin = Self::motion_origin(proposal_hash).unwrap_or(MotionOrigin::Members);
+		let result = match Self::dispatch_origin(motion_origin, voting.threshold, seats) {
+			Ok(origin) => proposal.dispatch(origin),
+			Err(e) => Err(e.into()),
+		};
 		Self::deposit_event(
 			RawEvent::Executed(proposal_hash, result.map(|_| ()).map_err(|e| e.error))
 		);
@@ -774,11 +1253,19 @@ impl<T: Config<I>, I: Instance> Module<T, I> {
 		Self::remove_proposal(proposal_hash)
 	}
 
-	// Removes a proposal from the pallet, cleaning up votes and the vector of proposals.
+	// Removes a proposal from the pallet, cleaning up votes and the vector of proposals, and
+	// records when it was closed.
 	fn remove_proposal(proposal_hash: T::Hash) -> u32 {
 		// remove proposal and vote
 		ProposalOf::<T, I>::remove(&proposal_hash);
-		Voting::<T, I>::remove(&proposal_hash);
+		MotionOrigins::<T, I>::remove(proposal_hash);
+		if let Some(votes) = Voting::<T, I>::take(&proposal_hash) {
+			MotionLifetimes::<T, I>::mutate(votes.index, |maybe_lifetime| {
+				if let Some(lifetime) = maybe_lifetime {
+					lifetime.closed_at = Some(system::Module::<T>::block_number());
+				}
+			});
+		}
 		let num_proposals = Proposals::<T, I>::mutate(|proposals| {
 			proposals.retain(|h| h != &proposal_hash);
 			proposals.len() + 1 // calculate weight based on original length
@@ -790,23 +1277,30 @@ impl<T: Config<I>, I: Instance> Module<T, I> {
 impl<T: Config<I>, I: Instance> ChangeMembers<T::AccountId> for Module<T, I> {
 	/// Update the members of the collective. Votes are updated and the prime is reset.
 	///
+	/// The votes of `outgoing` are removed from at most `MaxVoteCleanupsPerBlock` open motions
+	/// right away, and from the rest in `on_initialize`. An account that is made a member again
+	/// before then keeps the votes that were not removed yet.
+	///
 	/// NOTE: Does not enforce the expected `MaxMembers` limit on the amount of members, but
 	///       the weight estimations rely on it to estimate dispatchable weight.
 	///
+	/// Accounts that are not sorted and deduplicated are reported, see `StrictMemberOrdering`.
+	///
 	/// # <weight>
 	/// ## Weight
 	/// - `O(MP + N)`
 	///   - where `M` old-members-count (governance-bounded)
 	///   - where `N` new-members-count (governance-bounded)
-	///   - where `P` proposals-count
+	///   - where `P` proposals-count, of which at most `MaxVoteCleanupsPerBlock` are updated
 	/// - DB:
 	///   - 1 storage read (codec `O(P)`) for reading the proposals
+	///   - 2 storage writes (codec `O(M)` and `O(P)`) for staging the vote cleanup
 	///   - `P` storage mutations for updating the votes (codec `O(M)`)
 	///   - 1 storage write (codec `O(N)`) for storing the new members
 	///   - 1 storage write (codec `O(1)`) for deleting the old prime
 	/// # </weight>
 	fn change_members_sorted(
-		_incoming: &[T::AccountId],
+		incoming: &[T::AccountId],
 		outgoing: &[T::AccountId],
 		new: &[T::AccountId],
 	) {
@@ -817,21 +1311,33 @@ impl<T: Config<I>, I: Instance> ChangeMembers<T::AccountId> for Module<T, I> {
 				new.len()
 			);
 		}
-		// remove accounts from all current voting in motions.
-		let mut outgoing = outgoing.to_vec();
-		outgoing.sort();
-		for h in Self::proposals().into_iter() {
-			<Voting<T, I>>::mutate(h, |v|
-				if let Some(mut votes) = v.take() {
-					votes.ayes = votes.ayes.into_iter()
-						.filter(|i| outgoing.binary_search(i).is_err())
-						.collect();
-					votes.nays = votes.nays.into_iter()
-						.filter(|i| outgoing.binary_search(i).is_err())
-						.collect();
-					*v = Some(votes);
-				}
-			);
+		let mut new = new.to_vec();
+		for (name, accounts) in &[("incoming", incoming), ("outgoing", outgoing), ("new", &new[..])] {
+			if !is_sorted_and_unique(accounts) {
+				debug::error!("Members {} are not sorted and deduplicated: {:?}", name, accounts);
+				assert!(
+					!T::StrictMemberOrdering::get(),
+					"change_members_sorted called with unsorted {} members",
+					name,
+				);
+			}
+		}
+		new.sort();
+		new.dedup();
+		// remove accounts from all current voting in motions, including those of a previous
+		// change that are not done yet. Accounts that are members again keep their votes.
+		let mut outgoing_voters = Self::outgoing_voters();
+		outgoing_voters.extend(outgoing.iter().cloned());
+		outgoing_voters.sort();
+		outgoing_voters.dedup();
+		outgoing_voters.retain(|who| new.binary_search(who).is_err());
+		if outgoing_voters.is_empty() {
+			OutgoingVoters::<T, I>::kill();
+			VoteCleanupQueue::<T, I>::kill();
+		} else {
+			OutgoingVoters::<T, I>::put(outgoing_voters);
+			VoteCleanupQueue::<T, I>::put(Self::proposals());
+			Self::clean_up_votes(T::MaxVoteCleanupsPerBlock::get());
 		}
 		Members::<T, I>::put(new);
 		Prime::<T, I>::kill();
@@ -846,6 +1352,24 @@ impl<T: Config<I>, I: Instance> ChangeMembers<T::AccountId> for Module<T, I> {
 	}
 }
 
+impl<T: Config<I>, I: Instance> CollectiveInfo<T::AccountId> for Module<T, I> {
+	fn members() -> Vec<T::AccountId> {
+		Members::<T, I>::get()
+	}
+
+	fn member_count() -> MemberCount {
+		Members::<T, I>::decode_len().unwrap_or(0) as MemberCount
+	}
+
+	fn prime() -> Option<T::AccountId> {
+		Prime::<T, I>::get()
+	}
+
+	fn is_member(who: &T::AccountId) -> bool {
+		Members::<T, I>::get().contains(who)
+	}
+}
+
 impl<T: Config<I>, I: Instance> InitializeMembers<T::AccountId> for Module<T, I> {
 	fn initialize_members(members: &[T::AccountId]) {
 		if !members.is_empty() {
@@ -855,6 +1379,88 @@ impl<T: Config<I>, I: Instance> InitializeMembers<T::AccountId> for Module<T, I>
 	}
 }
 
+/// Shares the membership source of a parent collective instance `P` with a sub-committee
+/// instance `S` drawn from it.
+///
+/// Use as the `ChangeMembers` of whatever decides the members of `P`, such as an elections or
+/// membership pallet. Changes are passed on to `P`, and members of `S` who are no longer members
+/// of `P` are removed from `S`, which resets the prime of `S`. The prime is that of `P`.
+pub struct SharedMembership<T, P, S>(sp_std::marker::PhantomData<(T, P, S)>);
+
+impl<T, P, S> ChangeMembers<T::AccountId> for SharedMembership<T, P, S> where
+	T: Config<P> + Config<S>,
+	P: Instance,
+	S: Instance,
+{
+	fn change_members_sorted(
+		incoming: &[T::AccountId],
+		outgoing: &[T::AccountId],
+		new: &[T::AccountId],
+	) {
+		Module::<T, P>::change_members_sorted(incoming, outgoing, new);
+
+		let parent_members = Module::<T, P>::members();
+		let old_sub_members = Module::<T, S>::members();
+		let new_sub_members = old_sub_members.iter()
+			.filter(|m| parent_members.binary_search(m).is_ok())
+			.cloned()
+			.collect::<Vec<_>>();
+		if new_sub_members.len() != old_sub_members.len() {
+			Module::<T, S>::set_members_sorted(&new_sub_members, &old_sub_members);
+		}
+	}
+
+	fn set_prime(prime: Option<T::AccountId>) {
+		Module::<T, P>::set_prime(prime);
+	}
+
+	fn get_prime() -> Option<T::AccountId> {
+		Module::<T, P>::get_prime()
+	}
+}
+
+/// Admits only members of the parent collective instance `P` to the sub-committee instance `S`.
+///
+/// Use as the `ChangeMembers` of whatever decides the members of `S`, together with
+/// `SharedMembership` for `P`. Accounts that are not members of `P` are left out of `S`, and
+/// cannot be its prime. Members set on `S` directly through `set_members` are not checked.
+pub struct SubCommittee<T, P, S>(sp_std::marker::PhantomData<(T, P, S)>);
+
+impl<T, P, S> ChangeMembers<T::AccountId> for SubCommittee<T, P, S> where
+	T: Config<P> + Config<S>,
+	P: Instance,
+	S: Instance,
+{
+	fn change_members_sorted(
+		_incoming: &[T::AccountId],
+		_outgoing: &[T::AccountId],
+		new: &[T::AccountId],
+	) {
+		let parent_members = Module::<T, P>::members();
+		let (new_sub_members, rejected): (Vec<_>, Vec<_>) = new.iter()
+			.cloned()
+			.partition(|m| parent_members.binary_search(m).is_ok());
+		if !rejected.is_empty() {
+			debug::warn!("Sub-committee members are not members of the parent collective: {:?}", rejected);
+		}
+		Module::<T, S>::set_members_sorted(&new_sub_members, &Module::<T, S>::members());
+	}
+
+	fn set_prime(prime: Option<T::AccountId>) {
+		let prime = prime.filter(|p| Module::<T, P>::is_member(p));
+		Module::<T, S>::set_prime(prime);
+	}
+
+	fn get_prime() -> Option<T::AccountId> {
+		Module::<T, S>::get_prime()
+	}
+}
+
+/// Whether `accounts` is sorted in strictly ascending order, i.e. sorted and free of duplicates.
+fn is_sorted_and_unique<AccountId: Ord>(accounts: &[AccountId]) -> bool {
+	accounts.windows(2).all(|pair| pair[0] < pair[1])
+}
+
 /// Ensure that the origin `o` represents at least `n` members. Returns `Ok` or an `Err`
 /// otherwise.
 pub fn ensure_members<OuterOrigin, AccountId, I>(o: OuterOrigin, n: MemberCount)
@@ -963,7 +1569,8 @@ mod tests {
 	use frame_support::{Hashable, assert_ok, assert_noop, parameter_types};
 	use frame_system::{self as system, EventRecord, Phase};
 	use hex_literal::hex;
-	use sp_core::H256;
+	use crate::test_utils::{genesis_config, last_collective_event, record};
+	use sp_core::{H256, u32_trait::{_2, _3}};
 	use sp_runtime::{
 		traits::{BlakeTwo256, IdentityLookup}, testing::Header,
 		BuildStorage,
@@ -973,11 +1580,16 @@ mod tests {
 	parameter_types! {
 		pub const BlockHashCount: u64 = 250;
 		pub const MotionDuration: u64 = 3;
+		pub const EmergencyMotionDuration: u64 = 1;
 		pub const MaxProposals: u32 = 100;
 		pub const MaxMembers: u32 = 100;
 		pub BlockWeights: frame_system::limits::BlockWeights =
 			frame_system::limits::BlockWeights::simple_max(1024);
 	}
+	parameter_types! {
+		pub static StrictMemberOrdering: bool = true;
+		pub static MaxVoteCleanupsPerBlock: u32 = 100;
+	}
 	impl frame_system::Config for Test {
 		type BaseCallFilter = ();
 		type BlockWeights = ();
@@ -1002,14 +1614,40 @@ mod tests {
 		type SystemWeightInfo = ();
 		type SS58Prefix = ();
 	}
+	#[derive(Clone, PartialEq, Debug)]
+	pub enum MirroredVote {
+		Vote(H256, u64, bool, MemberCount, MemberCount),
+		Close(H256, bool, MemberCount, MemberCount),
+	}
+
+	thread_local! {
+		static MIRRORED_VOTES: std::cell::RefCell<Vec<MirroredVote>> = Default::default();
+	}
+
+	pub struct RecordVotes;
+	impl VoteHandler<u64, H256> for RecordVotes {
+		fn on_vote(hash: &H256, who: &u64, approve: bool, yes: MemberCount, no: MemberCount) {
+			MIRRORED_VOTES.with(|v| v.borrow_mut().push(MirroredVote::Vote(*hash, *who, approve, yes, no)));
+		}
+		fn on_close(hash: &H256, approved: bool, yes: MemberCount, no: MemberCount) {
+			MIRRORED_VOTES.with(|v| v.borrow_mut().push(MirroredVote::Close(*hash, approved, yes, no)));
+		}
+	}
+
 	impl Config<Instance1> for Test {
 		type Origin = Origin;
 		type Proposal = Call;
 		type Event = Event;
 		type MotionDuration = MotionDuration;
+		type EmergencyMotionDuration = EmergencyMotionDuration;
 		type MaxProposals = MaxProposals;
 		type MaxMembers = MaxMembers;
 		type DefaultVote = PrimeDefaultVote;
+		type VoteHandler = RecordVotes;
+		type BanOrigin = frame_system::EnsureRoot<u64>;
+		type RootCapableOrigin = EnsureProportionAtLeast<_2, _3, u64, Instance1>;
+		type StrictMemberOrdering = StrictMemberOrdering;
+		type MaxVoteCleanupsPerBlock = MaxVoteCleanupsPerBlock;
 		type WeightInfo = ();
 	}
 	impl Config<Instance2> for Test {
@@ -1017,9 +1655,15 @@ mod tests {
 		type Proposal = Call;
 		type Event = Event;
 		type MotionDuration = MotionDuration;
+		type EmergencyMotionDuration = EmergencyMotionDuration;
 		type MaxProposals = MaxProposals;
 		type MaxMembers = MaxMembers;
 		type DefaultVote = MoreThanMajorityThenPrimeDefaultVote;
+		type VoteHandler = ();
+		type BanOrigin = frame_system::EnsureRoot<u64>;
+		type RootCapableOrigin = frame_system::EnsureNever<()>;
+		type StrictMemberOrdering = StrictMemberOrdering;
+		type MaxVoteCleanupsPerBlock = MaxVoteCleanupsPerBlock;
 		type WeightInfo = ();
 	}
 	impl Config for Test {
@@ -1027,9 +1671,15 @@ mod tests {
 		type Proposal = Call;
 		type Event = Event;
 		type MotionDuration = MotionDuration;
+		type EmergencyMotionDuration = EmergencyMotionDuration;
 		type MaxProposals = MaxProposals;
 		type MaxMembers = MaxMembers;
 		type DefaultVote = PrimeDefaultVote;
+		type VoteHandler = ();
+		type BanOrigin = frame_system::EnsureRoot<u64>;
+		type RootCapableOrigin = frame_system::EnsureNever<()>;
+		type StrictMemberOrdering = StrictMemberOrdering;
+		type MaxVoteCleanupsPerBlock = MaxVoteCleanupsPerBlock;
 		type WeightInfo = ();
 	}
 
@@ -1051,14 +1701,8 @@ mod tests {
 
 	pub fn new_test_ext() -> sp_io::TestExternalities {
 		let mut ext: sp_io::TestExternalities = GenesisConfig {
-			collective_Instance1: Some(collective::GenesisConfig {
-				members: vec![1, 2, 3],
-				phantom: Default::default(),
-			}),
-			collective_Instance2: Some(collective::GenesisConfig {
-				members: vec![1, 2, 3, 4, 5],
-				phantom: Default::default(),
-			}),
+			collective_Instance1: Some(genesis_config(vec![1, 2, 3])),
+			collective_Instance2: Some(genesis_config(vec![1, 2, 3, 4, 5])),
 			collective: None,
 		}.build_storage().unwrap().into();
 		ext.execute_with(|| System::set_block_number(1));
@@ -1066,7 +1710,7 @@ mod tests {
 	}
 
 	fn make_proposal(value: u64) -> Call {
-		Call::System(frame_system::Call::remark(value.encode()))
+		crate::test_utils::make_proposal::<Test, Instance1>(value)
 	}
 
 	#[test]
@@ -1077,6 +1721,25 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn collective_info_works() {
+		new_test_ext().execute_with(|| {
+			assert_eq!(<Collective as CollectiveInfo<u64>>::members(), vec![1, 2, 3]);
+			assert_eq!(<Collective as CollectiveInfo<u64>>::member_count(), 3);
+			assert_eq!(<Collective as CollectiveInfo<u64>>::prime(), None);
+			assert!(<Collective as CollectiveInfo<u64>>::is_member(&2));
+			assert!(!<Collective as CollectiveInfo<u64>>::is_member(&4));
+
+			assert_ok!(Collective::set_members(Origin::root(), vec![1, 2, 3, 4], Some(4), MaxMembers::get()));
+			assert_eq!(<Collective as CollectiveInfo<u64>>::member_count(), 4);
+			assert_eq!(<Collective as CollectiveInfo<u64>>::prime(), Some(4));
+			assert!(<Collective as CollectiveInfo<u64>>::is_member(&4));
+
+			assert_eq!(<() as CollectiveInfo<u64>>::member_count(), 0);
+			assert!(!<() as CollectiveInfo<u64>>::is_member(&1));
+		});
+	}
+
 	#[test]
 	fn close_works() {
 		new_test_ext().execute_with(|| {
@@ -1097,16 +1760,75 @@ mod tests {
 			System::set_block_number(4);
 			assert_ok!(Collective::close(Origin::signed(4), hash.clone(), 0, proposal_weight, proposal_len));
 
-			let record = |event| EventRecord { phase: Phase::Initialization, event, topics: vec![] };
 			assert_eq!(System::events(), vec![
 				record(Event::collective_Instance1(RawEvent::Proposed(1, 0, hash.clone(), 3))),
 				record(Event::collective_Instance1(RawEvent::Voted(2, hash.clone(), true, 2, 0))),
+				record(Event::collective_Instance1(RawEvent::DefaultVotesApplied(hash, 1, false))),
 				record(Event::collective_Instance1(RawEvent::Closed(hash.clone(), 2, 1))),
 				record(Event::collective_Instance1(RawEvent::Disapproved(hash.clone())))
 			]);
 		});
 	}
 
+	#[test]
+	fn motion_lifetimes_are_recorded() {
+		new_test_ext().execute_with(|| {
+			let proposal = make_proposal(42);
+			let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+			let proposal_weight = proposal.get_dispatch_info().weight;
+			let hash = BlakeTwo256::hash_of(&proposal);
+
+			System::set_block_number(2);
+			assert_ok!(Collective::propose(Origin::signed(1), 3, Box::new(proposal.clone()), proposal_len));
+			let lifetime = Collective::motion_lifetime(0).unwrap();
+			assert_eq!(lifetime, MotionLifetime { proposed_at: 2, closed_at: None });
+			assert_eq!(lifetime.duration(), None);
+
+			System::set_block_number(5);
+			assert_ok!(Collective::close(Origin::signed(4), hash, 0, proposal_weight, proposal_len));
+			let lifetime = Collective::motion_lifetime(0).unwrap();
+			assert_eq!(lifetime, MotionLifetime { proposed_at: 2, closed_at: Some(5) });
+			assert_eq!(lifetime.duration(), Some(3));
+
+			// the prime can also close a motion early.
+			let proposal = make_proposal(69);
+			let hash = BlakeTwo256::hash_of(&proposal);
+			assert_ok!(Collective::propose(Origin::signed(1), 3, Box::new(proposal), proposal_len));
+			System::set_block_number(6);
+			assert_ok!(Collective::disapprove_proposal(Origin::root(), hash));
+			assert_eq!(Collective::motion_lifetime(1).and_then(|l| l.duration()), Some(1));
+
+			// proposals executed right away are not motions.
+			let proposal = make_proposal(7);
+			assert_ok!(Collective::propose(Origin::signed(1), 1, Box::new(proposal), proposal_len));
+			assert_eq!(Collective::motion_lifetime(2), None);
+		});
+	}
+
+	#[test]
+	fn vote_handler_mirrors_votes_and_close() {
+		new_test_ext().execute_with(|| {
+			let proposal = make_proposal(42);
+			let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+			let proposal_weight = proposal.get_dispatch_info().weight;
+			let hash = BlakeTwo256::hash_of(&proposal);
+
+			assert_ok!(Collective::propose(Origin::signed(1), 3, Box::new(proposal.clone()), proposal_len));
+			assert_ok!(Collective::vote(Origin::signed(2), hash, 0, true));
+			assert_ok!(Collective::vote(Origin::signed(2), hash, 0, false));
+
+			System::set_block_number(4);
+			assert_ok!(Collective::close(Origin::signed(4), hash, 0, proposal_weight, proposal_len));
+
+			assert_eq!(MIRRORED_VOTES.with(|v| v.borrow().clone()), vec![
+				MirroredVote::Vote(hash, 1, true, 1, 0),
+				MirroredVote::Vote(hash, 2, true, 2, 0),
+				MirroredVote::Vote(hash, 2, false, 1, 1),
+				MirroredVote::Close(hash, false, 1, 1),
+			]);
+		});
+	}
+
 	#[test]
 	fn proposal_weight_limit_works_on_approve() {
 		new_test_ext().execute_with(|| {
@@ -1159,16 +1881,136 @@ mod tests {
 			System::set_block_number(4);
 			assert_ok!(Collective::close(Origin::signed(4), hash.clone(), 0, proposal_weight, proposal_len));
 
-			let record = |event| EventRecord { phase: Phase::Initialization, event, topics: vec![] };
 			assert_eq!(System::events(), vec![
 				record(Event::collective_Instance1(RawEvent::Proposed(1, 0, hash.clone(), 3))),
 				record(Event::collective_Instance1(RawEvent::Voted(2, hash.clone(), true, 2, 0))),
+				record(Event::collective_Instance1(RawEvent::DefaultVotesApplied(hash, 1, false))),
 				record(Event::collective_Instance1(RawEvent::Closed(hash.clone(), 2, 1))),
 				record(Event::collective_Instance1(RawEvent::Disapproved(hash.clone())))
 			]);
 		});
 	}
 
+	#[test]
+	fn propose_with_origin_works() {
+		new_test_ext().execute_with(|| {
+			let proposal = Call::System(
+				frame_system::Call::set_storage(vec![(b"key".to_vec(), b"value".to_vec())])
+			);
+			let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+			let proposal_weight = proposal.get_dispatch_info().weight;
+			let hash = BlakeTwo256::hash_of(&proposal);
+
+			// a single member may not dispatch as Root.
+			assert_noop!(
+				Collective::propose_with_origin(
+					Origin::signed(1), 1, Box::new(proposal.clone()), MotionOrigin::Root, proposal_len
+				),
+				Error::<Test, Instance1>::RootNotAllowed
+			);
+
+			// the members origin may not set storage.
+			assert_ok!(Collective::propose(Origin::signed(1), 2, Box::new(proposal.clone()), proposal_len));
+			assert_eq!(Collective::motion_origin(hash), None);
+			assert_ok!(Collective::vote(Origin::signed(2), hash, 0, true));
+			assert_ok!(Collective::close(Origin::signed(4), hash, 0, proposal_weight, proposal_len));
+			assert_eq!(
+				last_collective_event::<Test, Instance1>(),
+				Some(RawEvent::Executed(hash, Err(DispatchError::BadOrigin)))
+			);
+
+			// two thirds of the members may.
+			assert_ok!(Collective::propose_with_origin(
+				Origin::signed(1), 2, Box::new(proposal.clone()), MotionOrigin::Root, proposal_len
+			));
+			assert_eq!(Collective::motion_origin(hash), Some(MotionOrigin::Root));
+			assert_ok!(Collective::vote(Origin::signed(2), hash, 1, true));
+			assert_ok!(Collective::close(Origin::signed(4), hash, 1, proposal_weight, proposal_len));
+			assert_eq!(last_collective_event::<Test, Instance1>(), Some(RawEvent::Executed(hash, Ok(()))));
+			assert_eq!(sp_io::storage::get(b"key"), Some(b"value".to_vec()));
+			assert!(!MotionOrigins::<Test, Instance1>::contains_key(hash));
+		});
+	}
+
+	#[test]
+	fn propose_emergency_works() {
+		new_test_ext().execute_with(|| {
+			let proposal = make_proposal(42);
+			let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+			let proposal_weight = proposal.get_dispatch_info().weight;
+			let hash = BlakeTwo256::hash_of(&proposal);
+			assert_ok!(Collective::set_members(Origin::root(), vec![1, 2, 3], Some(1), MaxMembers::get()));
+
+			assert_noop!(
+				Collective::propose_emergency(Origin::signed(2), 3, Box::new(proposal.clone()), proposal_len),
+				Error::<Test, Instance1>::NotPrime
+			);
+			assert_ok!(Collective::propose_emergency(Origin::signed(1), 3, Box::new(proposal.clone()), proposal_len));
+			assert_eq!(Collective::voting(hash).unwrap().end, 2);
+			assert_ok!(Collective::vote(Origin::signed(2), hash, 0, true));
+
+			// Closes after `EmergencyMotionDuration` rather than `MotionDuration`.
+			assert_noop!(
+				Collective::close(Origin::signed(4), hash, 0, proposal_weight, proposal_len),
+				Error::<Test, Instance1>::TooEarly
+			);
+			System::set_block_number(2);
+			assert_ok!(Collective::close(Origin::signed(4), hash, 0, proposal_weight, proposal_len));
+
+			assert_eq!(System::events(), vec![
+				record(Event::collective_Instance1(RawEvent::EmergencyProposed(1, 0, hash, 3))),
+				record(Event::collective_Instance1(RawEvent::Voted(2, hash, true, 2, 0))),
+				record(Event::collective_Instance1(RawEvent::DefaultVotesApplied(hash, 1, true))),
+				record(Event::collective_Instance1(RawEvent::Closed(hash, 3, 0))),
+				record(Event::collective_Instance1(RawEvent::Approved(hash))),
+				record(Event::collective_Instance1(RawEvent::Executed(hash, Err(DispatchError::BadOrigin))))
+			]);
+		});
+	}
+
+	#[test]
+	fn ban_member_works() {
+		new_test_ext().execute_with(|| {
+			let proposal = make_proposal(42);
+			let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+			let hash = BlakeTwo256::hash_of(&proposal);
+			assert_ok!(Collective::set_members(Origin::root(), vec![1, 2, 3], Some(1), MaxMembers::get()));
+			assert_ok!(Collective::propose(Origin::signed(1), 3, Box::new(proposal.clone()), proposal_len));
+			assert_ok!(Collective::vote(Origin::signed(2), hash, 0, true));
+
+			assert_noop!(Collective::ban_member(Origin::signed(1), 2, 10), DispatchError::BadOrigin);
+			assert_noop!(
+				Collective::ban_member(Origin::root(), 4, 10),
+				Error::<Test, Instance1>::NotMember
+			);
+			assert_ok!(Collective::ban_member(Origin::root(), 2, 10));
+
+			assert_eq!(Collective::members(), vec![1, 3]);
+			assert_eq!(Collective::prime(), Some(1));
+			assert_eq!(Collective::bans(2), Some(10));
+			assert_eq!(Collective::voting(hash).unwrap().ayes, vec![1]);
+			assert_eq!(
+				last_collective_event::<Test, Instance1>(),
+				Some(RawEvent::MemberBanned(2, 10))
+			);
+
+			// Cannot be re-added until the ban has passed.
+			assert_noop!(
+				Collective::set_members(Origin::root(), vec![1, 2, 3], None, MaxMembers::get()),
+				Error::<Test, Instance1>::Banned
+			);
+			System::set_block_number(10);
+			assert_ok!(Collective::set_members(Origin::root(), vec![1, 2, 3], None, MaxMembers::get()));
+			assert_eq!(Collective::members(), vec![1, 2, 3]);
+
+			// Banning the prime removes the prime.
+			assert_ok!(Collective::set_members(Origin::root(), vec![1, 2, 3], Some(3), MaxMembers::get()));
+			assert_ok!(Collective::ban_member(Origin::root(), 3, 20));
+			assert_eq!(Collective::members(), vec![1, 2]);
+			assert_eq!(Collective::prime(), None);
+		});
+	}
+
 	#[test]
 	fn close_with_voting_prime_works() {
 		new_test_ext().execute_with(|| {
@@ -1184,10 +2026,10 @@ mod tests {
 			System::set_block_number(4);
 			assert_ok!(Collective::close(Origin::signed(4), hash.clone(), 0, proposal_weight, proposal_len));
 
-			let record = |event| EventRecord { phase: Phase::Initialization, event, topics: vec![] };
 			assert_eq!(System::events(), vec![
 				record(Event::collective_Instance1(RawEvent::Proposed(1, 0, hash.clone(), 3))),
 				record(Event::collective_Instance1(RawEvent::Voted(2, hash.clone(), true, 2, 0))),
+				record(Event::collective_Instance1(RawEvent::DefaultVotesApplied(hash, 1, true))),
 				record(Event::collective_Instance1(RawEvent::Closed(hash.clone(), 3, 0))),
 				record(Event::collective_Instance1(RawEvent::Approved(hash.clone()))),
 				record(Event::collective_Instance1(RawEvent::Executed(hash.clone(), Err(DispatchError::BadOrigin))))
@@ -1211,11 +2053,11 @@ mod tests {
 			System::set_block_number(4);
 			assert_ok!(CollectiveMajority::close(Origin::signed(4), hash.clone(), 0, proposal_weight, proposal_len));
 
-			let record = |event| EventRecord { phase: Phase::Initialization, event, topics: vec![] };
 			assert_eq!(System::events(), vec![
 				record(Event::collective_Instance2(RawEvent::Proposed(1, 0, hash.clone(), 5))),
 				record(Event::collective_Instance2(RawEvent::Voted(2, hash.clone(), true, 2, 0))),
 				record(Event::collective_Instance2(RawEvent::Voted(3, hash.clone(), true, 3, 0))),
+				record(Event::collective_Instance2(RawEvent::DefaultVotesApplied(hash, 2, true))),
 				record(Event::collective_Instance2(RawEvent::Closed(hash.clone(), 5, 0))),
 				record(Event::collective_Instance2(RawEvent::Approved(hash.clone()))),
 				record(Event::collective_Instance2(RawEvent::Executed(hash.clone(), Err(DispatchError::BadOrigin))))
@@ -1223,6 +2065,49 @@ mod tests {
 		});
 	}
 
+	#[test]
+	#[should_panic(expected = "change_members_sorted called with unsorted new members")]
+	fn change_members_sorted_rejects_unsorted_members_in_strict_mode() {
+		new_test_ext().execute_with(|| {
+			Collective::change_members_sorted(&[4], &[], &[1, 2, 4, 3]);
+		});
+	}
+
+	#[test]
+	fn change_members_sorted_sorts_unsorted_members() {
+		new_test_ext().execute_with(|| {
+			StrictMemberOrdering::set(false);
+			Collective::change_members_sorted(&[5, 4], &[1], &[5, 2, 4, 3, 4]);
+			assert_eq!(Collective::members(), vec![2, 3, 4, 5]);
+		});
+	}
+
+	#[test]
+	fn shared_membership_keeps_sub_committee_within_parent() {
+		new_test_ext().execute_with(|| {
+			// `CollectiveMajority` is the parent of the `Collective` sub-committee.
+			type Shared = SharedMembership<Test, Instance2, Instance1>;
+			type Sub = SubCommittee<Test, Instance2, Instance1>;
+
+			Shared::change_members_sorted(&[6], &[2, 5], &[1, 3, 4, 6]);
+			assert_eq!(CollectiveMajority::members(), vec![1, 3, 4, 6]);
+			assert_eq!(Collective::members(), vec![1, 3]);
+
+			// only members of the parent can join the sub-committee or be its prime.
+			Sub::change_members_sorted(&[2, 4], &[], &[1, 2, 3, 4]);
+			assert_eq!(Collective::members(), vec![1, 3, 4]);
+			Sub::set_prime(Some(2));
+			assert_eq!(Collective::prime(), None);
+			Sub::set_prime(Some(4));
+			assert_eq!(Collective::prime(), Some(4));
+
+			// the sub-committee is left alone while its members stay in the parent.
+			Shared::change_members_sorted(&[5], &[6], &[1, 3, 4, 5]);
+			assert_eq!(Collective::members(), vec![1, 3, 4]);
+			assert_eq!(Collective::prime(), Some(4));
+		});
+	}
+
 	#[test]
 	fn removal_of_old_voters_votes_works() {
 		new_test_ext().execute_with(|| {
@@ -1259,6 +2144,50 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn removal_of_old_voters_votes_is_spread_over_blocks() {
+		new_test_ext().execute_with(|| {
+			use frame_support::traits::OnInitialize;
+			MaxVoteCleanupsPerBlock::set(1);
+			let end = 4;
+			let hashes = (0..3).map(|i| {
+				let proposal = make_proposal(i);
+				let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+				let hash = BlakeTwo256::hash_of(&proposal);
+				assert_ok!(Collective::propose(Origin::signed(1), 3, Box::new(proposal), proposal_len));
+				assert_ok!(Collective::vote(Origin::signed(3), hash, i as u32, false));
+				hash
+			}).collect::<Vec<_>>();
+			let votes = |index, ayes, nays| Some(Votes { index, threshold: 3, ayes, nays, end });
+
+			// only the first motion is cleaned up right away.
+			assert_ok!(Collective::set_members(Origin::root(), vec![2, 3, 4], None, MaxMembers::get()));
+			assert_eq!(Collective::voting(hashes[0]), votes(0, vec![], vec![3]));
+			assert_eq!(Collective::voting(hashes[1]), votes(1, vec![1], vec![3]));
+			assert_eq!(Collective::outgoing_voters(), vec![1]);
+			assert_eq!(Collective::vote_cleanup_queue(), vec![hashes[1], hashes[2]]);
+
+			// votes still to be removed are not counted.
+			assert_ok!(Collective::vote(Origin::signed(2), hashes[1], 1, true));
+			assert_eq!(last_collective_event::<Test, Instance1>(), Some(RawEvent::Voted(2, hashes[1], true, 1, 1)));
+			assert_eq!(Collective::voting(hashes[1]), votes(1, vec![2], vec![3]));
+
+			Collective::on_initialize(2);
+			assert_eq!(Collective::voting(hashes[2]), votes(2, vec![1], vec![3]));
+			Collective::on_initialize(3);
+			assert_eq!(Collective::voting(hashes[2]), votes(2, vec![], vec![3]));
+			assert!(Collective::outgoing_voters().is_empty());
+			assert!(Collective::vote_cleanup_queue().is_empty());
+
+			// an account made a member again keeps the votes that were not removed yet.
+			assert_ok!(Collective::set_members(Origin::root(), vec![2, 4], None, MaxMembers::get()));
+			assert_eq!(Collective::voting(hashes[0]), votes(0, vec![], vec![]));
+			assert_ok!(Collective::set_members(Origin::root(), vec![2, 3, 4], None, MaxMembers::get()));
+			assert!(Collective::vote_cleanup_queue().is_empty());
+			assert_eq!(Collective::voting(hashes[1]), votes(1, vec![2], vec![3]));
+		});
+	}
+
 	#[test]
 	fn removal_of_old_voters_votes_works_with_set_members() {
 		new_test_ext().execute_with(|| {
@@ -1570,6 +2499,49 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn proposals_keep_their_order_and_indices_are_not_reused() {
+		new_test_ext().execute_with(|| {
+			let proposals = (0..3).map(make_proposal).collect::<Vec<_>>();
+			let hashes = proposals.iter().map(BlakeTwo256::hash_of).collect::<Vec<H256>>();
+			for proposal in proposals.iter() {
+				let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+				assert_ok!(Collective::propose(Origin::signed(1), 3, Box::new(proposal.clone()), proposal_len));
+			}
+			assert_eq!(Collective::proposals(), hashes);
+			assert_eq!(Collective::proposal_index_of(hashes[1]), Some(1));
+
+			// closing a motion keeps the order of the others.
+			let proposal_len: u32 = proposals[1].using_encoded(|p| p.len() as u32);
+			let proposal_weight = proposals[1].get_dispatch_info().weight;
+			assert_ok!(Collective::vote(Origin::signed(2), hashes[1], 1, false));
+			assert_ok!(Collective::close(Origin::signed(2), hashes[1], 1, proposal_weight, proposal_len));
+			assert_eq!(Collective::proposals(), vec![hashes[0], hashes[2]]);
+			assert_eq!(Collective::proposal_index_of(hashes[1]), None);
+
+			// proposing it again appends it with a new index.
+			assert_ok!(Collective::propose(Origin::signed(1), 3, Box::new(proposals[1].clone()), proposal_len));
+			assert_eq!(Collective::proposals(), vec![hashes[0], hashes[2], hashes[1]]);
+			assert_eq!(Collective::proposal_index_of(hashes[1]), Some(3));
+			assert_eq!(Collective::proposal_count(), 4);
+		});
+	}
+
+	#[test]
+	fn proposal_indices_are_never_reused() {
+		new_test_ext().execute_with(|| {
+			ProposalCount::<Instance1>::put(ProposalIndex::MAX);
+			let proposal = make_proposal(42);
+			let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+			assert_noop!(
+				Collective::propose(Origin::signed(1), 3, Box::new(proposal.clone()), proposal_len),
+				Error::<Test, Instance1>::ProposalIndicesExhausted
+			);
+			// proposals executed right away need no index.
+			assert_ok!(Collective::propose(Origin::signed(1), 1, Box::new(proposal.clone()), proposal_len));
+		});
+	}
+
 	#[test]
 	fn motions_disapproval_works() {
 		new_test_ext().execute_with(|| {
@@ -1721,7 +2693,6 @@ mod tests {
 			assert_ok!(Collective::vote(Origin::signed(2), hash.clone(), 0, true));
 			// But Root can disapprove and remove it anyway
 			assert_ok!(Collective::disapprove_proposal(Origin::root(), hash.clone()));
-			let record = |event| EventRecord { phase: Phase::Initialization, event, topics: vec![] };
 			assert_eq!(System::events(), vec![
 				record(Event::collective_Instance1(RawEvent::Proposed(1, 0, hash.clone(), 2))),
 				record(Event::collective_Instance1(RawEvent::Voted(2, hash.clone(), true, 2, 0))),
@@ -0,0 +1,67 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Helpers for tests of runtimes that include a collective.
+//!
+//! Enabled by the `test-utils` feature.
+
+use super::*;
+use sp_std::convert::TryInto;
+use frame_system::{EventRecord, Phase};
+use sp_runtime::traits::Member;
+
+/// The genesis config of a collective instance `I` starting with `members`.
+#[cfg(feature = "std")]
+pub fn genesis_config<T: Config<I>, I: Instance>(members: Vec<T::AccountId>) -> GenesisConfig<T, I> {
+	GenesisConfig {
+		phantom: Default::default(),
+		members,
+	}
+}
+
+/// A proposal that only makes a remark. Different `value`s give different proposal hashes.
+pub fn make_proposal<T: Config<I>, I: Instance>(value: u64) -> <T as Config<I>>::Proposal {
+	frame_system::Call::<T>::remark(value.encode()).into()
+}
+
+/// The length and weight bounds to pass to `propose` and `close` for `proposal`.
+pub fn proposal_bounds<T: Config<I>, I: Instance>(proposal: &<T as Config<I>>::Proposal) -> (u32, Weight) {
+	(proposal.using_encoded(|p| p.len() as u32), proposal.get_dispatch_info().weight)
+}
+
+/// An event record as deposited during block initialization, e.g. by calls made directly from
+/// tests.
+pub fn record<E: Parameter + Member, Hash>(event: E) -> EventRecord<E, Hash> {
+	EventRecord { phase: Phase::Initialization, event, topics: vec![] }
+}
+
+/// The events deposited so far by the collective instance `I`, in order.
+pub fn collective_events<T: Config<I>, I: Instance>() -> Vec<Event<T, I>> where
+	<T as frame_system::Config>::Event: TryInto<Event<T, I>>,
+{
+	frame_system::Module::<T>::events()
+		.into_iter()
+		.filter_map(|r| r.event.try_into().ok())
+		.collect()
+}
+
+/// The last event deposited by the collective instance `I`, if any.
+pub fn last_collective_event<T: Config<I>, I: Instance>() -> Option<Event<T, I>> where
+	<T as frame_system::Config>::Event: TryInto<Event<T, I>>,
+{
+	collective_events::<T, I>().pop()
+}
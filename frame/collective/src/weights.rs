@@ -53,6 +53,10 @@ pub trait WeightInfo {
 	fn close_disapproved(_m: u32, _p: u32, ) -> Weight;
 	fn close_approved(_b: u32, _m: u32, _p: u32, ) -> Weight;
 	fn disapprove_proposal(_p: u32, ) -> Weight;
+	fn ban_member(_m: u32, _p: u32, ) -> Weight;
+	fn on_initialize_vote_cleanup(_p: u32, ) -> Weight;
+	fn propose_with_origin(_b: u32, _m: u32, _p: u32, ) -> Weight;
+	fn propose_emergency(_b: u32, _m: u32, _p: u32, ) -> Weight;
 
 }
 
@@ -65,6 +69,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add((254_000 as Weight).saturating_mul(n as Weight))
 			.saturating_add((28_233_000 as Weight).saturating_mul(p as Weight))
 			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(n as Weight)))
 			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(p as Weight)))
 			.saturating_add(T::DbWeight::get().writes(2 as Weight))
 			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(p as Weight)))
@@ -89,7 +94,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add((138_000 as Weight).saturating_mul(m as Weight))
 			.saturating_add((637_000 as Weight).saturating_mul(p as Weight))
 			.saturating_add(T::DbWeight::get().reads(4 as Weight))
-			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
 
 	}
 	fn vote(m: u32, ) -> Weight {
@@ -103,8 +108,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		(61_406_000 as Weight)
 			.saturating_add((225_000 as Weight).saturating_mul(m as Weight))
 			.saturating_add((630_000 as Weight).saturating_mul(p as Weight))
-			.saturating_add(T::DbWeight::get().reads(3 as Weight))
-			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
 
 	}
 	fn close_early_approved(b: u32, m: u32, p: u32, ) -> Weight {
@@ -112,16 +117,16 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add((1_000 as Weight).saturating_mul(b as Weight))
 			.saturating_add((233_000 as Weight).saturating_mul(m as Weight))
 			.saturating_add((597_000 as Weight).saturating_mul(p as Weight))
-			.saturating_add(T::DbWeight::get().reads(4 as Weight))
-			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
 
 	}
 	fn close_disapproved(m: u32, p: u32, ) -> Weight {
 		(67_942_000 as Weight)
 			.saturating_add((232_000 as Weight).saturating_mul(m as Weight))
 			.saturating_add((636_000 as Weight).saturating_mul(p as Weight))
-			.saturating_add(T::DbWeight::get().reads(4 as Weight))
-			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
 
 	}
 	fn close_approved(b: u32, m: u32, p: u32, ) -> Weight {
@@ -129,17 +134,50 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add((1_000 as Weight).saturating_mul(b as Weight))
 			.saturating_add((233_000 as Weight).saturating_mul(m as Weight))
 			.saturating_add((598_000 as Weight).saturating_mul(p as Weight))
-			.saturating_add(T::DbWeight::get().reads(5 as Weight))
-			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
 
 	}
 	fn disapprove_proposal(p: u32, ) -> Weight {
 		(36_628_000 as Weight)
 			.saturating_add((640_000 as Weight).saturating_mul(p as Weight))
-			.saturating_add(T::DbWeight::get().reads(1 as Weight))
-			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
 
 	}
+	fn ban_member(m: u32, p: u32, ) -> Weight {
+		(24_516_000 as Weight)
+			.saturating_add((112_000 as Weight).saturating_mul(m as Weight))
+			.saturating_add((28_233_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(p as Weight)))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(p as Weight)))
+	}
+	fn on_initialize_vote_cleanup(p: u32, ) -> Weight {
+		(3_071_000 as Weight)
+			.saturating_add((26_514_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(p as Weight)))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(p as Weight)))
+	}
+	fn propose_with_origin(b: u32, m: u32, p: u32, ) -> Weight {
+		(67_418_000 as Weight)
+			.saturating_add((5_000 as Weight).saturating_mul(b as Weight))
+			.saturating_add((138_000 as Weight).saturating_mul(m as Weight))
+			.saturating_add((637_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+	}
+	fn propose_emergency(b: u32, m: u32, p: u32, ) -> Weight {
+		(66_974_000 as Weight)
+			.saturating_add((5_000 as Weight).saturating_mul(b as Weight))
+			.saturating_add((138_000 as Weight).saturating_mul(m as Weight))
+			.saturating_add((637_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
 
 }
 
@@ -151,6 +189,7 @@ impl WeightInfo for () {
 			.saturating_add((254_000 as Weight).saturating_mul(n as Weight))
 			.saturating_add((28_233_000 as Weight).saturating_mul(p as Weight))
 			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(n as Weight)))
 			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(p as Weight)))
 			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
 			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(p as Weight)))
@@ -175,7 +214,7 @@ impl WeightInfo for () {
 			.saturating_add((138_000 as Weight).saturating_mul(m as Weight))
 			.saturating_add((637_000 as Weight).saturating_mul(p as Weight))
 			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
 
 	}
 	fn vote(m: u32, ) -> Weight {
@@ -189,8 +228,8 @@ impl WeightInfo for () {
 		(61_406_000 as Weight)
 			.saturating_add((225_000 as Weight).saturating_mul(m as Weight))
 			.saturating_add((630_000 as Weight).saturating_mul(p as Weight))
-			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
 
 	}
 	fn close_early_approved(b: u32, m: u32, p: u32, ) -> Weight {
@@ -198,16 +237,16 @@ impl WeightInfo for () {
 			.saturating_add((1_000 as Weight).saturating_mul(b as Weight))
 			.saturating_add((233_000 as Weight).saturating_mul(m as Weight))
 			.saturating_add((597_000 as Weight).saturating_mul(p as Weight))
-			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
 
 	}
 	fn close_disapproved(m: u32, p: u32, ) -> Weight {
 		(67_942_000 as Weight)
 			.saturating_add((232_000 as Weight).saturating_mul(m as Weight))
 			.saturating_add((636_000 as Weight).saturating_mul(p as Weight))
-			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
 
 	}
 	fn close_approved(b: u32, m: u32, p: u32, ) -> Weight {
@@ -215,16 +254,49 @@ impl WeightInfo for () {
 			.saturating_add((1_000 as Weight).saturating_mul(b as Weight))
 			.saturating_add((233_000 as Weight).saturating_mul(m as Weight))
 			.saturating_add((598_000 as Weight).saturating_mul(p as Weight))
-			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
 
 	}
 	fn disapprove_proposal(p: u32, ) -> Weight {
 		(36_628_000 as Weight)
 			.saturating_add((640_000 as Weight).saturating_mul(p as Weight))
-			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
 
 	}
+	fn ban_member(m: u32, p: u32, ) -> Weight {
+		(24_516_000 as Weight)
+			.saturating_add((112_000 as Weight).saturating_mul(m as Weight))
+			.saturating_add((28_233_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(p as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(p as Weight)))
+	}
+	fn on_initialize_vote_cleanup(p: u32, ) -> Weight {
+		(3_071_000 as Weight)
+			.saturating_add((26_514_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(p as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(p as Weight)))
+	}
+	fn propose_with_origin(b: u32, m: u32, p: u32, ) -> Weight {
+		(67_418_000 as Weight)
+			.saturating_add((5_000 as Weight).saturating_mul(b as Weight))
+			.saturating_add((138_000 as Weight).saturating_mul(m as Weight))
+			.saturating_add((637_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
+	}
+	fn propose_emergency(b: u32, m: u32, p: u32, ) -> Weight {
+		(66_974_000 as Weight)
+			.saturating_add((5_000 as Weight).saturating_mul(b as Weight))
+			.saturating_add((138_000 as Weight).saturating_mul(m as Weight))
+			.saturating_add((637_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+	}
 
 }
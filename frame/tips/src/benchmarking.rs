@@ -41,6 +41,15 @@ fn setup_awesome<T: Config>(length: u32) -> (T::AccountId, Vec<u8>, T::AccountId
 	(caller, reason, awesome_person)
 }
 
+// Create `s` beneficiaries with shares adding up to 100%.
+fn setup_shares<T: Config>(s: u32) -> Vec<(T::AccountId, Permill)> {
+	let parts = Permill::one().deconstruct();
+	(0 .. s).map(|i| {
+		let share = if i == s - 1 { parts - (parts / s) * (s - 1) } else { parts / s };
+		(account("beneficiary", i, SEED), Permill::from_parts(share))
+	}).collect()
+}
+
 // Create the pre-requisite information needed to call `tip_new`.
 fn setup_tip<T: Config>(r: u32, t: u32) ->
 	Result<(T::AccountId, Vec<u8>, T::AccountId, BalanceOf<T>), &'static str>
@@ -87,6 +96,7 @@ fn setup_pot_account<T: Config>() {
 
 const MAX_BYTES: u32 = 16384;
 const MAX_TIPPERS: u32 = 100;
+const MAX_TIP_SHARES: u32 = 10;
 
 benchmarks! {
 	report_awesome {
@@ -95,7 +105,18 @@ benchmarks! {
 		// Whitelist caller account from further DB operations.
 		let caller_key = frame_system::Account::<T>::hashed_key_for(&caller);
 		frame_benchmarking::benchmarking::add_to_whitelist(caller_key.into());
-	}: _(RawOrigin::Signed(caller), reason, awesome_person)
+	}: _(RawOrigin::Signed(caller), reason, ReasonKind::Url, awesome_person)
+
+	report_awesome_shared {
+		let r in 0 .. MAX_BYTES;
+		let s in 1 .. MAX_TIP_SHARES;
+		let shares = setup_shares::<T>(s);
+		let (caller, reason, _) = setup_awesome::<T>(r + shares.encoded_size() as u32);
+		let reason = reason[.. r as usize].to_vec();
+		// Whitelist caller account from further DB operations.
+		let caller_key = frame_system::Account::<T>::hashed_key_for(&caller);
+		frame_benchmarking::benchmarking::add_to_whitelist(caller_key.into());
+	}: _(RawOrigin::Signed(caller), reason, ReasonKind::Url, shares)
 
 	retract_tip {
 		let r = MAX_BYTES;
@@ -103,6 +124,7 @@ benchmarks! {
 		TipsMod::<T>::report_awesome(
 			RawOrigin::Signed(caller.clone()).into(),
 			reason.clone(),
+			ReasonKind::Url,
 			awesome_person.clone()
 		)?;
 		let reason_hash = T::Hashing::hash(&reason[..]);
@@ -120,23 +142,27 @@ benchmarks! {
 		// Whitelist caller account from further DB operations.
 		let caller_key = frame_system::Account::<T>::hashed_key_for(&caller);
 		frame_benchmarking::benchmarking::add_to_whitelist(caller_key.into());
-	}: _(RawOrigin::Signed(caller), reason, beneficiary, value)
+	}: _(RawOrigin::Signed(caller), reason, ReasonKind::Url, beneficiary, value)
 
 	tip {
 		let t in 1 .. MAX_TIPPERS;
-		let (member, reason, beneficiary, value) = setup_tip::<T>(0, t)?;
+		let d in 1 .. T::MaxTipDeclarations::get();
+		// every declaration is made by a different tipper.
+		let tippers = t.max(d);
+		let (member, reason, beneficiary, value) = setup_tip::<T>(0, tippers)?;
 		let value = T::Currency::minimum_balance().saturating_mul(100u32.into());
 		TipsMod::<T>::tip_new(
 			RawOrigin::Signed(member).into(),
 			reason.clone(),
+			ReasonKind::Url,
 			beneficiary.clone(),
 			value
 		)?;
 		let reason_hash = T::Hashing::hash(&reason[..]);
 		let hash = T::Hashing::hash_of(&(&reason_hash, &beneficiary));
 		ensure!(Tips::<T>::contains_key(hash), "tip does not exist");
-		create_tips::<T>(t - 1, hash.clone(), value)?;
-		let caller = account("member", t - 1, SEED);
+		create_tips::<T>(d - 1, hash.clone(), value)?;
+		let caller = account("member", tippers - 1, SEED);
 		// Whitelist caller account from further DB operations.
 		let caller_key = frame_system::Account::<T>::hashed_key_for(&caller);
 		frame_benchmarking::benchmarking::add_to_whitelist(caller_key.into());
@@ -144,6 +170,7 @@ benchmarks! {
 
 	close_tip {
 		let t in 1 .. MAX_TIPPERS;
+		let s in 0 .. MAX_TIP_SHARES;
 
 		// Make sure pot is funded
 		setup_pot_account::<T>();
@@ -154,6 +181,7 @@ benchmarks! {
 		TipsMod::<T>::tip_new(
 			RawOrigin::Signed(member).into(),
 			reason.clone(),
+			ReasonKind::Url,
 			beneficiary.clone(),
 			value
 		)?;
@@ -164,6 +192,9 @@ benchmarks! {
 		ensure!(Tips::<T>::contains_key(hash), "tip does not exist");
 
 		create_tips::<T>(t, hash.clone(), value)?;
+		if s > 0 {
+			TipShares::<T>::insert(hash, setup_shares::<T>(s));
+		}
 
 		let caller = account("caller", t, SEED);
 		// Whitelist caller account from further DB operations.
@@ -183,6 +214,7 @@ benchmarks! {
 		TipsMod::<T>::tip_new(
 			RawOrigin::Signed(member).into(),
 			reason.clone(),
+			ReasonKind::Url,
 			beneficiary.clone(),
 			value
 		)?;
@@ -191,6 +223,22 @@ benchmarks! {
 		let hash = T::Hashing::hash_of(&(&reason_hash, &beneficiary));
 		ensure!(Tips::<T>::contains_key(hash), "tip does not exist");
 	}: _(RawOrigin::Root, hash)
+
+	set_min_tippers_for_close {
+		let t in 1 .. MAX_TIPPERS;
+		setup_tip::<T>(0, t)?;
+	}: _(RawOrigin::Root, Some(1))
+	verify {
+		ensure!(TipsMod::<T>::min_tippers_for_close() == 1, "override not set");
+	}
+
+	reset_tip_cooldown {
+		let beneficiary: T::AccountId = account("beneficiary", 0, SEED);
+		LastTipClosed::<T>::insert(&beneficiary, frame_system::Module::<T>::block_number());
+	}: _(RawOrigin::Root, beneficiary.clone())
+	verify {
+		ensure!(TipsMod::<T>::last_tip_closed(&beneficiary).is_none(), "cooldown not reset");
+	}
 }
 
 #[cfg(test)]
@@ -203,11 +251,14 @@ mod tests {
 	fn test_benchmarks() {
 		new_test_ext().execute_with(|| {
 			assert_ok!(test_benchmark_report_awesome::<Test>());
+			assert_ok!(test_benchmark_report_awesome_shared::<Test>());
 			assert_ok!(test_benchmark_retract_tip::<Test>());
 			assert_ok!(test_benchmark_tip_new::<Test>());
 			assert_ok!(test_benchmark_tip::<Test>());
 			assert_ok!(test_benchmark_close_tip::<Test>());
 			assert_ok!(test_benchmark_slash_tip::<Test>());
+			assert_ok!(test_benchmark_set_min_tippers_for_close::<Test>());
+			assert_ok!(test_benchmark_reset_tip_cooldown::<Test>());
 		});
 	}
 }
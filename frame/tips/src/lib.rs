@@ -22,11 +22,18 @@
 //! A subsystem to allow for an agile "tipping" process, whereby a reward may be given without first
 //! having a pre-determined stakeholder group come to consensus on how much should be paid.
 //!
-//! A group of `Tippers` is determined through the config `Config`. After half of these have declared
-//! some amount that they believe a particular reported reason deserves, then a countdown period is
-//! entered where any remaining members can declare their tip amounts also. After the close of the
-//! countdown period, the median of all declared tips is paid to the reported beneficiary, along
-//! with any finders fee, in case of a public (and bonded) original report.
+//! A group of `Tippers` is determined through the config `Config`. After half of these, and at
+//! least `MinTippersForClose`, have declared some amount that they believe a particular reported
+//! reason deserves, then a countdown period is entered where any remaining members can declare
+//! their tip amounts also. After the close of the countdown period, the median of all declared
+//! tips is paid to the reported beneficiary, along with any finders fee, in case of a public (and
+//! bonded) original report.
+//!
+//! At the end of each treasury spend period, the tips paid out during it are summarized in a
+//! single `TipsPeriodSummary` event.
+//!
+//! A `TipFundingSource`, such as the bounties pallet, may co-fund the tips for some reasons, in
+//! which case it pays its part of a tip before the treasury pays the rest.
 //!
 //!
 //! ### Terminology
@@ -37,9 +44,15 @@
 //! - **Tip Reason:** The reason for a tip; generally a URL which embodies or explains why a
 //!   particular individual (identified by an account ID) is worthy of a recognition by the
 //!   treasury.
+//! - **Reason Kind:** How a tip reason is encoded (URL, IPFS CID, plain text or Markdown), as
+//!   given by whoever reported it.
 //! - **Finder:** The original public reporter of some reason for tipping.
 //! - **Finders Fee:** Some proportion of the tip amount that is paid to the reporter of the tip,
 //!   rather than the main beneficiary.
+//! - **Trusted Finder:** A finder whose reports need fewer tippers to declare a value before they
+//!   start closing, as judged by the `TrustProvider`.
+//! - **Self-found Tip:** A tip reported by one of its beneficiaries. It pays no finders fee and
+//!   may be disallowed through `AllowSelfReport`.
 //!
 //! ## Interface
 //!
@@ -47,16 +60,22 @@
 //!
 //! Tipping protocol:
 //! - `report_awesome` - Report something worthy of a tip and register for a finders fee.
+//! - `report_awesome_shared` - Report something worthy of a tip shared between several accounts
+//!   and register for a finders fee.
 //! - `retract_tip` - Retract a previous (finders fee registered) report.
 //! - `tip_new` - Report an item worthy of a tip and declare a specific amount to tip.
 //! - `tip` - Declare or redeclare an amount to tip for a particular reason.
 //! - `close_tip` - Close and pay out a tip.
+//! - `set_min_tippers_for_close` - Override `MinTippersForClose`, e.g. on a bootstrap network.
+//! - `reset_tip_cooldown` - Let a new tip for an account open before `BeneficiaryTipCooldown`
+//!   has passed.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
 mod tests;
 mod benchmarking;
 pub mod weights;
+pub mod migrations;
 
 use sp_std::prelude::*;
 use frame_support::{decl_module, decl_storage, decl_event, ensure, decl_error, Parameter};
@@ -65,10 +84,12 @@ use frame_support::traits::{
 	ReservableCurrency
 };
 
-use sp_runtime::{ Percent, RuntimeDebug, traits::{
-	Zero, AccountIdConversion, Hash, BadOrigin
+use sp_runtime::{ Percent, Permill, RuntimeDebug, DispatchError, traits::{
+	Zero, AccountIdConversion, Hash, BadOrigin, Saturating
 }};
 use frame_support::traits::{Contains, ContainsLengthBound, OnUnbalanced, EnsureOrigin};
+use frame_support::dispatch::DispatchResultWithPostInfo;
+use frame_support::weights::Weight;
 use codec::{Encode, Decode};
 use frame_system::{self as system, ensure_signed};
 pub use weights::WeightInfo;
@@ -76,6 +97,38 @@ pub use weights::WeightInfo;
 pub type BalanceOf<T> = pallet_treasury::BalanceOf<T>;
 pub type NegativeImbalanceOf<T> = pallet_treasury::NegativeImbalanceOf<T>;
 
+/// An account that co-funds the tips for some reasons alongside the treasury, such as the
+/// account of a bounty whose curator chose to reward contributions to it.
+///
+/// `()` never co-funds a tip.
+pub trait TipFundingSource<AccountId, Hash, Balance> {
+	/// The account that co-funds tips for the reason hashed to `reason`, and the most it pays
+	/// towards any one of them.
+	fn co_funding(reason: &Hash) -> Option<(AccountId, Balance)>;
+}
+
+impl<AccountId, Hash, Balance> TipFundingSource<AccountId, Hash, Balance> for () {
+	fn co_funding(_: &Hash) -> Option<(AccountId, Balance)> {
+		None
+	}
+}
+
+/// Judges how far the reports of a finder can be trusted, e.g. by whether a registrar verified
+/// their identity.
+///
+/// `()` trusts nobody.
+pub trait TrustProvider<AccountId> {
+	/// How many fewer tippers need to declare a value for a tip reported by `finder` before it
+	/// starts closing.
+	fn threshold_reduction(finder: &AccountId) -> u32;
+}
+
+impl<AccountId> TrustProvider<AccountId> for () {
+	fn threshold_reduction(_: &AccountId) -> u32 {
+		0
+	}
+}
+
 pub trait Config: frame_system::Config + pallet_treasury::Config {
 	/// Maximum acceptable reason length.
 	type MaximumReasonLength: Get<u32>;
@@ -91,12 +144,49 @@ pub trait Config: frame_system::Config + pallet_treasury::Config {
 	/// The period for which a tip remains open after is has achieved threshold tippers.
 	type TipCountdown: Get<Self::BlockNumber>;
 
+	/// The least number of tippers that must declare a tip value before a tip starts closing,
+	/// whatever the number of `Tippers`. Guards against a single tipper deciding a tip.
+	type MinTippersForClose: Get<u32>;
+
+	/// Origin from which `MinTippersForClose` may be overridden, e.g. on a bootstrap network with
+	/// fewer tippers.
+	type MinTippersOrigin: EnsureOrigin<Self::Origin>;
+
+	/// The number of blocks after a tip to an account closes before a new tip for that account
+	/// may be opened.
+	type BeneficiaryTipCooldown: Get<Self::BlockNumber>;
+
+	/// Origin from which the `BeneficiaryTipCooldown` of an account may be lifted in exceptional
+	/// cases.
+	type TipCooldownOverrideOrigin: EnsureOrigin<Self::Origin>;
+
 	/// The percent of the final tip which goes to the original reporter of the tip.
 	type TipFindersFee: Get<Percent>;
 
+	/// Whether an account may report, or open through `tip_new`, a tip of which it is a
+	/// beneficiary. Such tips never pay a finder's fee, but chains that consider self-reporting
+	/// abusive can disallow them.
+	type AllowSelfReport: Get<bool>;
+
 	/// The amount held on deposit for placing a tip report.
 	type TipReportDepositBase: Get<BalanceOf<Self>>;
 
+	/// Maximum number of accounts a single tip can be shared between.
+	type MaxTipShares: Get<u32>;
+
+	/// Maximum number of tip values retained for a single tip, one per tipper.
+	///
+	/// Should be no less than the maximum number of `Tippers`, or some tippers may not be able to
+	/// declare a value, and must be more than half of it for tips to close.
+	type MaxTipDeclarations: Get<u32>;
+
+	/// Co-funds the tips for some reasons; `()` leaves all tips to the treasury.
+	type TipFundingSource: TipFundingSource<Self::AccountId, Self::Hash, BalanceOf<Self>>;
+
+	/// Lowers the number of tippers needed for the tips of trusted finders to start closing,
+	/// though never below `MinTippersForClose`; `()` trusts nobody.
+	type TrustProvider: TrustProvider<Self::AccountId>;
+
 	/// The overarching event type.
 	type Event: From<Event<Self>> + Into<<Self as frame_system::Config>::Event>;
 
@@ -104,6 +194,20 @@ pub trait Config: frame_system::Config + pallet_treasury::Config {
 	type WeightInfo: WeightInfo;
 }
 
+/// How the reason of a tip is encoded, as given by whoever reported it, so that it can be
+/// displayed without guessing from its bytes.
+#[derive(Clone, Copy, Eq, PartialEq, Encode, Decode, RuntimeDebug)]
+pub enum ReasonKind {
+	/// A UTF-8-encoded URL.
+	Url,
+	/// An IPFS content identifier.
+	IpfsCid,
+	/// UTF-8-encoded plain text.
+	PlainText,
+	/// UTF-8-encoded Markdown.
+	Markdown,
+}
+
 /// An open tipping "motion". Retains all details of a tip including information on the finder
 /// and the members who have voted.
 #[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug)]
@@ -125,12 +229,24 @@ pub struct OpenTip<
 	/// The block number at which this tip will close if `Some`. If `None`, then no closing is
 	/// scheduled.
 	closes: Option<BlockNumber>,
-	/// The members who have voted for this tip. Sorted by AccountId.
+	/// The members who have voted for this tip. Sorted by AccountId. Holds at most
+	/// `MaxTipDeclarations` entries.
 	tips: Vec<(AccountId, Balance)>,
 	/// Whether this tip should result in the finder taking a fee.
 	finders_fee: bool,
 }
 
+/// Running totals of the tips paid out during the current spend period.
+#[derive(Clone, Default, Eq, PartialEq, Encode, Decode, RuntimeDebug)]
+pub struct TipsPeriodTotals<Balance> {
+	/// The amount paid out for tips, finder's fees and any co-funded part included.
+	total_paid: Balance,
+	/// The number of tips that were closed.
+	tips_closed: u32,
+	/// The largest amount paid out for a single tip.
+	largest_payout: Balance,
+}
+
 // Note :: For backward compatability reasons,
 // pallet-tips uses Treasury for storage.
 // This is temporary solution, soon will get replaced with
@@ -145,10 +261,51 @@ decl_storage! {
 			map hasher(twox_64_concat) T::Hash
 			=> Option<OpenTip<T::AccountId, BalanceOf<T>, T::BlockNumber, T::Hash>>;
 
+		/// The least number of tippers needed for a tip to start closing, if overridden from
+		/// `MinTippersForClose`.
+		pub MinTippersForCloseOverride get(fn min_tippers_for_close_override): Option<u32>;
+
 		/// Simple preimage lookup from the reason's hash to the original data. Again, has an
 		/// insecure enumerable hash since the key is guaranteed to be the result of a secure hash.
 		pub Reasons get(fn reasons): map hasher(identity) T::Hash => Option<Vec<u8>>;
 
+		/// The kind of each reason in `Reasons`. Reasons stored before kinds were recorded have
+		/// none.
+		pub ReasonKinds get(fn reason_kind): map hasher(identity) T::Hash => Option<ReasonKind>;
+
+		/// The beneficiaries of open tips reported through `report_awesome_shared`, along with
+		/// their share of the payout. The `who` of such a tip is its first beneficiary. Kept apart
+		/// from `Tips`, rather than in a shared variant of `OpenTip`, so that the encoding of
+		/// open tips stays the same and needs no migration.
+		pub TipShares get(fn tip_shares):
+			map hasher(twox_64_concat) T::Hash => Option<Vec<(T::AccountId, Permill)>>;
+
+		/// Totals of the tips paid out since the start of the current spend period. Reported
+		/// through `TipsPeriodSummary` and reset at the end of the period.
+		pub PeriodTipTotals get(fn period_tip_totals): TipsPeriodTotals<BalanceOf<T>>;
+
+		/// The total ever paid out by the tips for each reason hash, across all beneficiaries and
+		/// finder's fees. Unlike `Reasons`, kept after the tips close so that a reason used for
+		/// repeated payouts can be spotted.
+		pub ReasonPayoutTotals get(fn reason_payout_total):
+			map hasher(identity) T::Hash => BalanceOf<T>;
+
+		/// How many tippers the open tips of trusted finders need before they start closing, as
+		/// reduced by `TrustProvider` when they were reported. Tips of other finders have none,
+		/// and need a majority of `Tippers`.
+		pub TrustedTipThresholds get(fn trusted_tip_threshold):
+			map hasher(twox_64_concat) T::Hash => Option<u32>;
+
+		/// The block in which the last tip to each account closed. No new tip for the account may
+		/// be opened until `BeneficiaryTipCooldown` blocks later, when the entry is removed.
+		pub LastTipClosed get(fn last_tip_closed):
+			map hasher(twox_64_concat) T::AccountId => Option<T::BlockNumber>;
+
+		/// The accounts whose `BeneficiaryTipCooldown` ends in each block, so that their
+		/// `LastTipClosed` entries can be removed then.
+		pub TipCooldownExpiries get(fn tip_cooldown_expiries):
+			map hasher(twox_64_concat) T::BlockNumber => Vec<T::AccountId>;
+
 	}
 }
 
@@ -159,16 +316,28 @@ decl_event!(
 		<T as frame_system::Config>::AccountId,
 		<T as frame_system::Config>::Hash,
 	{
-		/// A new tip suggestion has been opened. \[tip_hash\]
-		NewTip(Hash),
+		/// A new tip suggestion has been opened. \[tip_hash, reason_kind\]
+		NewTip(Hash, ReasonKind),
 		/// A tip suggestion has reached threshold and is closing. \[tip_hash\]
 		TipClosing(Hash),
-		/// A tip suggestion has been closed. \[tip_hash, who, payout\]
-		TipClosed(Hash, AccountId, Balance),
+		/// A tip suggestion has been closed. `self_found` is whether `who` also reported the tip,
+		/// in which case no finder's fee was taken from their payout.
+		/// \[tip_hash, who, payout, self_found\]
+		TipClosed(Hash, AccountId, Balance, bool),
 		/// A tip suggestion has been retracted. \[tip_hash\]
 		TipRetracted(Hash),
 		/// A tip suggestion has been slashed. \[tip_hash, finder, deposit\]
 		TipSlashed(Hash, AccountId, Balance),
+		/// A spend period has ended, with the tips paid out during it.
+		/// \[total_paid, tips_closed, largest_payout\]
+		TipsPeriodSummary(Balance, u32, Balance),
+		/// Part of a tip was paid by a co-funding account. \[tip_hash, funder, amount\]
+		TipCoFunded(Hash, AccountId, Balance),
+		/// The least number of tippers needed for a tip to start closing was set.
+		/// \[min_tippers\]
+		MinTippersForCloseSet(u32),
+		/// The tip cooldown of an account was lifted. \[who\]
+		TipCooldownReset(AccountId),
 	}
 );
 
@@ -187,6 +356,18 @@ decl_error! {
 		StillOpen,
 		/// The tip cannot be claimed/closed because it's still in the countdown period.
 		Premature,
+		/// The shares of a tip must name distinct accounts and add up to exactly 100%.
+		InvalidShares,
+		/// The tip is shared between too many accounts.
+		TooManyShares,
+		/// The tip already holds the maximum number of declared tip values.
+		TooManyDeclarations,
+		/// A tip to the beneficiary closed less than `BeneficiaryTipCooldown` blocks ago.
+		TipCooldown,
+		/// The finder of a tip may not be one of its beneficiaries.
+		SelfReport,
+		/// More tippers would be needed for a tip to start closing than there are.
+		TooManyMinTippers,
 	}
 }
 
@@ -199,6 +380,9 @@ decl_module! {
 		/// The period for which a tip remains open after is has achieved threshold tippers.
 		const TipCountdown: T::BlockNumber = T::TipCountdown::get();
 
+		/// The least number of tippers that must declare a tip value before a tip starts closing.
+		const MinTippersForClose: u32 = T::MinTippersForClose::get();
+
 		/// The amount of the final tip which goes to the original reporter of the tip.
 		const TipFindersFee: Percent = T::TipFindersFee::get();
 
@@ -211,6 +395,19 @@ decl_module! {
 		/// Maximum acceptable reason length.
 		const MaximumReasonLength: u32 = T::MaximumReasonLength::get();
 
+		/// Maximum number of accounts a single tip can be shared between.
+		const MaxTipShares: u32 = T::MaxTipShares::get();
+
+		/// Maximum number of tip values retained for a single tip.
+		const MaxTipDeclarations: u32 = T::MaxTipDeclarations::get();
+
+		/// The number of blocks after a tip to an account closes before a new tip for that account
+		/// may be opened.
+		const BeneficiaryTipCooldown: T::BlockNumber = T::BeneficiaryTipCooldown::get();
+
+		/// Whether an account may report a tip of which it is a beneficiary.
+		const AllowSelfReport: bool = T::AllowSelfReport::get();
+
 		type Error = Error<T>;
 
 		fn deposit_event() = default;
@@ -224,6 +421,7 @@ decl_module! {
 		///
 		/// - `reason`: The reason for, or the thing that deserves, the tip; generally this will be
 		///   a UTF-8-encoded URL.
+		/// - `kind`: How `reason` is encoded.
 		/// - `who`: The account which should be credited for the tip.
 		///
 		/// Emits `NewTip` if successful.
@@ -231,11 +429,11 @@ decl_module! {
 		/// # <weight>
 		/// - Complexity: `O(R)` where `R` length of `reason`.
 		///   - encoding and hashing of 'reason'
-		/// - DbReads: `Reasons`, `Tips`
-		/// - DbWrites: `Reasons`, `Tips`
+		/// - DbReads: `Reasons`, `Tips`, `TrustProvider`, `LastTipClosed`
+		/// - DbWrites: `Reasons`, `ReasonKinds`, `Tips`, `TrustedTipThresholds`
 		/// # </weight>
 		#[weight = <T as Config>::WeightInfo::report_awesome(reason.len() as u32)]
-		fn report_awesome(origin, reason: Vec<u8>, who: T::AccountId) {
+		fn report_awesome(origin, reason: Vec<u8>, kind: ReasonKind, who: T::AccountId) {
 			let finder = ensure_signed(origin)?;
 
 			ensure!(reason.len() <= T::MaximumReasonLength::get() as usize, Error::<T>::ReasonTooBig);
@@ -244,12 +442,15 @@ decl_module! {
 			ensure!(!Reasons::<T>::contains_key(&reason_hash), Error::<T>::AlreadyKnown);
 			let hash = T::Hashing::hash_of(&(&reason_hash, &who));
 			ensure!(!Tips::<T>::contains_key(&hash), Error::<T>::AlreadyKnown);
+			ensure!(T::AllowSelfReport::get() || finder != who, Error::<T>::SelfReport);
+			Self::ensure_cooldown_over(&who)?;
 
 			let deposit = T::TipReportDepositBase::get()
 				+ T::DataDepositPerByte::get() * (reason.len() as u32).into();
 			T::Currency::reserve(&finder, deposit)?;
 
 			Reasons::<T>::insert(&reason_hash, &reason);
+			ReasonKinds::<T>::insert(reason_hash, kind);
 			let tip = OpenTip {
 				reason: reason_hash,
 				who,
@@ -259,8 +460,83 @@ decl_module! {
 				tips: vec![],
 				finders_fee: true
 			};
+			Self::note_finder_trust(hash, &tip.finder);
 			Tips::<T>::insert(&hash, tip);
-			Self::deposit_event(RawEvent::NewTip(hash));
+			Self::deposit_event(RawEvent::NewTip(hash, kind));
+		}
+
+		/// Report something `reason` that deserves a tip to be shared between several accounts
+		/// and claim any eventual the finder's fee.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// Payment: `TipReportDepositBase` will be reserved from the origin account, as well as
+		/// `DataDepositPerByte` for each byte in `reason` and in the encoded `shares`.
+		///
+		/// - `reason`: The reason for, or the thing that deserves, the tip; generally this will be
+		///   a UTF-8-encoded URL.
+		/// - `kind`: How `reason` is encoded.
+		/// - `shares`: The accounts which should be credited for the tip, each with its share of
+		///   the payout. Accounts must be distinct and the shares must add up to exactly 100%.
+		///
+		/// The tip is identified by the hash of the tuple of the hash of `reason` and `shares`.
+		///
+		/// Emits `NewTip` if successful.
+		///
+		/// # <weight>
+		/// - Complexity: `O(R + S)` where `R` length of `reason` and `S` the number of shares.
+		///   - encoding and hashing of 'reason' and 'shares'
+		/// - DbReads: `Reasons`, `Tips`, `TrustProvider`, `LastTipClosed` per share
+		/// - DbWrites: `Reasons`, `ReasonKinds`, `Tips`, `TipShares`, `TrustedTipThresholds`
+		/// # </weight>
+		#[weight = <T as Config>::WeightInfo::report_awesome_shared(
+			reason.len() as u32,
+			shares.len() as u32,
+		)]
+		fn report_awesome_shared(
+			origin,
+			reason: Vec<u8>,
+			kind: ReasonKind,
+			shares: Vec<(T::AccountId, Permill)>,
+		) {
+			let finder = ensure_signed(origin)?;
+
+			ensure!(reason.len() <= T::MaximumReasonLength::get() as usize, Error::<T>::ReasonTooBig);
+			ensure!(shares.len() <= T::MaxTipShares::get() as usize, Error::<T>::TooManyShares);
+			Self::ensure_valid_shares(&shares)?;
+
+			let reason_hash = T::Hashing::hash(&reason[..]);
+			ensure!(!Reasons::<T>::contains_key(reason_hash), Error::<T>::AlreadyKnown);
+			let hash = T::Hashing::hash_of(&(&reason_hash, &shares));
+			ensure!(!Tips::<T>::contains_key(hash), Error::<T>::AlreadyKnown);
+			ensure!(
+				T::AllowSelfReport::get() || shares.iter().all(|(who, _)| *who != finder),
+				Error::<T>::SelfReport
+			);
+			for (who, _) in shares.iter() {
+				Self::ensure_cooldown_over(who)?;
+			}
+
+			let data_len = reason.len() + shares.encoded_size();
+			let deposit = T::TipReportDepositBase::get()
+				+ T::DataDepositPerByte::get() * (data_len as u32).into();
+			T::Currency::reserve(&finder, deposit)?;
+
+			Reasons::<T>::insert(reason_hash, &reason);
+			ReasonKinds::<T>::insert(reason_hash, kind);
+			let tip = OpenTip {
+				reason: reason_hash,
+				who: shares[0].0.clone(),
+				finder,
+				deposit,
+				closes: None,
+				tips: vec![],
+				finders_fee: true
+			};
+			Self::note_finder_trust(hash, &tip.finder);
+			Tips::<T>::insert(hash, tip);
+			TipShares::<T>::insert(hash, shares);
+			Self::deposit_event(RawEvent::NewTip(hash, kind));
 		}
 
 		/// Retract a prior tip-report from `report_awesome`, and cancel the process of tipping.
@@ -280,7 +556,7 @@ decl_module! {
 		/// - Complexity: `O(1)`
 		///   - Depends on the length of `T::Hash` which is fixed.
 		/// - DbReads: `Tips`, `origin account`
-		/// - DbWrites: `Reasons`, `Tips`, `origin account`
+		/// - DbWrites: `Reasons`, `ReasonKinds`, `Tips`, `TrustedTipThresholds`, `origin account`
 		/// # </weight>
 		#[weight = <T as Config>::WeightInfo::retract_tip()]
 		fn retract_tip(origin, hash: T::Hash) {
@@ -289,7 +565,10 @@ decl_module! {
 			ensure!(tip.finder == who, Error::<T>::NotFinder);
 
 			Reasons::<T>::remove(&tip.reason);
+			ReasonKinds::<T>::remove(tip.reason);
 			Tips::<T>::remove(&hash);
+			TipShares::<T>::remove(hash);
+			TrustedTipThresholds::<T>::remove(hash);
 			if !tip.deposit.is_zero() {
 				let _ = T::Currency::unreserve(&who, tip.deposit);
 			}
@@ -303,6 +582,7 @@ decl_module! {
 		///
 		/// - `reason`: The reason for, or the thing that deserves, the tip; generally this will be
 		///   a UTF-8-encoded URL.
+		/// - `kind`: How `reason` is encoded.
 		/// - `who`: The account which should be credited for the tip.
 		/// - `tip_value`: The amount of tip that the sender would like to give. The median tip
 		///   value of active tippers will be given to the `who`.
@@ -315,19 +595,28 @@ decl_module! {
 		///     `T` is charged as upper bound given by `ContainsLengthBound`.
 		///     The actual cost depends on the implementation of `T::Tippers`.
 		///   - `O(R)`: hashing and encoding of reason of length `R`
-		/// - DbReads: `Tippers`, `Reasons`
-		/// - DbWrites: `Reasons`, `Tips`
+		/// - DbReads: `Tippers`, `Reasons`, `LastTipClosed`
+		/// - DbWrites: `Reasons`, `ReasonKinds`, `Tips`
 		/// # </weight>
 		#[weight = <T as Config>::WeightInfo::tip_new(reason.len() as u32, T::Tippers::max_len() as u32)]
-		fn tip_new(origin, reason: Vec<u8>, who: T::AccountId, #[compact] tip_value: BalanceOf<T>) {
+		fn tip_new(
+			origin,
+			reason: Vec<u8>,
+			kind: ReasonKind,
+			who: T::AccountId,
+			#[compact] tip_value: BalanceOf<T>,
+		) {
 			let tipper = ensure_signed(origin)?;
 			ensure!(T::Tippers::contains(&tipper), BadOrigin);
 			let reason_hash = T::Hashing::hash(&reason[..]);
 			ensure!(!Reasons::<T>::contains_key(&reason_hash), Error::<T>::AlreadyKnown);
 			let hash = T::Hashing::hash_of(&(&reason_hash, &who));
+			ensure!(T::AllowSelfReport::get() || tipper != who, Error::<T>::SelfReport);
+			Self::ensure_cooldown_over(&who)?;
 
 			Reasons::<T>::insert(&reason_hash, &reason);
-			Self::deposit_event(RawEvent::NewTip(hash.clone()));
+			ReasonKinds::<T>::insert(reason_hash, kind);
+			Self::deposit_event(RawEvent::NewTip(hash.clone(), kind));
 			let tips = vec![(tipper.clone(), tip_value)];
 			let tip = OpenTip {
 				reason: reason_hash,
@@ -356,23 +645,27 @@ decl_module! {
 		/// has started.
 		///
 		/// # <weight>
-		/// - Complexity: `O(T)` where `T` is the number of tippers.
+		/// - Complexity: `O(T + D)` where `T` is the number of tippers and `D` the number of tip
+		///   values declared.
 		///   decoding `Tipper` vec of length `T`, insert tip and check closing,
 		///   `T` is charged as upper bound given by `ContainsLengthBound`.
 		///   The actual cost depends on the implementation of `T::Tippers`.
 		///
 		///   Actually weight could be lower as it depends on how many tips are in `OpenTip` but it
-		///   is weighted as if almost full i.e of length `T-1`.
-		/// - DbReads: `Tippers`, `Tips`
+		///   is weighted as if full i.e of length `MaxTipDeclarations`.
+		/// - DbReads: `Tippers`, `Tips`, `TrustedTipThresholds`
 		/// - DbWrites: `Tips`
 		/// # </weight>
-		#[weight = <T as Config>::WeightInfo::tip(T::Tippers::max_len() as u32)]
+		#[weight = <T as Config>::WeightInfo::tip(
+			T::Tippers::max_len() as u32,
+			T::MaxTipDeclarations::get(),
+		)]
 		fn tip(origin, hash: T::Hash, #[compact] tip_value: BalanceOf<T>) {
 			let tipper = ensure_signed(origin)?;
 			ensure!(T::Tippers::contains(&tipper), BadOrigin);
 
 			let mut tip = Tips::<T>::get(hash).ok_or(Error::<T>::UnknownTip)?;
-			if Self::insert_tip_and_check_closing(&mut tip, tipper, tip_value) {
+			if Self::insert_tip_and_check_closing(hash, &mut tip, tipper, tip_value)? {
 				Self::deposit_event(RawEvent::TipClosing(hash.clone()));
 			}
 			Tips::<T>::insert(&hash, tip);
@@ -382,21 +675,29 @@ decl_module! {
 		///
 		/// The dispatch origin for this call must be _Signed_.
 		///
-		/// The tip identified by `hash` must have finished its countdown period.
+		/// The tip identified by `hash` must have finished its countdown period. A shared tip pays
+		/// each beneficiary its share of the median tip.
 		///
 		/// - `hash`: The identity of the open tip for which a tip value is declared. This is formed
 		///   as the hash of the tuple of the original tip `reason` and the beneficiary account ID.
 		///
 		/// # <weight>
-		/// - Complexity: `O(T)` where `T` is the number of tippers.
+		/// - Complexity: `O(T + S)` where `T` is the number of tippers and `S` the number of
+		///   shares.
 		///   decoding `Tipper` vec of length `T`.
 		///   `T` is charged as upper bound given by `ContainsLengthBound`.
 		///   The actual cost depends on the implementation of `T::Tippers`.
-		/// - DbReads: `Tips`, `Tippers`, `tip finder`
-		/// - DbWrites: `Reasons`, `Tips`, `Tippers`, `tip finder`
+		///   `S` is charged as upper bound given by `MaxTipShares` and refunded afterwards.
+		/// - DbReads: `Tips`, `TipShares`, `Tippers`, `tip finder`, `TipFundingSource`,
+		///   `co-funder`
+		/// - DbWrites: `Reasons`, `Tips`, `TipShares`, `TrustedTipThresholds`, `Tippers`,
+		///   `tip finder`, `co-funder`, `LastTipClosed` and `TipCooldownExpiries` per beneficiary
 		/// # </weight>
-		#[weight = <T as Config>::WeightInfo::close_tip(T::Tippers::max_len() as u32)]
-		fn close_tip(origin, hash: T::Hash) {
+		#[weight = <T as Config>::WeightInfo::close_tip(
+			T::Tippers::max_len() as u32,
+			T::MaxTipShares::get(),
+		)]
+		fn close_tip(origin, hash: T::Hash) -> DispatchResultWithPostInfo {
 			ensure_signed(origin)?;
 
 			let tip = Tips::<T>::get(hash).ok_or(Error::<T>::UnknownTip)?;
@@ -404,8 +705,62 @@ decl_module! {
 			ensure!(system::Module::<T>::block_number() >= *n, Error::<T>::Premature);
 			// closed.
 			Reasons::<T>::remove(&tip.reason);
+			ReasonKinds::<T>::remove(tip.reason);
 			Tips::<T>::remove(hash);
-			Self::payout_tip(hash, tip);
+			TrustedTipThresholds::<T>::remove(hash);
+			let shares = TipShares::<T>::take(hash);
+			let shares_len = shares.as_ref().map_or(0, |s| s.len() as u32);
+			Self::payout_tip(hash, tip, shares);
+
+			Ok(Some(<T as Config>::WeightInfo::close_tip(T::Tippers::max_len() as u32, shares_len)).into())
+		}
+
+		/// Override `MinTippersForClose`, or go back to it.
+		///
+		/// May only be called from `T::MinTippersOrigin`. Tips that are already closing are not
+		/// affected.
+		///
+		/// - `min_tippers`: The least number of tippers needed for a tip to start closing, or
+		///   `None` to use `MinTippersForClose` again. It may not be more than there are tippers.
+		///
+		/// Emits `MinTippersForCloseSet`.
+		///
+		/// # <weight>
+		/// - Complexity: `O(T)` where `T` is the number of tippers, charged as upper bound given
+		///   by `ContainsLengthBound`.
+		/// - DbReads: `Tippers`
+		/// - DbWrites: `MinTippersForCloseOverride`
+		/// # </weight>
+		#[weight = <T as Config>::WeightInfo::set_min_tippers_for_close(T::Tippers::max_len() as u32)]
+		fn set_min_tippers_for_close(origin, min_tippers: Option<u32>) {
+			T::MinTippersOrigin::ensure_origin(origin)?;
+			if let Some(min_tippers) = min_tippers {
+				ensure!(min_tippers as usize <= T::Tippers::count(), Error::<T>::TooManyMinTippers);
+			}
+
+			MinTippersForCloseOverride::set(min_tippers);
+			Self::deposit_event(RawEvent::MinTippersForCloseSet(Self::min_tippers_for_close()));
+		}
+
+		/// Let a new tip for `who` be opened before `BeneficiaryTipCooldown` has passed since the
+		/// last tip to them closed.
+		///
+		/// May only be called from `T::TipCooldownOverrideOrigin`.
+		///
+		/// - `who`: The account whose cooldown is lifted.
+		///
+		/// Emits `TipCooldownReset`.
+		///
+		/// # <weight>
+		/// - O(1).
+		/// - DbWrites: `LastTipClosed`
+		/// # </weight>
+		#[weight = <T as Config>::WeightInfo::reset_tip_cooldown()]
+		fn reset_tip_cooldown(origin, who: T::AccountId) {
+			T::TipCooldownOverrideOrigin::ensure_origin(origin)?;
+
+			LastTipClosed::<T>::remove(&who);
+			Self::deposit_event(RawEvent::TipCooldownReset(who));
 		}
 
 		/// Remove and slash an already-open tip.
@@ -431,8 +786,33 @@ decl_module! {
 				T::OnSlash::on_unbalanced(imbalance);
 			}
 			Reasons::<T>::remove(&tip.reason);
+			ReasonKinds::<T>::remove(tip.reason);
+			TipShares::<T>::remove(hash);
+			TrustedTipThresholds::<T>::remove(hash);
 			Self::deposit_event(RawEvent::TipSlashed(hash, tip.finder, tip.deposit));
 		}
+
+		/// # <weight>
+		/// - O(C) where C is the number of beneficiaries whose cooldown ends in block `n`.
+		/// - 1 storage read and 1 storage write for `TipCooldownExpiries`, and 1 storage read and
+		///   1 storage write for `LastTipClosed` per beneficiary.
+		/// - Once per `SpendPeriod`: 1 storage read and 1 storage write for `PeriodTipTotals`.
+		/// # </weight>
+		fn on_initialize(n: T::BlockNumber) -> Weight {
+			let mut weight = Self::remove_expired_cooldowns(n);
+
+			// Report the tips of the period alongside the treasury spending.
+			if (n % T::SpendPeriod::get()).is_zero() {
+				let totals = PeriodTipTotals::<T>::take();
+				Self::deposit_event(RawEvent::TipsPeriodSummary(
+					totals.total_paid,
+					totals.tips_closed,
+					totals.largest_payout,
+				));
+				weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+			}
+			weight
+		}
 	}
 }
 
@@ -447,29 +827,115 @@ impl<T: Config> Module<T> {
 		T::ModuleId::get().into_account()
 	}
 
-	/// Given a mutable reference to an `OpenTip`, insert the tip into it and check whether it
-	/// closes, if so, then deposit the relevant event and set closing accordingly.
+	/// Given a mutable reference to the `OpenTip` identified by `hash`, insert the tip into it and
+	/// check whether it closes, if so, then deposit the relevant event and set closing
+	/// accordingly.
 	///
-	/// `O(T)` and one storage access.
+	/// Fails if the tip would hold more than `MaxTipDeclarations` tip values, in which case `tip`
+	/// must not be stored.
+	///
+	/// `O(T + D)` and two storage accesses.
 	fn insert_tip_and_check_closing(
+		hash: T::Hash,
 		tip: &mut OpenTip<T::AccountId, BalanceOf<T>, T::BlockNumber, T::Hash>,
 		tipper: T::AccountId,
 		tip_value: BalanceOf<T>,
-	) -> bool {
+	) -> Result<bool, DispatchError> {
 		match tip.tips.binary_search_by_key(&&tipper, |x| &x.0) {
 			Ok(pos) => tip.tips[pos] = (tipper, tip_value),
 			Err(pos) => tip.tips.insert(pos, (tipper, tip_value)),
 		}
 		Self::retain_active_tips(&mut tip.tips);
-		let threshold = (T::Tippers::count() + 1) / 2;
-		if tip.tips.len() >= threshold && tip.closes.is_none() {
+		ensure!(
+			tip.tips.len() <= T::MaxTipDeclarations::get() as usize,
+			Error::<T>::TooManyDeclarations
+		);
+		if tip.tips.len() >= Self::tip_threshold(hash) as usize && tip.closes.is_none() {
 			tip.closes = Some(system::Module::<T>::block_number() + T::TipCountdown::get());
-			true
+			Ok(true)
 		} else {
-			false
+			Ok(false)
 		}
 	}
 
+	/// The least number of tippers that must declare a tip value before a tip starts closing.
+	pub fn min_tippers_for_close() -> u32 {
+		Self::min_tippers_for_close_override().unwrap_or_else(T::MinTippersForClose::get)
+	}
+
+	/// The number of tippers that must declare a value for the tip `hash` before it starts
+	/// closing: a majority of `Tippers`, or the threshold of a trusted finder, but at least
+	/// `min_tippers_for_close`.
+	pub fn tip_threshold(hash: T::Hash) -> u32 {
+		Self::trusted_tip_threshold(hash)
+			.unwrap_or_else(Self::tippers_majority)
+			.max(Self::min_tippers_for_close())
+	}
+
+	/// A majority of `Tippers`.
+	fn tippers_majority() -> u32 {
+		(T::Tippers::count() as u32 + 1) / 2
+	}
+
+	/// Record the threshold of the tip `hash`, if `TrustProvider` grants `finder` a reduction.
+	fn note_finder_trust(hash: T::Hash, finder: &T::AccountId) {
+		let reduction = T::TrustProvider::threshold_reduction(finder);
+		if reduction > 0 {
+			let threshold = Self::tippers_majority()
+				.saturating_sub(reduction)
+				.max(Self::min_tippers_for_close());
+			TrustedTipThresholds::<T>::insert(hash, threshold);
+		}
+	}
+
+	/// Ensure `shares` names distinct accounts whose shares add up to exactly 100%.
+	fn ensure_valid_shares(shares: &[(T::AccountId, Permill)]) -> Result<(), Error<T>> {
+		let total = shares.iter().fold(0u32, |acc, (_, share)| acc.saturating_add(share.deconstruct()));
+		ensure!(total == Permill::one().deconstruct(), Error::<T>::InvalidShares);
+
+		let mut accounts = shares.iter().map(|(who, _)| who).collect::<Vec<_>>();
+		accounts.sort();
+		accounts.dedup();
+		ensure!(accounts.len() == shares.len(), Error::<T>::InvalidShares);
+
+		Ok(())
+	}
+
+	/// Start the `BeneficiaryTipCooldown` of `who`, after a tip to them closed at `now`.
+	fn start_cooldown(who: &T::AccountId, now: T::BlockNumber) {
+		let cooldown = T::BeneficiaryTipCooldown::get();
+		if cooldown.is_zero() {
+			return
+		}
+		LastTipClosed::<T>::insert(who, now);
+		TipCooldownExpiries::<T>::append(now.saturating_add(cooldown), who);
+	}
+
+	/// Remove the `LastTipClosed` entries of the accounts whose cooldown ends at `n`, unless
+	/// another tip to them closed since. Returns the weight of doing so.
+	fn remove_expired_cooldowns(n: T::BlockNumber) -> Weight {
+		let expired = TipCooldownExpiries::<T>::take(n);
+		let cooldown = T::BeneficiaryTipCooldown::get();
+		for who in expired.iter() {
+			if let Some(closed) = Self::last_tip_closed(who) {
+				if closed.saturating_add(cooldown) <= n {
+					LastTipClosed::<T>::remove(who);
+				}
+			}
+		}
+		let accesses = (expired.len() as Weight).saturating_add(1);
+		T::DbWeight::get().reads_writes(accesses, accesses)
+	}
+
+	/// Ensure that no tip to `who` closed within the last `BeneficiaryTipCooldown` blocks.
+	fn ensure_cooldown_over(who: &T::AccountId) -> Result<(), Error<T>> {
+		if let Some(closed) = Self::last_tip_closed(who) {
+			let now = system::Module::<T>::block_number();
+			ensure!(now >= closed.saturating_add(T::BeneficiaryTipCooldown::get()), Error::<T>::TipCooldown);
+		}
+		Ok(())
+	}
+
 	/// Remove any non-members of `Tippers` from a `tips` vector. `O(T)`.
 	fn retain_active_tips(tips: &mut Vec<(T::AccountId, BalanceOf<T>)>) {
 		let members = T::Tippers::sorted_members();
@@ -491,24 +957,56 @@ impl<T: Config> Module<T> {
 		});
 	}
 
-	/// Execute the payout of a tip.
+	/// Execute the payout of a tip, splitting it between `shares` if given.
 	///
-	/// Up to three balance operations.
+	/// Up to three balance operations, plus one per share.
 	/// Plus `O(T)` (`T` is Tippers length).
-	fn payout_tip(hash: T::Hash, tip: OpenTip<T::AccountId, BalanceOf<T>, T::BlockNumber, T::Hash>) {
+	fn payout_tip(
+		hash: T::Hash,
+		tip: OpenTip<T::AccountId, BalanceOf<T>, T::BlockNumber, T::Hash>,
+		shares: Option<Vec<(T::AccountId, Permill)>>,
+	) {
 		let mut tips = tip.tips;
 		Self::retain_active_tips(&mut tips);
 		tips.sort_by_key(|i| i.1);
 
 		let treasury = Self::account_id();
-		let max_payout = pallet_treasury::Module::<T>::pot();
+		let mut payout = tips[tips.len() / 2].1;
 
-		let mut payout = tips[tips.len() / 2].1.min(max_payout);
+		// the co-funder pays first, into the treasury account, so that the payout below works the
+		// same whichever account the funds came from.
+		if let Some((funder, max)) = T::TipFundingSource::co_funding(&tip.reason) {
+			let available = T::Currency::free_balance(&funder)
+				.saturating_sub(T::Currency::minimum_balance());
+			let co_funded = payout.min(max).min(available);
+			if !co_funded.is_zero() &&
+				T::Currency::transfer(&funder, &treasury, co_funded, KeepAlive).is_ok()
+			{
+				Self::deposit_event(RawEvent::TipCoFunded(hash, funder, co_funded));
+			}
+		}
+
+		let max_payout = pallet_treasury::Module::<T>::pot();
+		payout = payout.min(max_payout);
+		// the totals are of what beneficiaries got, whoever funded it.
+		PeriodTipTotals::<T>::mutate(|totals| {
+			totals.total_paid = totals.total_paid.saturating_add(payout);
+			totals.tips_closed = totals.tips_closed.saturating_add(1);
+			totals.largest_payout = totals.largest_payout.max(payout);
+		});
+		ReasonPayoutTotals::<T>::mutate(tip.reason, |total| *total = total.saturating_add(payout));
+		// the finder gets their deposit back whether or not they are also a beneficiary.
 		if !tip.deposit.is_zero() {
 			let _ = T::Currency::unreserve(&tip.finder, tip.deposit);
 		}
 
-		if tip.finders_fee && tip.finder != tip.who {
+		// a self-found tip pays no finder's fee: the finder is paid through their share instead.
+		let finder = &tip.finder;
+		let self_found = match shares {
+			Some(ref shares) => shares.iter().any(|(who, _)| who == finder),
+			None => tip.finder == tip.who,
+		};
+		if tip.finders_fee && !self_found {
 			// pay out the finder's fee.
 			let finders_fee = T::TipFindersFee::get() * payout;
 			payout -= finders_fee;
@@ -517,62 +1015,41 @@ impl<T: Config> Module<T> {
 			let _ = T::Currency::transfer(&treasury, &tip.finder, finders_fee, KeepAlive);
 		}
 
-		// same as above: best-effort only.
-		let _ = T::Currency::transfer(&treasury, &tip.who, payout, KeepAlive);
-		Self::deposit_event(RawEvent::TipClosed(hash, tip.who, payout));
+		let now = system::Module::<T>::block_number();
+		match shares {
+			Some(shares) => {
+				// the last beneficiary takes whatever is left after rounding.
+				let mut remaining = payout;
+				let last = shares.len().saturating_sub(1);
+				for (i, (who, share)) in shares.into_iter().enumerate() {
+					let amount = if i == last { remaining } else { share * payout };
+					remaining = remaining.saturating_sub(amount);
+					// same as above: best-effort only.
+					let _ = T::Currency::transfer(&treasury, &who, amount, KeepAlive);
+					Self::start_cooldown(&who, now);
+					let self_found = who == tip.finder;
+					Self::deposit_event(RawEvent::TipClosed(hash, who, amount, self_found));
+				}
+			},
+			None => {
+				// same as above: best-effort only.
+				let _ = T::Currency::transfer(&treasury, &tip.who, payout, KeepAlive);
+				Self::start_cooldown(&tip.who, now);
+				Self::deposit_event(RawEvent::TipClosed(hash, tip.who, payout, self_found));
+			},
+		}
 	}
 
 	pub fn migrate_retract_tip_for_tip_new() {
-		/// An open tipping "motion". Retains all details of a tip including information on the finder
-		/// and the members who have voted.
-		#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug)]
-		pub struct OldOpenTip<
-			AccountId: Parameter,
-			Balance: Parameter,
-			BlockNumber: Parameter,
-			Hash: Parameter,
-		> {
-			/// The hash of the reason for the tip. The reason should be a human-readable UTF-8 encoded string. A URL would be
-			/// sensible.
-			reason: Hash,
-			/// The account to be tipped.
-			who: AccountId,
-			/// The account who began this tip and the amount held on deposit.
-			finder: Option<(AccountId, Balance)>,
-			/// The block number at which this tip will close if `Some`. If `None`, then no closing is
-			/// scheduled.
-			closes: Option<BlockNumber>,
-			/// The members who have voted for this tip. Sorted by AccountId.
-			tips: Vec<(AccountId, Balance)>,
-		}
-
 		use frame_support::{Twox64Concat, migration::StorageKeyIterator};
 
 		for (hash, old_tip) in StorageKeyIterator::<
 			T::Hash,
-			OldOpenTip<T::AccountId, BalanceOf<T>, T::BlockNumber, T::Hash>,
+			migrations::OldOpenTipOf<T>,
 			Twox64Concat,
 		>::new(b"Treasury", b"Tips").drain()
 		{
-
-			let (finder, deposit, finders_fee) = match old_tip.finder {
-				Some((finder, deposit)) => {
-					(finder, deposit, true)
-				},
-				None => {
-					(T::AccountId::default(), Zero::zero(), false)
-				},
-			};
-			let new_tip = OpenTip {
-				reason: old_tip.reason,
-				who: old_tip.who,
-				finder,
-				deposit,
-				closes: old_tip.closes,
-				tips: old_tip.tips,
-				finders_fee
-			};
-			Tips::<T>::insert(hash, new_tip)
+			Tips::<T>::insert(hash, old_tip.upgraded())
 		}
 	}
 }
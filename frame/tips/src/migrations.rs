@@ -0,0 +1,131 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Migrations for the tips pallet.
+
+use super::*;
+
+/// An open tipping "motion" as stored before `retract_tip` was split from `tip_new`, i.e. before
+/// the finder and their deposit became mandatory fields of [`OpenTip`].
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug)]
+pub struct OldOpenTip<
+	AccountId: Parameter,
+	Balance: Parameter,
+	BlockNumber: Parameter,
+	Hash: Parameter,
+> {
+	/// The hash of the reason for the tip. The reason should be a human-readable UTF-8 encoded
+	/// string. A URL would be sensible.
+	pub reason: Hash,
+	/// The account to be tipped.
+	pub who: AccountId,
+	/// The account who began this tip and the amount held on deposit.
+	pub finder: Option<(AccountId, Balance)>,
+	/// The block number at which this tip will close if `Some`. If `None`, then no closing is
+	/// scheduled.
+	pub closes: Option<BlockNumber>,
+	/// The members who have voted for this tip. Sorted by AccountId.
+	pub tips: Vec<(AccountId, Balance)>,
+}
+
+/// The pre-migration tip type of a runtime.
+pub type OldOpenTipOf<T> = OldOpenTip<
+	<T as frame_system::Config>::AccountId,
+	BalanceOf<T>,
+	<T as frame_system::Config>::BlockNumber,
+	<T as frame_system::Config>::Hash,
+>;
+
+impl<AccountId, Balance, BlockNumber, Hash> OldOpenTip<AccountId, Balance, BlockNumber, Hash> where
+	AccountId: Parameter + Default,
+	Balance: Parameter + Zero,
+	BlockNumber: Parameter,
+	Hash: Parameter,
+{
+	/// The tip this becomes after `migrate_retract_tip_for_tip_new`.
+	pub fn upgraded(self) -> OpenTip<AccountId, Balance, BlockNumber, Hash> {
+		let (finder, deposit, finders_fee) = match self.finder {
+			Some((finder, deposit)) => {
+				(finder, deposit, true)
+			},
+			None => {
+				(AccountId::default(), Zero::zero(), false)
+			},
+		};
+		OpenTip {
+			reason: self.reason,
+			who: self.who,
+			finder,
+			deposit,
+			closes: self.closes,
+			tips: self.tips,
+			finders_fee
+		}
+	}
+}
+
+/// Helpers to check the tips migrations against a chosen set of pre-migration tips, e.g. ones
+/// taken from a snapshot of a live chain.
+///
+/// Use them from within externalities of a runtime `T` whose tips pallet stores under the
+/// `Treasury` prefix.
+#[cfg(any(test, feature = "mock-migrations"))]
+pub mod mock {
+	use super::*;
+	use frame_support::storage::IterableStorageMap;
+
+	/// Write `old_tips` to the `Tips` storage of `T` using the pre-migration layout.
+	pub fn insert_old_tips<T: Config>(old_tips: &[(T::Hash, OldOpenTipOf<T>)]) {
+		for (hash, old_tip) in old_tips {
+			frame_support::storage::unhashed::put(&Tips::<T>::hashed_key_for(hash), old_tip);
+		}
+	}
+
+	/// Write `old_tips`, run `migrate_retract_tip_for_tip_new` and assert that every tip was
+	/// upgraded in place and that no other tip exists afterwards.
+	///
+	/// A tip with a finder must keep them, their deposit and their fee; a tip without one must
+	/// get the default account, no deposit and no finder's fee. The other fields must be kept.
+	pub fn check_migrate_retract_tip_for_tip_new<T: Config>(
+		old_tips: Vec<(T::Hash, OldOpenTipOf<T>)>,
+	) {
+		insert_old_tips::<T>(&old_tips);
+
+		Module::<T>::migrate_retract_tip_for_tip_new();
+
+		assert_eq!(Tips::<T>::iter().count(), old_tips.len());
+		for (hash, old_tip) in old_tips {
+			let tip = Tips::<T>::get(hash).expect("tip is migrated in place");
+			assert_eq!(tip.reason, old_tip.reason);
+			assert_eq!(tip.who, old_tip.who);
+			assert_eq!(tip.closes, old_tip.closes);
+			assert_eq!(tip.tips, old_tip.tips);
+			match old_tip.finder {
+				Some((finder, deposit)) => {
+					assert_eq!(tip.finder, finder);
+					assert_eq!(tip.deposit, deposit);
+					assert!(tip.finders_fee);
+				},
+				None => {
+					assert_eq!(tip.finder, T::AccountId::default());
+					assert!(tip.deposit.is_zero());
+					assert!(!tip.finders_fee);
+				},
+			}
+		}
+	}
+}
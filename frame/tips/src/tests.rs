@@ -22,7 +22,9 @@
 use crate as tips;
 use super::*;
 use std::cell::RefCell;
-use frame_support::{assert_noop, assert_ok, parameter_types, weights::Weight, traits::Contains};
+use frame_support::{
+	assert_noop, assert_ok, parameter_types, weights::Weight, traits::{Contains, OnInitialize},
+};
 use sp_runtime::Permill;
 use sp_core::H256;
 use sp_runtime::{
@@ -142,13 +144,45 @@ parameter_types! {
 	pub const TipCountdown: u64 = 1;
 	pub const TipFindersFee: Percent = Percent::from_percent(20);
 	pub const TipReportDepositBase: u64 = 1;
+	pub const MaxTipShares: u32 = 10;
+}
+parameter_types! {
+	pub static MaxTipDeclarations: u32 = 100;
+	pub static MinTippersForClose: u32 = 2;
+	pub static BeneficiaryTipCooldown: u64 = 0;
+	pub static AllowSelfReport: bool = true;
+}
+/// The account that co-funds tips for "co-funded.dot" in tests, up to 10 per tip.
+pub const CO_FUNDER: u128 = 50;
+pub struct TestTipFundingSource;
+impl TipFundingSource<u128, H256, u64> for TestTipFundingSource {
+	fn co_funding(reason: &H256) -> Option<(u128, u64)> {
+		if *reason == BlakeTwo256::hash(b"co-funded.dot") { Some((CO_FUNDER, 10)) } else { None }
+	}
+}
+/// A finder whose tips need two fewer tippers in tests.
+pub const TRUSTED_FINDER: u128 = 5;
+pub struct TestTrustProvider;
+impl TrustProvider<u128> for TestTrustProvider {
+	fn threshold_reduction(finder: &u128) -> u32 {
+		if *finder == TRUSTED_FINDER { 2 } else { 0 }
+	}
 }
 impl Config for Test {
 	type MaximumReasonLength = MaximumReasonLength;
 	type Tippers = TenToFourteen;
 	type TipCountdown = TipCountdown;
+	type MinTippersForClose = MinTippersForClose;
+	type MinTippersOrigin = frame_system::EnsureRoot<u128>;
+	type BeneficiaryTipCooldown = BeneficiaryTipCooldown;
+	type TipCooldownOverrideOrigin = frame_system::EnsureRoot<u128>;
 	type TipFindersFee = TipFindersFee;
+	type AllowSelfReport = AllowSelfReport;
 	type TipReportDepositBase = TipReportDepositBase;
+	type MaxTipShares = MaxTipShares;
+	type MaxTipDeclarations = MaxTipDeclarations;
+	type TipFundingSource = TestTipFundingSource;
+	type TrustProvider = TestTrustProvider;
 	type DataDepositPerByte = DataDepositPerByte;
 	type Event = Event;
 	type WeightInfo = ();
@@ -189,9 +223,9 @@ fn tip_hash() -> H256 {
 fn tip_new_cannot_be_used_twice() {
 	new_test_ext().execute_with(|| {
 		Balances::make_free_balance_be(&Treasury::account_id(), 101);
-		assert_ok!(TipsModTestInst::tip_new(Origin::signed(10), b"awesome.dot".to_vec(), 3, 10));
+		assert_ok!(TipsModTestInst::tip_new(Origin::signed(10), b"awesome.dot".to_vec(), ReasonKind::Url, 3, 10));
 		assert_noop!(
-			TipsModTestInst::tip_new(Origin::signed(11), b"awesome.dot".to_vec(), 3, 10),
+			TipsModTestInst::tip_new(Origin::signed(11), b"awesome.dot".to_vec(), ReasonKind::Url, 3, 10),
 			Error::<Test>::AlreadyKnown
 		);
 	});
@@ -201,13 +235,13 @@ fn tip_new_cannot_be_used_twice() {
 fn report_awesome_and_tip_works() {
 	new_test_ext().execute_with(|| {
 		Balances::make_free_balance_be(&Treasury::account_id(), 101);
-		assert_ok!(TipsModTestInst::report_awesome(Origin::signed(0), b"awesome.dot".to_vec(), 3));
+		assert_ok!(TipsModTestInst::report_awesome(Origin::signed(0), b"awesome.dot".to_vec(), ReasonKind::Url, 3));
 		assert_eq!(Balances::reserved_balance(0), 12);
 		assert_eq!(Balances::free_balance(0), 88);
 
 		// other reports don't count.
 		assert_noop!(
-			TipsModTestInst::report_awesome(Origin::signed(1), b"awesome.dot".to_vec(), 3),
+			TipsModTestInst::report_awesome(Origin::signed(1), b"awesome.dot".to_vec(), ReasonKind::Url, 3),
 			Error::<Test>::AlreadyKnown
 		);
 
@@ -224,11 +258,61 @@ fn report_awesome_and_tip_works() {
 	});
 }
 
+#[test]
+fn reason_kinds_are_recorded() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+
+		let reason = b"# awesome".to_vec();
+		let reason_hash = BlakeTwo256::hash(&reason);
+		let h = BlakeTwo256::hash_of(&(reason_hash, 3u128));
+		assert_ok!(TipsModTestInst::report_awesome(Origin::signed(0), reason.clone(), ReasonKind::Markdown, 3));
+		assert_eq!(last_event(), RawEvent::NewTip(h, ReasonKind::Markdown));
+		assert_eq!(TipsModTestInst::reason_kind(reason_hash), Some(ReasonKind::Markdown));
+
+		assert_ok!(TipsModTestInst::retract_tip(Origin::signed(0), h));
+		assert_eq!(TipsModTestInst::reason_kind(reason_hash), None);
+
+		assert_ok!(TipsModTestInst::tip_new(Origin::signed(10), reason, ReasonKind::PlainText, 3, 10));
+		assert_eq!(last_event(), RawEvent::NewTip(h, ReasonKind::PlainText));
+		assert_eq!(TipsModTestInst::reason_kind(reason_hash), Some(ReasonKind::PlainText));
+		assert_ok!(TipsModTestInst::tip(Origin::signed(11), h, 10));
+		assert_ok!(TipsModTestInst::tip(Origin::signed(12), h, 10));
+
+		System::set_block_number(2);
+		assert_ok!(TipsModTestInst::close_tip(Origin::signed(0), h));
+		assert_eq!(TipsModTestInst::reason_kind(reason_hash), None);
+	});
+}
+
+#[test]
+fn reason_payout_totals_accumulate() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+
+		// the same reason is used again once its first tip closed.
+		let reason = b"awesome.dot".to_vec();
+		let reason_hash = BlakeTwo256::hash(&reason);
+		for (&who, total) in [3u128, 4].iter().zip(&[10u64, 20]) {
+			assert_ok!(TipsModTestInst::tip_new(Origin::signed(10), reason.clone(), ReasonKind::Url, who, 10));
+			let h = BlakeTwo256::hash_of(&(reason_hash, who));
+			assert_ok!(TipsModTestInst::tip(Origin::signed(11), h, 10));
+			assert_ok!(TipsModTestInst::tip(Origin::signed(12), h, 10));
+			System::set_block_number(System::block_number() + 1);
+			assert_ok!(TipsModTestInst::close_tip(Origin::signed(0), h));
+			assert_eq!(TipsModTestInst::reason_payout_total(reason_hash), *total);
+		}
+		assert_eq!(TipsModTestInst::reasons(reason_hash), None);
+	});
+}
+
 #[test]
 fn report_awesome_from_beneficiary_and_tip_works() {
 	new_test_ext().execute_with(|| {
 		Balances::make_free_balance_be(&Treasury::account_id(), 101);
-		assert_ok!(TipsModTestInst::report_awesome(Origin::signed(0), b"awesome.dot".to_vec(), 0));
+		assert_ok!(TipsModTestInst::report_awesome(Origin::signed(0), b"awesome.dot".to_vec(), ReasonKind::Url, 0));
 		assert_eq!(Balances::reserved_balance(0), 12);
 		assert_eq!(Balances::free_balance(0), 88);
 		let h = BlakeTwo256::hash_of(&(BlakeTwo256::hash(b"awesome.dot"), 0u128));
@@ -242,6 +326,74 @@ fn report_awesome_from_beneficiary_and_tip_works() {
 	});
 }
 
+#[test]
+fn report_awesome_shared_and_tip_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		let shares = vec![(3, Permill::from_percent(50)), (4, Permill::from_percent(50))];
+		assert_ok!(TipsModTestInst::report_awesome_shared(
+			Origin::signed(0),
+			b"awesome.dot".to_vec(),
+			ReasonKind::Url,
+			shares.clone(),
+		));
+		// base + reason + encoded shares
+		assert_eq!(Balances::reserved_balance(0), 1 + 11 + 41);
+
+		let h = BlakeTwo256::hash_of(&(BlakeTwo256::hash(b"awesome.dot"), &shares));
+		assert_eq!(last_event(), RawEvent::NewTip(h, ReasonKind::Url));
+		assert_eq!(TipsModTestInst::tip_shares(h), Some(shares));
+
+		assert_ok!(TipsModTestInst::tip(Origin::signed(10), h, 10));
+		assert_ok!(TipsModTestInst::tip(Origin::signed(11), h, 10));
+		assert_ok!(TipsModTestInst::tip(Origin::signed(12), h, 10));
+		System::set_block_number(2);
+		assert_ok!(TipsModTestInst::close_tip(Origin::signed(100), h));
+
+		assert_eq!(Balances::reserved_balance(0), 0);
+		assert_eq!(Balances::free_balance(0), 102);
+		assert_eq!(Balances::free_balance(3), 4);
+		assert_eq!(Balances::free_balance(4), 4);
+		assert_eq!(last_event(), RawEvent::TipClosed(h, 4, 4, false));
+		assert_eq!(TipsModTestInst::tip_shares(h), None);
+	});
+}
+
+#[test]
+fn report_awesome_shared_validates_shares() {
+	new_test_ext().execute_with(|| {
+		let reason = b"awesome.dot".to_vec();
+		assert_noop!(
+			TipsModTestInst::report_awesome_shared(Origin::signed(0), reason.clone(), ReasonKind::Url, vec![]),
+			Error::<Test>::InvalidShares
+		);
+		assert_noop!(
+			TipsModTestInst::report_awesome_shared(
+				Origin::signed(0),
+				reason.clone(),
+				ReasonKind::Url,
+				vec![(3, Permill::from_percent(50)), (4, Permill::from_percent(40))],
+			),
+			Error::<Test>::InvalidShares
+		);
+		assert_noop!(
+			TipsModTestInst::report_awesome_shared(
+				Origin::signed(0),
+				reason.clone(),
+				ReasonKind::Url,
+				vec![(3, Permill::from_percent(50)), (3, Permill::from_percent(50))],
+			),
+			Error::<Test>::InvalidShares
+		);
+		let too_many = (0..11u128).map(|i| (i, Permill::from_percent(10))).collect();
+		assert_noop!(
+			TipsModTestInst::report_awesome_shared(Origin::signed(0), reason, ReasonKind::Url, too_many),
+			Error::<Test>::TooManyShares
+		);
+	});
+}
+
 #[test]
 fn close_tip_works() {
 	new_test_ext().execute_with(|| {
@@ -250,11 +402,11 @@ fn close_tip_works() {
 		Balances::make_free_balance_be(&Treasury::account_id(), 101);
 		assert_eq!(Treasury::pot(), 100);
 
-		assert_ok!(TipsModTestInst::tip_new(Origin::signed(10), b"awesome.dot".to_vec(), 3, 10));
+		assert_ok!(TipsModTestInst::tip_new(Origin::signed(10), b"awesome.dot".to_vec(), ReasonKind::Url, 3, 10));
 
 		let h = tip_hash();
 
-		assert_eq!(last_event(), RawEvent::NewTip(h));
+		assert_eq!(last_event(), RawEvent::NewTip(h, ReasonKind::Url));
 
 		assert_ok!(TipsModTestInst::tip(Origin::signed(11), h.clone(), 10));
 
@@ -271,12 +423,197 @@ fn close_tip_works() {
 		assert_ok!(TipsModTestInst::close_tip(Origin::signed(0), h.into()));
 		assert_eq!(Balances::free_balance(3), 10);
 
-		assert_eq!(last_event(), RawEvent::TipClosed(h, 3, 10));
+		assert_eq!(last_event(), RawEvent::TipClosed(h, 3, 10, false));
 
 		assert_noop!(TipsModTestInst::close_tip(Origin::signed(100), h.into()), Error::<Test>::UnknownTip);
 	});
 }
 
+#[test]
+fn co_funded_tip_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&CO_FUNDER, 8);
+
+		assert_ok!(TipsModTestInst::tip_new(Origin::signed(10), b"co-funded.dot".to_vec(), ReasonKind::Url, 3, 15));
+		let h = BlakeTwo256::hash_of(&(BlakeTwo256::hash(b"co-funded.dot"), 3u128));
+		assert_ok!(TipsModTestInst::tip(Origin::signed(11), h, 15));
+		assert_ok!(TipsModTestInst::tip(Origin::signed(12), h, 15));
+
+		System::set_block_number(2);
+		assert_ok!(TipsModTestInst::close_tip(Origin::signed(0), h));
+
+		// the co-funder pays what it can above the existential deposit, the treasury the rest.
+		assert_eq!(Balances::free_balance(3), 15);
+		assert_eq!(Balances::free_balance(CO_FUNDER), 1);
+		assert_eq!(Treasury::pot(), 92);
+		let events = System::events().into_iter().map(|r| r.event).collect::<Vec<_>>();
+		assert!(events.contains(&Event::tips(RawEvent::TipCoFunded(h, CO_FUNDER, 7))));
+		assert_eq!(last_event(), RawEvent::TipClosed(h, 3, 15, false));
+
+		// other reasons are paid by the treasury alone.
+		assert_ok!(TipsModTestInst::tip_new(Origin::signed(10), b"awesome.dot".to_vec(), ReasonKind::Url, 3, 10));
+		assert_ok!(TipsModTestInst::tip(Origin::signed(11), tip_hash(), 10));
+		assert_ok!(TipsModTestInst::tip(Origin::signed(12), tip_hash(), 10));
+		System::set_block_number(3);
+		assert_ok!(TipsModTestInst::close_tip(Origin::signed(0), tip_hash()));
+		assert_eq!(Balances::free_balance(CO_FUNDER), 1);
+		assert_eq!(Treasury::pot(), 82);
+	});
+}
+
+#[test]
+fn min_tippers_for_close_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+
+		// more than half of the five tippers are needed anyway.
+		assert_eq!(TipsModTestInst::min_tippers_for_close(), 2);
+		MinTippersForClose::set(4);
+		assert_ok!(TipsModTestInst::tip_new(Origin::signed(10), b"awesome.dot".to_vec(), ReasonKind::Url, 3, 10));
+		let h = tip_hash();
+		assert_ok!(TipsModTestInst::tip(Origin::signed(11), h, 10));
+		assert_ok!(TipsModTestInst::tip(Origin::signed(12), h, 10));
+		assert_eq!(TipsModTestInst::tips(h).unwrap().closes, None);
+		assert_ok!(TipsModTestInst::tip(Origin::signed(13), h, 10));
+		assert_eq!(last_event(), RawEvent::TipClosing(h));
+
+		assert_noop!(TipsModTestInst::set_min_tippers_for_close(Origin::signed(10), Some(1)), BadOrigin);
+		// there are only five tippers.
+		assert_noop!(
+			TipsModTestInst::set_min_tippers_for_close(Origin::root(), Some(6)),
+			Error::<Test>::TooManyMinTippers,
+		);
+		assert_ok!(TipsModTestInst::set_min_tippers_for_close(Origin::root(), Some(5)));
+		assert_eq!(last_event(), RawEvent::MinTippersForCloseSet(5));
+		assert_eq!(TipsModTestInst::min_tippers_for_close(), 5);
+		assert_ok!(TipsModTestInst::set_min_tippers_for_close(Origin::root(), None));
+		assert_eq!(last_event(), RawEvent::MinTippersForCloseSet(4));
+	});
+}
+
+#[test]
+fn trusted_finders_need_fewer_tippers() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&TRUSTED_FINDER, 100);
+
+		assert_ok!(TipsModTestInst::report_awesome(Origin::signed(0), b"awesome.dot".to_vec(), ReasonKind::Url, 3));
+		let h = tip_hash();
+		assert_eq!(TipsModTestInst::trusted_tip_threshold(h), None);
+		assert_eq!(TipsModTestInst::tip_threshold(h), 3);
+
+		let reason = b"trusted.dot".to_vec();
+		assert_ok!(TipsModTestInst::report_awesome(Origin::signed(TRUSTED_FINDER), reason.clone(), ReasonKind::Url, 3));
+		let trusted = BlakeTwo256::hash_of(&(BlakeTwo256::hash(&reason), 3u128));
+		assert_eq!(TipsModTestInst::trusted_tip_threshold(trusted), Some(2));
+		// never below `MinTippersForClose`.
+		assert_eq!(TipsModTestInst::tip_threshold(trusted), 2);
+
+		assert_ok!(TipsModTestInst::tip(Origin::signed(10), h, 10));
+		assert_ok!(TipsModTestInst::tip(Origin::signed(11), h, 10));
+		assert_eq!(TipsModTestInst::tips(h).unwrap().closes, None);
+		assert_ok!(TipsModTestInst::tip(Origin::signed(10), trusted, 10));
+		assert_ok!(TipsModTestInst::tip(Origin::signed(11), trusted, 10));
+		assert_eq!(last_event(), RawEvent::TipClosing(trusted));
+
+		System::set_block_number(2);
+		assert_ok!(TipsModTestInst::close_tip(Origin::signed(0), trusted));
+		assert!(!TrustedTipThresholds::<Test>::contains_key(trusted));
+	});
+}
+
+#[test]
+fn beneficiary_tip_cooldown_works() {
+	new_test_ext().execute_with(|| {
+		BeneficiaryTipCooldown::set(5);
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+
+		assert_ok!(TipsModTestInst::tip_new(Origin::signed(10), b"awesome.dot".to_vec(), ReasonKind::Url, 3, 10));
+		assert_ok!(TipsModTestInst::tip(Origin::signed(11), tip_hash(), 10));
+		assert_ok!(TipsModTestInst::tip(Origin::signed(12), tip_hash(), 10));
+		System::set_block_number(2);
+		assert_ok!(TipsModTestInst::close_tip(Origin::signed(0), tip_hash()));
+		assert_eq!(TipsModTestInst::last_tip_closed(3), Some(2));
+
+		assert_noop!(
+			TipsModTestInst::tip_new(Origin::signed(10), b"again.dot".to_vec(), ReasonKind::Url, 3, 10),
+			Error::<Test>::TipCooldown,
+		);
+		assert_noop!(
+			TipsModTestInst::report_awesome(Origin::signed(1), b"again.dot".to_vec(), ReasonKind::Url, 3),
+			Error::<Test>::TipCooldown,
+		);
+		assert_noop!(
+			TipsModTestInst::report_awesome_shared(
+				Origin::signed(1),
+				b"again.dot".to_vec(),
+				ReasonKind::Url,
+				vec![(4, Permill::from_percent(50)), (3, Permill::from_percent(50))],
+			),
+			Error::<Test>::TipCooldown,
+		);
+		// other beneficiaries are not affected.
+		assert_ok!(TipsModTestInst::tip_new(Origin::signed(10), b"other.dot".to_vec(), ReasonKind::Url, 4, 10));
+
+		// the entry is removed once the cooldown is over.
+		<TipsModTestInst as OnInitialize<u64>>::on_initialize(6);
+		assert_eq!(TipsModTestInst::last_tip_closed(3), Some(2));
+		System::set_block_number(7);
+		<TipsModTestInst as OnInitialize<u64>>::on_initialize(7);
+		assert_eq!(TipsModTestInst::last_tip_closed(3), None);
+		assert!(TipsModTestInst::tip_cooldown_expiries(7).is_empty());
+		assert_ok!(TipsModTestInst::tip_new(Origin::signed(10), b"again.dot".to_vec(), ReasonKind::Url, 3, 10));
+		let again = BlakeTwo256::hash_of(&(BlakeTwo256::hash(b"again.dot"), 3u128));
+		assert_ok!(TipsModTestInst::tip(Origin::signed(11), again, 10));
+		assert_ok!(TipsModTestInst::tip(Origin::signed(12), again, 10));
+		System::set_block_number(8);
+		assert_ok!(TipsModTestInst::close_tip(Origin::signed(0), again));
+
+		// the override origin can lift the cooldown.
+		assert_noop!(TipsModTestInst::reset_tip_cooldown(Origin::signed(0), 3), BadOrigin);
+		assert_ok!(TipsModTestInst::reset_tip_cooldown(Origin::root(), 3));
+		assert_eq!(last_event(), RawEvent::TipCooldownReset(3));
+		assert_ok!(TipsModTestInst::tip_new(Origin::signed(10), b"urgent.dot".to_vec(), ReasonKind::Url, 3, 10));
+	});
+}
+
+#[test]
+fn tips_period_summary_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+
+		assert_ok!(TipsModTestInst::tip_new(Origin::signed(10), b"awesome.dot".to_vec(), ReasonKind::Url, 3, 10));
+		assert_ok!(TipsModTestInst::tip(Origin::signed(11), tip_hash(), 10));
+		assert_ok!(TipsModTestInst::tip(Origin::signed(12), tip_hash(), 10));
+		assert_ok!(TipsModTestInst::tip_new(Origin::signed(10), b"great.dot".to_vec(), ReasonKind::Url, 4, 20));
+		let h = BlakeTwo256::hash_of(&(BlakeTwo256::hash(b"great.dot"), 4u128));
+		assert_ok!(TipsModTestInst::tip(Origin::signed(11), h, 20));
+		assert_ok!(TipsModTestInst::tip(Origin::signed(12), h, 20));
+
+		System::set_block_number(2);
+		assert_ok!(TipsModTestInst::close_tip(Origin::signed(0), tip_hash()));
+		assert_ok!(TipsModTestInst::close_tip(Origin::signed(0), h));
+		assert_eq!(TipsModTestInst::period_tip_totals().tips_closed, 2);
+
+		// Nothing is reported in the middle of a spend period.
+		<TipsModTestInst as OnInitialize<u64>>::on_initialize(3);
+		assert_eq!(last_event(), RawEvent::TipClosed(h, 4, 20, false));
+
+		<TipsModTestInst as OnInitialize<u64>>::on_initialize(4);
+		assert_eq!(last_event(), RawEvent::TipsPeriodSummary(30, 2, 20));
+		assert_eq!(TipsModTestInst::period_tip_totals(), Default::default());
+
+		<TipsModTestInst as OnInitialize<u64>>::on_initialize(6);
+		assert_eq!(last_event(), RawEvent::TipsPeriodSummary(0, 0, 0));
+	});
+}
+
 #[test]
 fn slash_tip_works() {
 	new_test_ext().execute_with(|| {
@@ -287,13 +624,13 @@ fn slash_tip_works() {
 		assert_eq!(Balances::reserved_balance(0), 0);
 		assert_eq!(Balances::free_balance(0), 100);
 
-		assert_ok!(TipsModTestInst::report_awesome(Origin::signed(0), b"awesome.dot".to_vec(), 3));
+		assert_ok!(TipsModTestInst::report_awesome(Origin::signed(0), b"awesome.dot".to_vec(), ReasonKind::Url, 3));
 
 		assert_eq!(Balances::reserved_balance(0), 12);
 		assert_eq!(Balances::free_balance(0), 88);
 
 		let h = tip_hash();
-		assert_eq!(last_event(), RawEvent::NewTip(h));
+		assert_eq!(last_event(), RawEvent::NewTip(h, ReasonKind::Url));
 
 		// can't remove from any origin
 		assert_noop!(
@@ -316,7 +653,7 @@ fn retract_tip_works() {
 	new_test_ext().execute_with(|| {
 		// with report awesome
 		Balances::make_free_balance_be(&Treasury::account_id(), 101);
-		assert_ok!(TipsModTestInst::report_awesome(Origin::signed(0), b"awesome.dot".to_vec(), 3));
+		assert_ok!(TipsModTestInst::report_awesome(Origin::signed(0), b"awesome.dot".to_vec(), ReasonKind::Url, 3));
 		let h = tip_hash();
 		assert_ok!(TipsModTestInst::tip(Origin::signed(10), h.clone(), 10));
 		assert_ok!(TipsModTestInst::tip(Origin::signed(11), h.clone(), 10));
@@ -328,7 +665,7 @@ fn retract_tip_works() {
 
 		// with tip new
 		Balances::make_free_balance_be(&Treasury::account_id(), 101);
-		assert_ok!(TipsModTestInst::tip_new(Origin::signed(10), b"awesome.dot".to_vec(), 3, 10));
+		assert_ok!(TipsModTestInst::tip_new(Origin::signed(10), b"awesome.dot".to_vec(), ReasonKind::Url, 3, 10));
 		let h = tip_hash();
 		assert_ok!(TipsModTestInst::tip(Origin::signed(11), h.clone(), 10));
 		assert_ok!(TipsModTestInst::tip(Origin::signed(12), h.clone(), 10));
@@ -343,7 +680,7 @@ fn retract_tip_works() {
 fn tip_median_calculation_works() {
 	new_test_ext().execute_with(|| {
 		Balances::make_free_balance_be(&Treasury::account_id(), 101);
-		assert_ok!(TipsModTestInst::tip_new(Origin::signed(10), b"awesome.dot".to_vec(), 3, 0));
+		assert_ok!(TipsModTestInst::tip_new(Origin::signed(10), b"awesome.dot".to_vec(), ReasonKind::Url, 3, 0));
 		let h = tip_hash();
 		assert_ok!(TipsModTestInst::tip(Origin::signed(11), h.clone(), 10));
 		assert_ok!(TipsModTestInst::tip(Origin::signed(12), h.clone(), 1000000));
@@ -357,7 +694,7 @@ fn tip_median_calculation_works() {
 fn tip_changing_works() {
 	new_test_ext().execute_with(|| {
 		Balances::make_free_balance_be(&Treasury::account_id(), 101);
-		assert_ok!(TipsModTestInst::tip_new(Origin::signed(10), b"awesome.dot".to_vec(), 3, 10000));
+		assert_ok!(TipsModTestInst::tip_new(Origin::signed(10), b"awesome.dot".to_vec(), ReasonKind::Url, 3, 10000));
 		let h = tip_hash();
 		assert_ok!(TipsModTestInst::tip(Origin::signed(11), h.clone(), 10000));
 		assert_ok!(TipsModTestInst::tip(Origin::signed(12), h.clone(), 10000));
@@ -372,33 +709,29 @@ fn tip_changing_works() {
 	});
 }
 
+#[test]
+fn tip_declarations_are_bounded() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		MaxTipDeclarations::set(2);
+		assert_ok!(TipsModTestInst::tip_new(Origin::signed(10), b"awesome.dot".to_vec(), ReasonKind::Url, 3, 10));
+		let h = tip_hash();
+		assert_ok!(TipsModTestInst::tip(Origin::signed(11), h, 10));
+		assert_noop!(TipsModTestInst::tip(Origin::signed(12), h, 10), Error::<Test>::TooManyDeclarations);
+
+		// Tippers that already declared a value may still change it.
+		assert_ok!(TipsModTestInst::tip(Origin::signed(11), h, 20));
+		assert_eq!(TipsModTestInst::tips(h).unwrap().tips, vec![(10, 10), (11, 20)]);
+	});
+}
+
 #[test]
 fn test_last_reward_migration() {
 	use sp_storage::Storage;
+	use crate::migrations::OldOpenTip;
 
 	let mut s = Storage::default();
 
-	#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug)]
-	pub struct OldOpenTip<
-		AccountId: Parameter,
-		Balance: Parameter,
-		BlockNumber: Parameter,
-		Hash: Parameter,
-	> {
-		/// The hash of the reason for the tip. The reason should be a human-readable UTF-8 encoded string. A URL would be
-		/// sensible.
-		reason: Hash,
-		/// The account to be tipped.
-		who: AccountId,
-		/// The account who began this tip and the amount held on deposit.
-		finder: Option<(AccountId, Balance)>,
-		/// The block number at which this tip will close if `Some`. If `None`, then no closing is
-		/// scheduled.
-		closes: Option<BlockNumber>,
-		/// The members who have voted for this tip. Sorted by AccountId.
-		tips: Vec<(AccountId, Balance)>,
-	}
-
 	let reason1 = BlakeTwo256::hash(b"reason1");
 	let hash1 = BlakeTwo256::hash_of(&(reason1, 10u64));
 
@@ -468,6 +801,36 @@ fn test_last_reward_migration() {
 	});
 }
 
+#[test]
+fn migration_harness_works() {
+	let old_tips = vec![
+		(
+			BlakeTwo256::hash_of(&(BlakeTwo256::hash(b"reason1"), 10u128)),
+			migrations::OldOpenTip {
+				reason: BlakeTwo256::hash(b"reason1"),
+				who: 10,
+				finder: Some((20, 30)),
+				closes: None,
+				tips: vec![],
+			},
+		),
+		(
+			BlakeTwo256::hash_of(&(BlakeTwo256::hash(b"reason2"), 20u128)),
+			migrations::OldOpenTip {
+				reason: BlakeTwo256::hash(b"reason2"),
+				who: 20,
+				finder: None,
+				closes: Some(13),
+				tips: vec![(40, 50)],
+			},
+		),
+	];
+
+	new_test_ext().execute_with(|| {
+		migrations::mock::check_migrate_retract_tip_for_tip_new::<Test>(old_tips);
+	});
+}
+
 #[test]
 fn genesis_funding_works() {
 	let mut t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
@@ -484,3 +847,61 @@ fn genesis_funding_works() {
 		assert_eq!(Treasury::pot(), initial_funding - Balances::minimum_balance());
 	});
 }
+
+#[test]
+fn self_found_tips_work() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+
+		assert_ok!(TipsModTestInst::report_awesome(Origin::signed(1), b"self.dot".to_vec(), ReasonKind::Url, 1));
+		let h = BlakeTwo256::hash_of(&(BlakeTwo256::hash(b"self.dot"), 1u128));
+		assert_eq!(Balances::reserved_balance(1), 9);
+		assert_ok!(TipsModTestInst::tip(Origin::signed(10), h, 10));
+		assert_ok!(TipsModTestInst::tip(Origin::signed(11), h, 10));
+		assert_ok!(TipsModTestInst::tip(Origin::signed(12), h, 10));
+		System::set_block_number(2);
+		assert_ok!(TipsModTestInst::close_tip(Origin::signed(0), h));
+
+		// the deposit is returned and the whole tip paid, without a finder's fee.
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::free_balance(1), 108);
+		assert_eq!(last_event(), RawEvent::TipClosed(h, 1, 10, true));
+
+		// only the share of the finder is flagged as self-found.
+		let shares = vec![(1, Permill::from_percent(50)), (3, Permill::from_percent(50))];
+		assert_ok!(TipsModTestInst::report_awesome_shared(
+			Origin::signed(1), b"shared.dot".to_vec(), ReasonKind::Url, shares.clone(),
+		));
+		let h = BlakeTwo256::hash_of(&(BlakeTwo256::hash(b"shared.dot"), &shares));
+		assert_ok!(TipsModTestInst::tip(Origin::signed(10), h, 10));
+		assert_ok!(TipsModTestInst::tip(Origin::signed(11), h, 10));
+		assert_ok!(TipsModTestInst::tip(Origin::signed(12), h, 10));
+		System::set_block_number(3);
+		assert_ok!(TipsModTestInst::close_tip(Origin::signed(0), h));
+		let events = System::events().into_iter().map(|r| r.event).collect::<Vec<_>>();
+		assert!(events.contains(&Event::tips(RawEvent::TipClosed(h, 1, 5, true))));
+		assert_eq!(last_event(), RawEvent::TipClosed(h, 3, 5, false));
+
+		// chains may disallow self-reporting altogether.
+		AllowSelfReport::set(false);
+		assert_noop!(
+			TipsModTestInst::report_awesome(Origin::signed(1), b"again.dot".to_vec(), ReasonKind::Url, 1),
+			Error::<Test>::SelfReport,
+		);
+		assert_noop!(
+			TipsModTestInst::report_awesome_shared(
+				Origin::signed(1), b"again.dot".to_vec(), ReasonKind::Url, shares,
+			),
+			Error::<Test>::SelfReport,
+		);
+		assert_ok!(TipsModTestInst::report_awesome(Origin::signed(1), b"again.dot".to_vec(), ReasonKind::Url, 3));
+
+		// tippers included.
+		assert_noop!(
+			TipsModTestInst::tip_new(Origin::signed(10), b"mine.dot".to_vec(), ReasonKind::Url, 10, 10),
+			Error::<Test>::SelfReport,
+		);
+		assert_ok!(TipsModTestInst::tip_new(Origin::signed(10), b"mine.dot".to_vec(), ReasonKind::Url, 3, 10));
+	});
+}
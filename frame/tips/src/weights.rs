@@ -45,11 +45,14 @@ use sp_std::marker::PhantomData;
 /// Weight functions needed for pallet_tips.
 pub trait WeightInfo {
 	fn report_awesome(r: u32, ) -> Weight;
+	fn report_awesome_shared(r: u32, s: u32, ) -> Weight;
 	fn retract_tip() -> Weight;
 	fn tip_new(r: u32, t: u32, ) -> Weight;
-	fn tip(t: u32, ) -> Weight;
-	fn close_tip(t: u32, ) -> Weight;
+	fn tip(t: u32, d: u32, ) -> Weight;
+	fn close_tip(t: u32, s: u32, ) -> Weight;
 	fn slash_tip(t: u32, ) -> Weight;
+	fn set_min_tippers_for_close(t: u32, ) -> Weight;
+	fn reset_tip_cooldown() -> Weight;
 }
 
 /// Weights for pallet_tips using the Substrate node and recommended hardware.
@@ -59,13 +62,23 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		(73_795_000 as Weight)
 			// Standard Error: 0
 			.saturating_add((2_000 as Weight).saturating_mul(r as Weight))
-			.saturating_add(T::DbWeight::get().reads(2 as Weight))
-			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	fn report_awesome_shared(r: u32, s: u32, ) -> Weight {
+		(76_412_000 as Weight)
+			// Standard Error: 0
+			.saturating_add((2_000 as Weight).saturating_mul(r as Weight))
+			// Standard Error: 2_000
+			.saturating_add((1_153_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(s as Weight)))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
 	}
 	fn retract_tip() -> Weight {
 		(61_753_000 as Weight)
 			.saturating_add(T::DbWeight::get().reads(1 as Weight))
-			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
 	}
 	fn tip_new(r: u32, t: u32, ) -> Weight {
 		(47_731_000 as Weight)
@@ -73,29 +86,46 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add((2_000 as Weight).saturating_mul(r as Weight))
 			// Standard Error: 0
 			.saturating_add((154_000 as Weight).saturating_mul(t as Weight))
-			.saturating_add(T::DbWeight::get().reads(2 as Weight))
-			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
 	}
-	fn tip(t: u32, ) -> Weight {
-		(35_215_000 as Weight)
+	fn tip(t: u32, d: u32, ) -> Weight {
+		(34_862_000 as Weight)
+			// Standard Error: 1_000
+			.saturating_add((418_000 as Weight).saturating_mul(t as Weight))
 			// Standard Error: 1_000
-			.saturating_add((712_000 as Weight).saturating_mul(t as Weight))
-			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add((301_000 as Weight).saturating_mul(d as Weight))
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
-	fn close_tip(t: u32, ) -> Weight {
-		(117_027_000 as Weight)
+	fn close_tip(t: u32, s: u32, ) -> Weight {
+		(163_412_000 as Weight)
 			// Standard Error: 1_000
 			.saturating_add((375_000 as Weight).saturating_mul(t as Weight))
-			.saturating_add(T::DbWeight::get().reads(3 as Weight))
-			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+			// Standard Error: 8_000
+			.saturating_add((48_617_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(T::DbWeight::get().reads(8 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(s as Weight)))
+			.saturating_add(T::DbWeight::get().writes(11 as Weight))
+			.saturating_add(T::DbWeight::get().writes((3 as Weight).saturating_mul(s as Weight)))
 	}
 	fn slash_tip(t: u32, ) -> Weight {
 		(37_184_000 as Weight)
 			// Standard Error: 0
 			.saturating_add((11_000 as Weight).saturating_mul(t as Weight))
 			.saturating_add(T::DbWeight::get().reads(1 as Weight))
-			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	fn set_min_tippers_for_close(t: u32, ) -> Weight {
+		(4_371_000 as Weight)
+			// Standard Error: 0
+			.saturating_add((11_000 as Weight).saturating_mul(t as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn reset_tip_cooldown() -> Weight {
+		(16_902_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
 }
 
@@ -105,13 +135,23 @@ impl WeightInfo for () {
 		(73_795_000 as Weight)
 			// Standard Error: 0
 			.saturating_add((2_000 as Weight).saturating_mul(r as Weight))
-			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	fn report_awesome_shared(r: u32, s: u32, ) -> Weight {
+		(76_412_000 as Weight)
+			// Standard Error: 0
+			.saturating_add((2_000 as Weight).saturating_mul(r as Weight))
+			// Standard Error: 2_000
+			.saturating_add((1_153_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(s as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
 	}
 	fn retract_tip() -> Weight {
 		(61_753_000 as Weight)
 			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
 	}
 	fn tip_new(r: u32, t: u32, ) -> Weight {
 		(47_731_000 as Weight)
@@ -119,28 +159,45 @@ impl WeightInfo for () {
 			.saturating_add((2_000 as Weight).saturating_mul(r as Weight))
 			// Standard Error: 0
 			.saturating_add((154_000 as Weight).saturating_mul(t as Weight))
-			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
 	}
-	fn tip(t: u32, ) -> Weight {
-		(35_215_000 as Weight)
+	fn tip(t: u32, d: u32, ) -> Weight {
+		(34_862_000 as Weight)
+			// Standard Error: 1_000
+			.saturating_add((418_000 as Weight).saturating_mul(t as Weight))
 			// Standard Error: 1_000
-			.saturating_add((712_000 as Weight).saturating_mul(t as Weight))
-			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add((301_000 as Weight).saturating_mul(d as Weight))
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
 	}
-	fn close_tip(t: u32, ) -> Weight {
-		(117_027_000 as Weight)
+	fn close_tip(t: u32, s: u32, ) -> Weight {
+		(163_412_000 as Weight)
 			// Standard Error: 1_000
 			.saturating_add((375_000 as Weight).saturating_mul(t as Weight))
-			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+			// Standard Error: 8_000
+			.saturating_add((48_617_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(RocksDbWeight::get().reads(8 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(s as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(11 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((3 as Weight).saturating_mul(s as Weight)))
 	}
 	fn slash_tip(t: u32, ) -> Weight {
 		(37_184_000 as Weight)
 			// Standard Error: 0
 			.saturating_add((11_000 as Weight).saturating_mul(t as Weight))
 			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	fn set_min_tippers_for_close(t: u32, ) -> Weight {
+		(4_371_000 as Weight)
+			// Standard Error: 0
+			.saturating_add((11_000 as Weight).saturating_mul(t as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn reset_tip_cooldown() -> Weight {
+		(16_902_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
 	}
 }